@@ -0,0 +1,292 @@
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    fs, io,
+    path::{self, Path, PathBuf},
+};
+
+use crate::{Config, NU_PARSER_VERSION, hash::fnv1a};
+
+/// Name of the cache directory in the project root
+const CACHE_DIR: &str = ".nu-lint-cache";
+const CLEAN_FILES: &str = "clean-files";
+
+/// Cache directory of the project: next to the given configuration file, or
+/// else in the closest ancestor of `start` with a `.nu-lint.toml` or `.git`,
+/// or else in `start` itself.
+#[must_use]
+pub fn cache_dir(config_file: Option<&Path>, start: &Path) -> PathBuf {
+    if let Some(dir) = config_file.and_then(Path::parent) {
+        return dir.join(CACHE_DIR);
+    }
+    // A relative directory such as `.` has no ancestors to search
+    let start = fs::canonicalize(start).unwrap_or_else(|_| start.to_path_buf());
+    start
+        .ancestors()
+        .find(|dir| dir.join(".nu-lint.toml").is_file() || dir.join(".git").exists())
+        .unwrap_or(start.as_path())
+        .join(CACHE_DIR)
+}
+
+/// On-disk record of files that had no violations, so unchanged files can be
+/// skipped on the next run.
+///
+/// Each file is remembered by its absolute path with a hash of the content it
+/// was linted with. The whole cache is invalidated when the configuration,
+/// the version of nu-lint (and thus the behaviour of the rules) or the version
+/// of the Nu parser changes. Files loaded with `use` or `source` are not part
+/// of the hash, so a file is not linted again when only its dependencies
+/// change.
+pub struct LintCache {
+    dir: PathBuf,
+    /// Identifies the configuration and versions the cache is valid for
+    stamp: String,
+    clean: HashMap<PathBuf, u64>,
+}
+
+impl LintCache {
+    /// Load the cache in `dir`, or start an empty one when it is missing or
+    /// was written for another configuration or version.
+    #[must_use]
+    pub fn load(dir: &Path, config: &Config) -> Self {
+        let stamp = stamp(config);
+        let clean = fs::read_to_string(dir.join(CLEAN_FILES))
+            .ok()
+            .and_then(|content| {
+                let mut lines = content.lines();
+                (lines.next() == Some(stamp.as_str())).then(|| {
+                    lines
+                        .filter_map(|line| {
+                            let (key, path) = line.split_once(' ')?;
+                            Some((PathBuf::from(path), u64::from_str_radix(key, 16).ok()?))
+                        })
+                        .collect()
+                })
+            })
+            .unwrap_or_default();
+        Self {
+            dir: dir.to_path_buf(),
+            stamp,
+            clean,
+        }
+    }
+
+    /// Hash of the content a file is linted with
+    #[must_use]
+    pub fn key(source: &str) -> u64 {
+        fnv1a(&[source.as_bytes()])
+    }
+
+    /// Whether the file was last linted without violations with the content
+    /// of the key
+    #[must_use]
+    pub fn is_clean(&self, path: &Path, key: u64) -> bool {
+        self.clean.get(&entry_path(path)) == Some(&key)
+    }
+
+    /// Remember whether linting the file with the content of the key found
+    /// no violations. Other files keep their entries.
+    pub fn record(&mut self, path: &Path, key: u64, clean: bool) {
+        if clean {
+            self.clean.insert(entry_path(path), key);
+        } else {
+            self.clean.remove(&entry_path(path));
+        }
+    }
+
+    /// Write the cache to disk, dropping files that no longer exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory or file cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        // Keep the cache out of version control without touching the
+        // project's own ignore file
+        fs::write(self.dir.join(".gitignore"), "*\n")?;
+
+        let mut entries: Vec<(&str, u64)> = self
+            .clean
+            .iter()
+            .filter(|(path, _)| path.is_file())
+            .filter_map(|(path, key)| Some((path.to_str()?, *key)))
+            .collect();
+        entries.sort_unstable();
+        let mut content = format!("{}\n", self.stamp);
+        for (path, key) in entries {
+            writeln!(content, "{key:016x} {path}").unwrap();
+        }
+        fs::write(self.dir.join(CLEAN_FILES), content)
+    }
+
+    /// Remove the cache directory, if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory exists but cannot be removed.
+    pub fn clear(dir: &Path) -> io::Result<()> {
+        match fs::remove_dir_all(dir) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+}
+
+/// Files are remembered by absolute path, so runs from other directories
+/// share the entries
+fn entry_path(file: &Path) -> PathBuf {
+    path::absolute(file).unwrap_or_else(|_| file.to_path_buf())
+}
+
+/// First line of the cache file. The configuration is serialized through
+/// `toml::Value`, whose tables are sorted, so the hash is the same for equal
+/// configurations.
+fn stamp(config: &Config) -> String {
+    let config = toml::Value::try_from(config)
+        .map(|value| value.to_string())
+        .unwrap_or_default();
+    format!(
+        "nu-lint {} nu-parser {NU_PARSER_VERSION} config {:016x}",
+        env!("CARGO_PKG_VERSION"),
+        fnv1a(&[config.as_bytes()])
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remembers_clean_files_across_loads() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_dir = dir.path().join(CACHE_DIR);
+        let file = dir.path().join("a.nu");
+        fs::write(&file, "print 1\n").unwrap();
+        let key = LintCache::key("print 1\n");
+
+        let mut cache = LintCache::load(&cache_dir, &Config::default());
+        assert!(!cache.is_clean(&file, key));
+        cache.record(&file, key, true);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(&cache_dir, &Config::default());
+        assert!(
+            cache.is_clean(&file, key),
+            "clean result is loaded from disk"
+        );
+        assert!(
+            !cache.is_clean(&file, LintCache::key("print 2\n")),
+            "changed content is linted again"
+        );
+    }
+
+    #[test]
+    fn record_keeps_other_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let (first, second) = (dir.path().join("first.nu"), dir.path().join("second.nu"));
+        let key = LintCache::key("print 1\n");
+
+        let mut cache = LintCache::load(dir.path(), &Config::default());
+        cache.record(&first, key, true);
+        cache.record(&second, key, true);
+        assert!(
+            cache.is_clean(&first, key),
+            "other files keep their entries"
+        );
+
+        cache.record(&second, key, false);
+        assert!(
+            !cache.is_clean(&second, key),
+            "files with violations are dropped"
+        );
+        assert!(cache.is_clean(&first, key));
+    }
+
+    #[test]
+    fn save_drops_removed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.nu");
+        let key = LintCache::key("print 1\n");
+
+        let mut cache = LintCache::load(dir.path(), &Config::default());
+        cache.record(&file, key, true);
+        cache.save().unwrap();
+        assert!(!LintCache::load(dir.path(), &Config::default()).is_clean(&file, key));
+    }
+
+    #[test]
+    fn other_config_invalidates_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_dir = dir.path().join(CACHE_DIR);
+        let file = dir.path().join("a.nu");
+        fs::write(&file, "print 1\n").unwrap();
+        let key = LintCache::key("print 1\n");
+
+        let mut cache = LintCache::load(&cache_dir, &Config::default());
+        cache.record(&file, key, true);
+        cache.save().unwrap();
+
+        let config = Config::load_from_str("[rules]\nunused_variable = \"off\"").unwrap();
+        assert!(!LintCache::load(&cache_dir, &config).is_clean(&file, key));
+    }
+
+    #[test]
+    fn cache_dir_is_in_project_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("scripts/lib");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(dir.path().join(".nu-lint.toml"), "").unwrap();
+        let root = fs::canonicalize(dir.path()).unwrap();
+
+        assert_eq!(cache_dir(None, &nested), root.join(CACHE_DIR));
+        let config_file = nested.join("custom.toml");
+        assert_eq!(
+            cache_dir(Some(&config_file), dir.path()),
+            nested.join(CACHE_DIR),
+            "an explicit configuration file decides the root"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn cache_dir_from_relative_start() {
+        use std::env;
+
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("scripts");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(dir.path().join(".nu-lint.toml"), "").unwrap();
+
+        // `../../tmp/.../scripts` from the working directory
+        let cwd = fs::canonicalize(env::current_dir().unwrap()).unwrap();
+        let nested = fs::canonicalize(nested).unwrap();
+        let relative: PathBuf = cwd
+            .components()
+            .skip(1)
+            .map(|_| Path::new(".."))
+            .chain(
+                nested
+                    .components()
+                    .skip(1)
+                    .map(|c| Path::new(c.as_os_str())),
+            )
+            .collect();
+        assert!(relative.is_relative());
+        assert_eq!(
+            cache_dir(None, &relative),
+            fs::canonicalize(dir.path()).unwrap().join(CACHE_DIR)
+        );
+    }
+
+    #[test]
+    fn clear_removes_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_dir = dir.path().join(CACHE_DIR);
+        LintCache::load(&cache_dir, &Config::default())
+            .save()
+            .unwrap();
+        LintCache::clear(&cache_dir).unwrap();
+        assert!(!cache_dir.exists());
+        LintCache::clear(&cache_dir).unwrap();
+    }
+}
@@ -1,25 +1,57 @@
 use std::{
-    io::{self, Read},
+    env,
+    fmt::Write,
+    fs,
+    io::{self, IsTerminal, Read},
+    num::NonZeroUsize,
     path::{Path, PathBuf},
     process,
 };
 
-use clap::{Parser, crate_version};
+use clap::{Parser, ValueEnum, crate_version};
 use miette::Severity;
+use serde::Serialize;
 
 use crate::{
     LintLevel,
     ast::tree,
+    cache::{LintCache, cache_dir},
     config::{Config, find_config_file_from},
-    engine::{LintEngine, collect_nu_files},
-    fix::{apply_fixes, apply_fixes_to_stdin, format_fix_results},
-    format::{Format, Summary, format_output},
-    log::{init_lsp_log, init_test_log},
+    engine::{FileFilter, LintEngine, cap_violations, collect_nu_files_filtered},
+    fix::{IterativeFixResult, apply_fixes, apply_fixes_iteratively, format_fix_results},
+    format::{Format, Statistics, Summary, format_output, format_unified_diff},
+    log::{init_lsp_log, init_verbose_log},
     lsp,
     rule::Rule,
-    rules::{USED_RULES, groups::ALL_GROUPS},
+    rules::{
+        USED_RULES,
+        groups::{ALL_GROUPS, groups_for_rule},
+    },
+    violation::Violation,
 };
 
+/// What `--stats` prints
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum StatsMode {
+    /// Print only the statistics, in the `--format` of choice, to stdout
+    Only,
+    /// Print the violations as usual and a statistics table to stderr after
+    /// them, so machine-readable formats on stdout stay valid
+    Append,
+}
+
+/// Entry of `--list --format json`
+#[derive(Serialize)]
+struct RuleListing {
+    id: &'static str,
+    groups: Vec<&'static str>,
+    /// Level after applying the configuration
+    level: LintLevel,
+    default_level: LintLevel,
+    fixable: bool,
+    description: &'static str,
+}
+
 #[derive(Parser)]
 #[command(name = "nu-lint")]
 #[command(about = "A linter for Nushell scripts")]
@@ -29,10 +61,20 @@ pub struct Cli {
     #[arg(default_value = ".")]
     paths: Vec<PathBuf>,
 
-    /// Auto-fix lint violations
+    /// Auto-fix lint violations. Exits with code 2 when fixes are still left
+    /// after `max_fix_iterations` passes.
     #[arg(long, conflicts_with_all = ["lsp", "list", "groups", "explain"])]
     fix: bool,
 
+    /// Print the changes `--fix` would make as a unified diff, without
+    /// writing any files. Exits with code 2 like `--fix`.
+    #[arg(long, conflicts_with_all = ["fix", "lsp", "list", "groups", "explain", "stats"])]
+    diff: bool,
+
+    /// Do not color the output of `--diff`
+    #[arg(long)]
+    no_color: bool,
+
     /// Start the LSP server
     #[arg(long, conflicts_with_all = ["fix", "list", "groups", "explain"])]
     lsp: bool,
@@ -41,6 +83,10 @@ pub struct Cli {
     #[arg(long, conflicts_with_all = ["fix", "lsp", "groups", "explain"], alias = "rules")]
     list: bool,
 
+    /// Only list the rules of this group (with `--list`)
+    #[arg(long, value_name = "GROUP", requires = "list", alias = "category")]
+    group: Option<String>,
+
     /// List all available rule groups
     #[arg(long, conflicts_with_all = ["fix", "lsp", "list", "explain"], alias = "sets")]
     groups: bool,
@@ -49,11 +95,47 @@ pub struct Cli {
     #[arg(long, value_name = "RULE_ID", conflicts_with_all = ["fix", "lsp", "list", "groups"])]
     explain: Option<String>,
 
+    /// Validate the configuration file and print the effective configuration
+    #[arg(long, conflicts_with_all = ["fix", "lsp", "list", "groups", "explain"])]
+    check_config: bool,
+
     /// Print AST (Abstract Syntax Tree) with expanded blocks for the given
     /// source code
     #[arg(long, value_name = "SOURCE", conflicts_with_all = ["fix", "lsp", "list", "groups", "explain"])]
     ast: Option<String>,
 
+    /// Print how often each rule fires across the linted files, instead of
+    /// the violations (`only`, the default) or after them (`append`)
+    #[arg(
+        long,
+        value_name = "MODE",
+        value_enum,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "only",
+        conflicts_with_all = ["fix", "lsp", "list", "groups", "explain", "max_violations"]
+    )]
+    stats: Option<StatsMode>,
+
+    /// Only run these rules, given as comma-separated rule ids, group names
+    /// or `all`
+    #[arg(long, value_name = "RULES", value_delimiter = ',')]
+    select: Vec<String>,
+
+    /// Never run these rules, given as comma-separated rule ids, group names
+    /// or `all`. Takes precedence over `--select`.
+    #[arg(long, value_name = "RULES", value_delimiter = ',')]
+    ignore: Vec<String>,
+
+    /// Skip paths matching this gitignore-style glob when linting
+    /// directories. Can be repeated and adds to `exclude` of the config.
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
+
+    /// Also lint files ignored by `.gitignore`, `.ignore` and similar files
+    #[arg(long)]
+    no_ignore: bool,
+
     /// Output format
     #[arg(long, short = 'f', value_enum, default_value_t = Format::Pretty)]
     format: Format,
@@ -66,6 +148,29 @@ pub struct Cli {
     #[arg(long)]
     stdin: bool,
 
+    /// Stop after reporting this many violations
+    #[arg(long, value_name = "N", conflicts_with_all = ["fix", "lsp"])]
+    max_violations: Option<usize>,
+
+    /// Fail when more than this many warnings are reported. Errors always
+    /// fail.
+    #[arg(long, value_name = "N", conflicts_with_all = ["fix", "lsp"])]
+    max_warnings: Option<usize>,
+
+    /// Lint every file, also the ones that had no violations in a previous
+    /// run and did not change since. Needed when only a file loaded with
+    /// `use` or `source` changed, as those are not tracked by the cache.
+    #[arg(long)]
+    no_cache: bool,
+
+    /// Remove the cache of files without violations before linting
+    #[arg(long)]
+    clear_cache: bool,
+
+    /// Number of files to lint in parallel (defaults to the number of CPUs)
+    #[arg(long, short = 'j', value_name = "N")]
+    jobs: Option<NonZeroUsize>,
+
     /// Verbose output (requires a level set by environment variable
     /// `RUST_LOG=debug`)
     #[arg(long, short = 'v')]
@@ -101,23 +206,56 @@ impl Cli {
         source
     }
 
-    fn lint(&self, config: &Config) {
+    /// Files to lint from the given paths, applying the ignore files and
+    /// excluded globs to directories
+    fn collect_files(&self, config: &Config) -> Vec<PathBuf> {
+        let filter = FileFilter {
+            respect_ignore_files: !self.no_ignore,
+            exclude: config.exclude.clone(),
+        };
+        collect_nu_files_filtered(&self.paths, &filter)
+    }
+
+    /// Engine for the configuration, exiting when it is invalid. Unknown
+    /// groups are only reported as a warning.
+    fn lint_engine(config: &Config) -> LintEngine {
         if let Err(e) = config.validate() {
             eprintln!("Error: {e}");
             process::exit(1);
         }
-        let engine = LintEngine::new(config.clone());
+        for warning in config.unknown_groups() {
+            eprintln!("Warning: {warning}");
+        }
+        LintEngine::new(config.clone())
+    }
 
-        let violations = if self.stdin {
-            let source = Self::read_stdin();
-            engine.lint_stdin(&source)
-        } else {
-            let files = collect_nu_files(&self.paths);
-            if files.is_empty() {
-                eprintln!("Warning: No Nushell files found in specified paths");
-                return;
-            }
-            engine.lint_files(&files)
+    /// Lint standard input or the files in `paths`, returning the violations,
+    /// whether they were truncated and the number of linted files. Returns
+    /// `None` when no files were found.
+    fn collect_violations(
+        &self,
+        engine: &LintEngine,
+        config: &Config,
+    ) -> Option<(Vec<Violation>, bool, usize)> {
+        if self.stdin {
+            let mut violations = engine.lint_stdin(&Self::read_stdin());
+            let truncated = cap_violations(&mut violations, self.max_violations);
+            return Some((violations, truncated, 1));
+        }
+        let files = self.collect_files(config);
+        if files.is_empty() {
+            eprintln!("Warning: No Nushell files found in specified paths");
+            return None;
+        }
+        let (violations, truncated) = self.lint_files_cached(engine, config, &files);
+        Some((violations, truncated, files.len()))
+    }
+
+    fn lint(&self, config: &Config) {
+        let engine = Self::lint_engine(config);
+        let Some((violations, truncated, file_count)) = self.collect_violations(&engine, config)
+        else {
+            return;
         };
 
         let output = format_output(&violations, self.format);
@@ -125,67 +263,228 @@ impl Cli {
             println!("{output}");
         }
 
+        if truncated {
+            eprintln!(
+                "... and more violations, stopped after {} (--max-violations)",
+                violations.len()
+            );
+        }
+
+        if self.stats == Some(StatsMode::Append) {
+            eprintln!(
+                "\n{}",
+                Statistics::from_violations(&violations, file_count).format_table()
+            );
+        }
+
         let summary = Summary::from_violations(&violations);
         eprintln!("{}", summary.format_compact());
 
-        if violations.iter().any(|v| v.lint_level > Severity::Warning) {
-            process::exit(1);
-        } else {
-            process::exit(0);
-        }
+        process::exit(exit_code(&violations, truncated, self.max_warnings));
     }
 
-    fn fix(&self, config: &Config) {
-        if let Err(e) = config.validate() {
-            eprintln!("Error: {e}");
-            process::exit(1);
+    /// Lint the files that changed since they last had no violations, and
+    /// remember the ones that have none now
+    fn lint_files_cached(
+        &self,
+        engine: &LintEngine,
+        config: &Config,
+        files: &[PathBuf],
+    ) -> (Vec<Violation>, bool) {
+        let cwd = env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        let cache_dir = cache_dir(self.config.as_deref(), &cwd);
+        if self.clear_cache
+            && let Err(e) = LintCache::clear(&cache_dir)
+        {
+            eprintln!("Warning: failed to clear {}: {e}", cache_dir.display());
+        }
+        if self.no_cache {
+            return engine.lint_files_capped(files, self.max_violations);
         }
-        let engine = LintEngine::new(config.clone());
 
-        if self.stdin {
-            Self::fix_stdin(&engine);
-        } else {
-            Self::fix_files(&self.paths, &engine);
+        let mut cache = LintCache::load(&cache_dir, config);
+        let (violations, truncated) =
+            engine.lint_files_cached(files, self.max_violations, Some(&mut cache));
+        if let Err(e) = cache.save() {
+            log::warn!("Failed to write {}: {e}", cache_dir.display());
         }
+        (violations, truncated)
     }
 
-    fn fix_stdin(engine: &LintEngine) {
-        let source = Self::read_stdin();
-        let violations = engine.lint_stdin(&source);
+    fn stats(&self, config: &Config) {
+        let engine = Self::lint_engine(config);
+        let Some((violations, _, file_count)) = self.collect_violations(&engine, config) else {
+            return;
+        };
+
+        println!(
+            "{}",
+            Statistics::from_violations(&violations, file_count).format(self.format)
+        );
+    }
 
-        if let Some(fixed) = apply_fixes_to_stdin(&violations) {
-            print!("{fixed}");
+    fn fix(&self, config: &Config) {
+        let engine = Self::lint_engine(config);
+
+        let converged = if self.stdin {
+            Self::fix_stdin(&engine)
         } else {
-            print!("{source}");
+            Self::fix_files(&self.collect_files(config), &engine)
+        };
+        if !converged {
+            process::exit(UNCONVERGED_EXIT_CODE);
         }
     }
 
-    fn fix_files(paths: &[PathBuf], engine: &LintEngine) {
-        let files = collect_nu_files(paths);
+    /// Fix stdin iteratively, returning whether fixing converged
+    fn fix_stdin(engine: &LintEngine) -> bool {
+        let source = Self::read_stdin();
+        let result = apply_fixes_iteratively(&source, engine);
+        print!("{}", result.content);
+        Self::report_unconverged(&result)
+    }
+
+    /// Fix files in place, returning whether fixing converged for all of them
+    fn fix_files(files: &[PathBuf], engine: &LintEngine) -> bool {
         if files.is_empty() {
             eprintln!("Warning: No Nushell files found in specified paths");
-            return;
+            return true;
         }
 
-        let violations = engine.lint_files(&files);
+        let violations = engine.lint_files(files);
 
         let results = apply_fixes(&violations, false, engine);
         let output = format_fix_results(&results, false);
         print!("{output}");
+        results.iter().all(|r| r.unconverged_rules.is_empty())
+    }
+
+    /// Print the rules still firing to stderr when fixing stdin did not
+    /// converge, since stdout holds the fixed source or its diff. Returns
+    /// whether fixing converged.
+    fn report_unconverged(result: &IterativeFixResult) -> bool {
+        if !result.converged() {
+            eprintln!(
+                "Warning: fixes did not converge, rules still firing: {}",
+                result.unconverged_rules.join(", ")
+            );
+        }
+        result.converged()
+    }
+
+    fn diff(&self, config: &Config) {
+        let engine = Self::lint_engine(config);
+        let color = !self.no_color && io::stdout().is_terminal();
+
+        let (diffs, converged): (Vec<String>, bool) = if self.stdin {
+            let source = Self::read_stdin();
+            let result = apply_fixes_iteratively(&source, &engine);
+            let diff = format_unified_diff("<stdin>", &source, &result.content, color);
+            (vec![diff], Self::report_unconverged(&result))
+        } else {
+            Self::diff_files(&self.collect_files(config), &engine, color)
+        };
+
+        let diffs: Vec<String> = diffs.into_iter().filter(|d| !d.is_empty()).collect();
+        if diffs.is_empty() {
+            eprintln!("No fixable violations found.");
+        }
+        for diff in diffs {
+            print!("{diff}");
+        }
+        if !converged {
+            process::exit(UNCONVERGED_EXIT_CODE);
+        }
+    }
+
+    /// Unified diffs of the fixes of files, and whether fixing converged for
+    /// all of them
+    fn diff_files(files: &[PathBuf], engine: &LintEngine, color: bool) -> (Vec<String>, bool) {
+        let results = apply_fixes(&engine.lint_files(files), true, engine);
+        for result in results.iter().filter(|r| !r.unconverged_rules.is_empty()) {
+            eprintln!(
+                "Warning: fixes of {} did not converge, rules still firing: {}",
+                result.file_path.display(),
+                result.unconverged_rules.join(", ")
+            );
+        }
+        let diffs = results
+            .iter()
+            .map(|result| {
+                format_unified_diff(
+                    &result.file_path.display().to_string(),
+                    &result.original_content,
+                    &result.fixed_content,
+                    color,
+                )
+            })
+            .collect();
+        (
+            diffs,
+            results.iter().all(|r| r.unconverged_rules.is_empty()),
+        )
     }
 
-    fn list_rules(config: &Config) {
-        let mut sorted_rules: Vec<&dyn Rule> = USED_RULES.to_vec();
+    /// Render the rule listing of `--list`, optionally restricted to the rules
+    /// of one group. JSON output contains the same fields as the table.
+    fn rules_listing(
+        config: &Config,
+        group: Option<&str>,
+        format: Format,
+    ) -> Result<String, String> {
+        let group_rules = match group {
+            Some(name) => {
+                let group = ALL_GROUPS.iter().find(|g| g.name == name).ok_or_else(|| {
+                    let names: Vec<&str> = ALL_GROUPS.iter().map(|g| g.name).collect();
+                    format!(
+                        "Unknown group '{name}'. Available groups: {}",
+                        names.join(", ")
+                    )
+                })?;
+                Some(group.rules)
+            }
+            None => None,
+        };
+
+        let mut sorted_rules: Vec<&dyn Rule> = USED_RULES
+            .iter()
+            .copied()
+            .filter(|rule| {
+                group_rules.is_none_or(|rules| rules.iter().any(|r| r.id() == rule.id()))
+            })
+            .collect();
         sorted_rules.sort_by_key(|r| r.id());
 
+        if matches!(format, Format::Json) {
+            let entries: Vec<RuleListing> = sorted_rules
+                .iter()
+                .map(|rule| RuleListing {
+                    id: rule.id(),
+                    groups: groups_for_rule(rule.id()),
+                    level: config.get_lint_level(*rule),
+                    default_level: rule.level(),
+                    fixable: rule.has_auto_fix(),
+                    description: rule.short_description(),
+                })
+                .collect();
+            return Ok(
+                serde_json::to_string_pretty(&entries).expect("rule listing serializes to JSON")
+            );
+        }
+
         if sorted_rules.is_empty() {
-            println!("No rules enabled.");
-            return;
+            return Ok("No rules enabled.".to_string());
         }
 
+        let rule_groups: Vec<String> = sorted_rules
+            .iter()
+            .map(|rule| groups_for_rule(rule.id()).join(","))
+            .collect();
         let max_id_len = sorted_rules.iter().map(|r| r.id().len()).max().unwrap_or(0);
+        let max_group_len = rule_groups.iter().map(String::len).max().unwrap_or(0);
 
-        for rule in &sorted_rules {
+        let mut output = String::new();
+        for (rule, groups) in sorted_rules.iter().zip(&rule_groups) {
             let level = config.get_lint_level(*rule);
             let level_char = match level {
                 LintLevel::Hint => 'H',
@@ -195,19 +494,35 @@ impl Cli {
             };
             let fix_char = if rule.has_auto_fix() { 'F' } else { ' ' };
             let desc = rule.short_description();
-            println!(
-                "{level_char}{fix_char} {:<width$}  {desc}",
+            writeln!(
+                output,
+                "{level_char}{fix_char} {:<id_width$}  {groups:<group_width$}  {desc}",
                 rule.id(),
-                width = max_id_len
-            );
+                id_width = max_id_len,
+                group_width = max_group_len
+            )
+            .unwrap();
         }
 
         let fixable_count = sorted_rules.iter().filter(|r| r.has_auto_fix()).count();
-        println!(
+        write!(
+            output,
             "\n{n} rules, {f} fixable. [H]int [W]arning [E]rror [F]ixable [D]eactivated",
             n = sorted_rules.len(),
             f = fixable_count
-        );
+        )
+        .unwrap();
+        Ok(output)
+    }
+
+    fn list_rules(&self, config: &Config) {
+        match Self::rules_listing(config, self.group.as_deref(), self.format) {
+            Ok(output) => println!("{output}"),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                process::exit(1);
+            }
+        }
     }
 
     fn list_groups() {
@@ -228,12 +543,56 @@ impl Cli {
         }
     }
 
+    fn check_config(path: Option<&Path>) {
+        let path = path
+            .map(Path::to_path_buf)
+            .or_else(|| find_config_file_from(Path::new(".")));
+
+        let config = if let Some(path) = path {
+            let content = fs::read_to_string(&path).unwrap_or_else(|source| {
+                eprintln!("Error: failed to read '{}': {source}", path.display());
+                process::exit(1);
+            });
+            match Config::check_str(&content) {
+                Ok(config) => {
+                    eprintln!("Configuration file '{}' is valid.", path.display());
+                    config
+                }
+                Err(errors) => {
+                    for error in &errors {
+                        eprintln!("Error: {error}");
+                    }
+                    eprintln!(
+                        "Configuration file '{}' has {} problem(s).",
+                        path.display(),
+                        errors.len()
+                    );
+                    process::exit(1);
+                }
+            }
+        } else {
+            eprintln!("No configuration file found, using defaults.");
+            Config::default()
+        };
+
+        match config.effective_toml() {
+            Ok(effective) => print!("{effective}"),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
     fn explain_rule(rule_id: &str) {
         let rule = USED_RULES.iter().find(|r| r.id() == rule_id);
 
         if let Some(rule) = rule {
             println!("Rule: {}", rule.id());
             println!("Explanation: {}", rule.short_description());
+            if let Some(long_description) = rule.long_description() {
+                println!("\n{long_description}\n");
+            }
             if let Some(url) = rule.source_link() {
                 println!("Documentation: {url}");
             }
@@ -244,16 +603,51 @@ impl Cli {
     }
 }
 
+/// Exit code of `--fix` and `--diff` when fixes were still left after the
+/// configured number of passes, so CI can tell it apart from violations
+const UNCONVERGED_EXIT_CODE: i32 = 2;
+
+/// Exit code of a lint run: 1 when there are errors, when violations were
+/// dropped by `--max-violations` or when there are more warnings than
+/// `max_warnings`, and 0 otherwise.
+fn exit_code(violations: &[Violation], truncated: bool, max_warnings: Option<usize>) -> i32 {
+    let has_errors = violations.iter().any(|v| v.lint_level > Severity::Warning);
+    let warnings = violations
+        .iter()
+        .filter(|v| v.lint_level == Severity::Warning)
+        .count();
+    let too_many_warnings = max_warnings.is_some_and(|max| warnings > max);
+    i32::from(truncated || has_errors || too_many_warnings)
+}
+
 pub fn run() {
     let cli = Cli::parse();
 
     if cli.verbose {
-        init_test_log();
+        init_verbose_log();
+    }
+
+    if cli.check_config {
+        Cli::check_config(cli.config.as_deref());
+        return;
     }
 
-    let config = Cli::load_config(cli.config.clone());
+    if let Some(jobs) = cli.jobs
+        && let Err(e) = rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs.get())
+            .build_global()
+    {
+        eprintln!("Warning: could not limit the number of jobs: {e}");
+    }
+
+    let mut config = Cli::load_config(cli.config.clone());
+    if let Err(e) = config.apply_rule_selection(&cli.select, &cli.ignore) {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    }
+    config.exclude.extend(cli.exclude.iter().cloned());
     if cli.list {
-        Cli::list_rules(&config);
+        cli.list_rules(&config);
     } else if cli.groups {
         Cli::list_groups();
     } else if let Some(ref rule_id) = cli.explain {
@@ -266,6 +660,10 @@ pub fn run() {
         lsp::run_lsp_server();
     } else if cli.fix {
         cli.fix(&config);
+    } else if cli.diff {
+        cli.diff(&config);
+    } else if cli.stats == Some(StatsMode::Only) {
+        cli.stats(&config);
     } else {
         log::debug!("No flags given, will lint workspace.");
         cli.lint(&config);
@@ -274,11 +672,18 @@ pub fn run() {
 
 #[cfg(test)]
 mod tests {
-    use std::{fs, path::PathBuf};
+    use std::{fs, num::NonZeroUsize, path::PathBuf};
 
     use clap::Parser;
+    use miette::Severity;
 
-    use crate::{Config, LintEngine, cli::Cli, engine::collect_nu_files};
+    use crate::{
+        Config, LintEngine,
+        cache::LintCache,
+        cli::{Cli, StatsMode, exit_code},
+        engine::collect_nu_files,
+        format::{Format, Statistics, format_output},
+    };
 
     #[test]
     fn test_cli_parsing() {
@@ -305,12 +710,180 @@ mod tests {
         assert!(cli.groups);
     }
 
+    #[test]
+    fn test_cli_select_and_ignore_flags() {
+        let cli = Cli::try_parse_from([
+            "nu-lint",
+            "--select",
+            "posix,unused_variable",
+            "--ignore",
+            "cat_to_open",
+        ])
+        .unwrap();
+        assert_eq!(cli.select, ["posix", "unused_variable"]);
+        assert_eq!(cli.ignore, ["cat_to_open"]);
+    }
+
+    #[test]
+    fn test_select_filters_lint_results() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("test.nu"),
+            "let x = 5\n^cat file.txt\n",
+        )
+        .unwrap();
+        let files = collect_nu_files(&[temp_dir.path().to_path_buf()]);
+
+        let mut config = Config::default();
+        config
+            .apply_rule_selection(&["unused_variable".to_string()], &[])
+            .unwrap();
+        let violations = LintEngine::new(config).lint_files(&files);
+        assert!(!violations.is_empty());
+        assert!(
+            violations
+                .iter()
+                .all(|v| v.rule_id.as_deref() == Some("unused_variable")),
+            "only the selected rule runs"
+        );
+    }
+
+    #[test]
+    fn test_ignore_files_and_excluded_globs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        for dir in ["src", "target", "vendor"] {
+            fs::create_dir(root.join(dir)).unwrap();
+            fs::write(root.join(dir).join("a.nu"), "print 1\n").unwrap();
+        }
+        fs::write(root.join(".ignore"), "target/\n").unwrap();
+        let root_arg = root.to_str().unwrap();
+        let names = |files: Vec<PathBuf>| -> Vec<String> {
+            files
+                .iter()
+                .map(|f| f.strip_prefix(root).unwrap().display().to_string())
+                .collect()
+        };
+
+        let cli = Cli::try_parse_from(["nu-lint", root_arg]).unwrap();
+        assert_eq!(
+            names(cli.collect_files(&Config::default())),
+            ["src/a.nu", "vendor/a.nu"]
+        );
+
+        let cli = Cli::try_parse_from(["nu-lint", "--no-ignore", root_arg]).unwrap();
+        assert_eq!(cli.collect_files(&Config::default()).len(), 3);
+
+        let config = Config::load_from_str("exclude = [\"vendor\"]").unwrap();
+        assert_eq!(
+            names(cli.collect_files(&config)),
+            ["src/a.nu", "target/a.nu"]
+        );
+
+        let vendored = root.join("vendor").join("a.nu");
+        let cli = Cli::try_parse_from([
+            "nu-lint",
+            "--exclude",
+            "vendor",
+            "--exclude",
+            "src/*.nu",
+            root_arg,
+            vendored.to_str().unwrap(),
+        ])
+        .unwrap();
+        let mut config = Config::default();
+        config.exclude.extend(cli.exclude.iter().cloned());
+        assert_eq!(
+            names(cli.collect_files(&config)),
+            ["vendor/a.nu"],
+            "explicit paths are linted even when excluded"
+        );
+    }
+
+    #[test]
+    fn test_disable_file_directive() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("generated.nu"),
+            "# nu-lint: disable-file\nlet x = 5\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("vendored.nu"),
+            "# nu-lint: disable-file unused_variable\nlet x = 5\n^cat file.txt\n",
+        )
+        .unwrap();
+        let files = collect_nu_files(&[temp_dir.path().to_path_buf()]);
+
+        let violations = LintEngine::new(Config::default()).lint_files(&files);
+        assert!(
+            !violations.is_empty(),
+            "other rules still run in vendored.nu"
+        );
+        for v in &violations {
+            assert!(
+                v.file.as_ref().unwrap().as_str().ends_with("vendored.nu"),
+                "generated.nu is skipped"
+            );
+            assert_ne!(v.rule_id.as_deref(), Some("unused_variable"));
+        }
+    }
+
+    #[test]
+    fn test_cli_list_category_filter() {
+        let cli = Cli::try_parse_from(["nu-lint", "--list", "--category", "posix"]).unwrap();
+        assert_eq!(cli.group.as_deref(), Some("posix"));
+        assert!(
+            Cli::try_parse_from(["nu-lint", "--group", "posix"]).is_err(),
+            "--group requires --list"
+        );
+    }
+
+    #[test]
+    fn test_list_rules_json_filtered_by_group() {
+        let output = Cli::rules_listing(&Config::default(), Some("posix"), Format::Json).unwrap();
+        let rules: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let rules = rules.as_array().unwrap();
+        assert!(!rules.is_empty(), "posix group has rules");
+        for rule in rules {
+            assert!(
+                rule["groups"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .any(|g| g == "posix"),
+                "{rule} should belong to the posix group"
+            );
+            assert!(rule["default_level"].is_string());
+            assert!(rule["description"].is_string());
+        }
+    }
+
+    #[test]
+    fn test_list_rules_unknown_group() {
+        let error =
+            Cli::rules_listing(&Config::default(), Some("nope"), Format::Pretty).unwrap_err();
+        assert!(
+            error.contains("posix"),
+            "lists the available groups: {error}"
+        );
+    }
+
     #[test]
     fn test_cli_explain_flag() {
         let cli = Cli::try_parse_from(["nu-lint", "--explain", "some-rule"]).unwrap();
         assert_eq!(cli.explain, Some("some-rule".to_string()));
     }
 
+    #[test]
+    fn test_cli_check_config_flag() {
+        let cli =
+            Cli::try_parse_from(["nu-lint", "--check-config", "--config", "lint.toml"]).unwrap();
+        assert!(cli.check_config, "Expected --check-config to be set");
+        assert_eq!(cli.config, Some(PathBuf::from("lint.toml")));
+        assert!(Cli::try_parse_from(["nu-lint", "--check-config", "--fix"]).is_err());
+    }
+
     #[test]
     fn test_cli_lsp_flag() {
         let cli = Cli::try_parse_from(["nu-lint", "--lsp"]).unwrap();
@@ -331,6 +904,363 @@ mod tests {
         assert!(Cli::try_parse_from(["nu-lint", "--fix", "--explain", "rule"]).is_err());
     }
 
+    #[test]
+    fn test_cli_max_violations_flag() {
+        let cli = Cli::try_parse_from(["nu-lint", "--max-violations", "5"]).unwrap();
+        assert_eq!(cli.max_violations, Some(5));
+        assert!(Cli::try_parse_from(["nu-lint", "--max-violations", "5", "--fix"]).is_err());
+    }
+
+    #[test]
+    fn test_max_violations_caps_in_file_order() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let paths: Vec<PathBuf> = ["a.nu", "b.nu", "c.nu"]
+            .iter()
+            .map(|name| {
+                let path = temp_dir.path().join(name);
+                fs::write(&path, "let x = 1\nlet y = 2\nlet z = 3\n").unwrap();
+                path
+            })
+            .collect();
+
+        let engine = LintEngine::new(Config::default());
+        let files = collect_nu_files(&paths);
+        let all = engine.lint_files(&files);
+        assert!(
+            all.len() > 3,
+            "Expected more than 3 violations, got {}",
+            all.len()
+        );
+
+        let (capped, truncated) = engine.lint_files_capped(&files, Some(3));
+        assert_eq!(capped.len(), 3, "Expected exactly 3 violations");
+        assert!(truncated, "Expected the cap to be reported as hit");
+
+        let key = |v: &crate::violation::Violation| (v.rule_id.clone(), v.file_span().start);
+        assert_eq!(
+            capped.iter().map(key).collect::<Vec<_>>(),
+            all.iter().take(3).map(key).collect::<Vec<_>>(),
+            "Capped violations should be the first ones in file order"
+        );
+
+        let (uncapped, truncated) = engine.lint_files_capped(&files, Some(all.len()));
+        assert_eq!(
+            uncapped.len(),
+            all.len(),
+            "Cap equal to the total keeps everything"
+        );
+        assert!(
+            !truncated,
+            "Cap equal to the total should not be reported as hit"
+        );
+    }
+
+    #[test]
+    fn test_cli_stats_flag() {
+        let cli = Cli::try_parse_from(["nu-lint", "--stats", "--format", "json"]).unwrap();
+        assert_eq!(
+            cli.stats,
+            Some(StatsMode::Only),
+            "Expected --stats to default to only"
+        );
+        assert!(Cli::try_parse_from(["nu-lint", "--stats", "--fix"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_stats_append_flag() {
+        let cli = Cli::try_parse_from(["nu-lint", "--stats=append", "--format", "sarif"]).unwrap();
+        assert_eq!(cli.stats, Some(StatsMode::Append));
+        let cli = Cli::try_parse_from(["nu-lint", "--stats", "script.nu"]).unwrap();
+        assert_eq!(
+            cli.paths,
+            [PathBuf::from("script.nu")],
+            "A path after --stats is not taken as its mode"
+        );
+        assert!(Cli::try_parse_from(["nu-lint", "--stats=append", "--fix"]).is_err());
+    }
+
+    #[test]
+    fn test_stats_aggregates_counts() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let paths: Vec<PathBuf> = [("a.nu", "let x = 1\nlet y = 2\n"), ("b.nu", "let z = 3\n")]
+            .iter()
+            .map(|(name, source)| {
+                let path = temp_dir.path().join(name);
+                fs::write(&path, source).unwrap();
+                path
+            })
+            .collect();
+
+        let engine = LintEngine::new(Config::default());
+        let files = collect_nu_files(&paths);
+        let violations = engine.lint_files(&files);
+        let stats = Statistics::from_violations(&violations, files.len());
+
+        assert_eq!(stats.files, 2, "Expected both fixture files to be counted");
+        assert_eq!(stats.violations, violations.len());
+        assert_eq!(
+            stats.rules.iter().map(|r| r.count).sum::<usize>(),
+            violations.len(),
+            "Rule counts should add up to the total"
+        );
+        assert!(
+            stats.rules.windows(2).all(|w| w[0].count >= w[1].count),
+            "Rules should be sorted by descending count"
+        );
+
+        let unused = stats
+            .rules
+            .iter()
+            .find(|r| r.rule_id == "unused_variable")
+            .expect("Expected unused_variable in the statistics");
+        assert_eq!(unused.count, 3, "Expected one unused variable per `let`");
+        assert_eq!(unused.severity, "warning");
+        let total = f64::from(u32::try_from(violations.len()).unwrap());
+        let expected = 300.0 / total;
+        assert!(
+            (unused.percentage - expected).abs() < f64::EPSILON,
+            "Expected {expected}%, got {}%",
+            unused.percentage
+        );
+    }
+
+    #[test]
+    fn test_sarif_output() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_file = temp_dir.path().join("test.nu");
+        fs::write(&test_file, "# café 🦀\nlet x = 1\n").unwrap();
+
+        let engine = LintEngine::new(Config::default());
+        let violations = engine.lint_files(&collect_nu_files(&[test_file]));
+        let sarif: serde_json::Value =
+            serde_json::from_str(&format_output(&violations, Format::Sarif)).unwrap();
+
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert!(
+            run["tool"]["driver"]["rules"]
+                .as_array()
+                .is_some_and(|rules| rules.iter().any(|r| r["id"] == "unused_variable")),
+            "Expected all registered rules in the driver"
+        );
+        let result = run["results"]
+            .as_array()
+            .and_then(|results| results.iter().find(|r| r["ruleId"] == "unused_variable"))
+            .expect("Expected an unused_variable result");
+        let region = &result["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(
+            region["startLine"], 2,
+            "Expected the `let` on the second line"
+        );
+        assert_eq!(result["level"], "warning");
+    }
+
+    #[test]
+    fn test_gitlab_output() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_file = temp_dir.path().join("test.nu");
+        fs::write(&test_file, "let x = 1\nlet y = 2\n").unwrap();
+
+        let engine = LintEngine::new(Config::default());
+        let violations = engine.lint_files(&collect_nu_files(&[test_file]));
+        let report = format_output(&violations, Format::Gitlab);
+        let issues: Vec<serde_json::Value> = serde_json::from_str(&report).unwrap();
+
+        let unused: Vec<&serde_json::Value> = issues
+            .iter()
+            .filter(|issue| issue["check_name"] == "unused_variable")
+            .collect();
+        assert_eq!(unused.len(), 2, "Expected one issue per unused variable");
+        assert_eq!(unused[0]["severity"], "minor");
+        assert_eq!(unused[1]["location"]["lines"]["begin"], 2);
+        assert_ne!(
+            unused[0]["fingerprint"], unused[1]["fingerprint"],
+            "Distinct issues need distinct fingerprints"
+        );
+        assert_eq!(
+            report,
+            format_output(&violations, Format::Gitlab),
+            "The report should be deterministic"
+        );
+    }
+
+    #[test]
+    fn test_json_output_includes_fixes() {
+        let engine = LintEngine::new(Config::default());
+        let violations = engine.lint_stdin("let x = 1\n^tac log.txt\n");
+        let entries: Vec<serde_json::Value> =
+            serde_json::from_str(&format_output(&violations, Format::Json)).unwrap();
+
+        let tac = entries
+            .iter()
+            .find(|e| e["rule_id"] == "tac_to_reverse")
+            .expect("Expected a tac_to_reverse entry");
+        assert_eq!(tac["line"], 2);
+        assert_eq!(tac["end_line"], 2);
+        assert_eq!(
+            tac["span"]["start"], 10,
+            "Byte span should be file-relative"
+        );
+        assert_eq!(tac["fix_available"], true);
+        assert_eq!(
+            tac["fixes"][0]["replacement"], "open --raw log.txt | lines | reverse",
+            "Expected the fix replacement text"
+        );
+    }
+
+    #[test]
+    fn test_checkstyle_output_groups_by_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let paths: Vec<PathBuf> = ["a.nu", "b.nu"]
+            .iter()
+            .map(|name| {
+                let path = temp_dir.path().join(name);
+                fs::write(&path, "let x = 1\nlet y = 2\n").unwrap();
+                path
+            })
+            .collect();
+
+        let engine = LintEngine::new(Config::default());
+        let violations = engine.lint_files(&collect_nu_files(&paths));
+        let xml = format_output(&violations, Format::Checkstyle);
+
+        assert!(xml.starts_with("<?xml"), "Expected an XML declaration");
+        assert_eq!(
+            xml.matches("<file ").count(),
+            2,
+            "Expected one element per file"
+        );
+        assert!(
+            xml.contains(r#"line="2" column="1" severity="warning""#),
+            "Expected the second `let` to be reported:\n{xml}"
+        );
+        assert!(xml.contains(r#"source="unused_variable""#));
+        assert!(xml.trim_end().ends_with("</checkstyle>"));
+    }
+
+    #[test]
+    fn test_rule_level_override_reaches_output() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::write(temp_dir.path().join("test.nu"), "let x = 5\n").unwrap();
+        let files = collect_nu_files(&[temp_dir.path().to_path_buf()]);
+
+        let config = Config::load_from_str("[rules]\nunused_variable = \"error\"").unwrap();
+        let violations = LintEngine::new(config).lint_files(&files);
+        let unused = violations
+            .iter()
+            .find(|v| v.rule_id.as_deref() == Some("unused_variable"))
+            .expect("unused_variable fires");
+        assert_eq!(unused.lint_level, Severity::Error, "promoted to an error");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_output(&violations, Format::Json)).unwrap();
+        assert!(
+            json.as_array()
+                .unwrap()
+                .iter()
+                .any(|v| v["rule_id"] == "unused_variable" && v["severity"] == "error")
+        );
+    }
+
+    #[test]
+    fn test_cli_cache_flags() {
+        let cli = Cli::try_parse_from(["nu-lint", "--no-cache", "--clear-cache"]).unwrap();
+        assert!(cli.no_cache);
+        assert!(cli.clear_cache);
+    }
+
+    #[test]
+    fn test_cache_merges_clean_files_of_the_run() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let clean = temp_dir.path().join("clean.nu");
+        let dirty = temp_dir.path().join("dirty.nu");
+        fs::write(&clean, "print 1\n").unwrap();
+        fs::write(&dirty, "let x = 5\n").unwrap();
+        let mut config = Config::default();
+        config
+            .apply_rule_selection(&["unused_variable".to_string()], &[])
+            .unwrap();
+        let engine = LintEngine::new(config.clone());
+        let mut cache = LintCache::load(&temp_dir.path().join("cache"), &config);
+
+        let files = [clean.clone(), dirty.clone()];
+        let (violations, _) = engine.lint_files_cached(&files, None, Some(&mut cache));
+        assert_eq!(violations.len(), 1);
+        assert!(cache.is_clean(&clean, LintCache::key("print 1\n")));
+        assert!(!cache.is_clean(&dirty, LintCache::key("let x = 5\n")));
+
+        fs::write(&dirty, "print 2\n").unwrap();
+        let (violations, _) = engine.lint_files_cached(&[dirty.clone()], None, Some(&mut cache));
+        assert!(violations.is_empty());
+        assert!(
+            cache.is_clean(&dirty, LintCache::key("print 2\n")),
+            "the fixed file is remembered"
+        );
+        assert!(
+            cache.is_clean(&clean, LintCache::key("print 1\n")),
+            "files outside the run keep their entries"
+        );
+    }
+
+    #[test]
+    fn test_cli_diff_flag() {
+        let cli = Cli::try_parse_from(["nu-lint", "--diff", "--no-color", "script.nu"]).unwrap();
+        assert!(cli.diff);
+        assert!(cli.no_color);
+        assert!(Cli::try_parse_from(["nu-lint", "--diff", "--fix"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_jobs_flag() {
+        let cli = Cli::try_parse_from(["nu-lint", "--jobs", "4"]).unwrap();
+        assert_eq!(cli.jobs.map(NonZeroUsize::get), Some(4));
+        assert!(
+            Cli::try_parse_from(["nu-lint", "-j", "0"]).is_err(),
+            "at least one job is needed"
+        );
+    }
+
+    #[test]
+    fn test_cli_max_warnings_flag() {
+        let cli = Cli::try_parse_from(["nu-lint", "--max-warnings", "3"]).unwrap();
+        assert_eq!(cli.max_warnings, Some(3));
+        assert_eq!(Cli::try_parse_from(["nu-lint"]).unwrap().max_warnings, None);
+    }
+
+    #[test]
+    fn test_exit_code_with_warning_budget() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::write(temp_dir.path().join("test.nu"), "let x = 5\nlet y = 6\n").unwrap();
+        let files = collect_nu_files(&[temp_dir.path().to_path_buf()]);
+        let mut config = Config::default();
+        config
+            .apply_rule_selection(&["unused_variable".to_string()], &[])
+            .unwrap();
+        let violations = LintEngine::new(config).lint_files(&files);
+        assert_eq!(violations.len(), 2);
+
+        assert_eq!(
+            exit_code(&violations, false, None),
+            0,
+            "warnings pass by default"
+        );
+        assert_eq!(exit_code(&violations, false, Some(2)), 0, "within budget");
+        assert_eq!(exit_code(&violations, false, Some(1)), 1, "over budget");
+        assert_eq!(
+            exit_code(&violations, true, None),
+            1,
+            "truncated output fails"
+        );
+        assert_eq!(exit_code(&[], false, Some(0)), 0);
+    }
+
+    #[test]
+    fn test_exit_code_fails_on_errors() {
+        let config = Config::load_from_str("[rules]\nunused_variable = \"error\"").unwrap();
+        let violations = LintEngine::new(config).lint_str("let x = 5\n");
+        assert_eq!(exit_code(&violations, false, Some(10)), 1);
+    }
+
     #[test]
     fn test_lint_integration() {
         let temp_dir = tempfile::tempdir().unwrap();
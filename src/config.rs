@@ -44,6 +44,69 @@ pub enum PipelinePlacement {
     End,
 }
 
+/// Whether multi-line list and record literals end with a trailing comma.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TrailingComma {
+    /// Add a trailing comma when the items are already separated by commas
+    Always,
+    /// Remove trailing commas
+    #[default]
+    Never,
+}
+
+/// Options of the `pipeline_too_long` rule.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct PipelineLengthLimit {
+    /// Number of elements a single pipeline may have before it is reported
+    pub max_elements: usize,
+}
+
+impl Default for PipelineLengthLimit {
+    fn default() -> Self {
+        Self { max_elements: 8 }
+    }
+}
+
+/// Options of the `magic_number` rule.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct MagicNumberExceptions {
+    /// Integer literals that are never reported
+    pub allowed_values: Vec<i64>,
+    /// Commands whose arguments are never reported, for example `take`
+    pub ignored_commands: Vec<String>,
+}
+
+impl Default for MagicNumberExceptions {
+    fn default() -> Self {
+        Self {
+            allowed_values: vec![0, 1],
+            ignored_commands: Vec::new(),
+        }
+    }
+}
+
+/// Options of the `consider_par_each` rule.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ParEachCandidates {
+    /// Built-in commands that are slow enough to run in parallel. External
+    /// commands always count as expensive.
+    pub expensive_commands: Vec<String>,
+}
+
+impl Default for ParEachCandidates {
+    fn default() -> Self {
+        Self {
+            expensive_commands: ["http get", "http post", "open", "hash md5", "hash sha256"]
+                .map(String::from)
+                .to_vec(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(default)]
 pub struct Config {
@@ -56,6 +119,18 @@ pub struct Config {
     /// When true, rules recommend `get --optional` instead of `$list.0?` for
     /// safe access. Default is false (prefer `?` syntax).
     pub explicit_optional_access: bool,
+    /// Trailing comma style for multi-line list and record literals.
+    pub trailing_comma: TrailingComma,
+    pub pipeline_too_long: PipelineLengthLimit,
+    pub magic_number: MagicNumberExceptions,
+    pub consider_par_each: ParEachCandidates,
+    /// Maximum number of passes `--fix` makes over one file. Each pass
+    /// applies the fixes that do not conflict and lints the file again, so
+    /// this stops fixes of different rules that keep triggering each other.
+    pub max_fix_iterations: usize,
+    /// Gitignore-style globs of paths to skip when linting a directory,
+    /// for example `vendor` or `scripts/generated/*.nu`
+    pub exclude: Vec<String>,
 }
 
 impl Default for Config {
@@ -68,6 +143,12 @@ impl Default for Config {
             max_pipeline_length: 80,
             skip_external_parse_errors: true,
             explicit_optional_access: false,
+            trailing_comma: TrailingComma::default(),
+            pipeline_too_long: PipelineLengthLimit::default(),
+            magic_number: MagicNumberExceptions::default(),
+            consider_par_each: ParEachCandidates::default(),
+            max_fix_iterations: 10,
+            exclude: Vec::new(),
         }
     }
 }
@@ -85,11 +166,35 @@ impl Config {
 
     /// Load configuration from a TOML string.
     ///
+    /// Options of a rule may be given in a `[rules.<rule_id>]` table, next to
+    /// an optional `level`:
+    ///
+    /// ```toml
+    /// [rules.pipeline_too_long]
+    /// level = "error"
+    /// max_elements = 5
+    /// ```
+    ///
+    /// This is equivalent to `rules.pipeline_too_long = "error"` together
+    /// with `pipeline_too_long.max_elements = 5`.
+    ///
     /// # Errors
     ///
     /// Errors when TOML string is not a valid TOML string.
     pub(crate) fn load_from_str(toml_str: &str) -> Result<Self, LintError> {
-        toml::from_str(toml_str).map_err(|source| LintError::Config { source })
+        let table: toml::Table =
+            toml::from_str(toml_str).map_err(|source| LintError::Config { source })?;
+        let has_rule_tables = table
+            .get("rules")
+            .and_then(toml::Value::as_table)
+            .is_some_and(|rules| rules.values().any(toml::Value::is_table));
+        if !has_rule_tables {
+            // Deserialize the source directly, so errors point at a line
+            return toml::from_str(toml_str).map_err(|source| LintError::Config { source });
+        }
+        split_rule_tables(table)
+            .try_into()
+            .map_err(|source| LintError::Config { source })
     }
     /// Load configuration from a TOML file.
     ///
@@ -106,41 +211,127 @@ impl Config {
         Self::load_from_str(&content)
     }
 
-    /// Validate that no conflicting rules are both enabled
+    /// Validate that all rules exist and that no conflicting rules are both
+    /// enabled
     ///
     /// # Errors
     ///
-    /// Returns an error if two conflicting rules are both enabled.
+    /// Returns the first problem found, see [`Self::validation_errors`].
     pub fn validate(&self) -> Result<(), LintError> {
         log::debug!("Validating loaded configuration.");
+        self.validation_errors()
+            .into_iter()
+            .next()
+            .map_or(Ok(()), Err)
+    }
+
+    /// Collect every unknown rule id and rule conflict instead of stopping at
+    /// the first one. Unknown group names are not included, see
+    /// [`Self::unknown_groups`].
+    #[must_use]
+    pub fn validation_errors(&self) -> Vec<LintError> {
+        let mut errors = Vec::new();
 
-        for rule_id_in_config_file in self.rules.keys() {
-            if USED_RULES
+        let mut configured_rules: Vec<&String> = self.rules.keys().collect();
+        configured_rules.sort();
+        for rule_id_in_config_file in configured_rules {
+            if !USED_RULES
                 .iter()
-                .find(|rule| rule.id() == rule_id_in_config_file)
-                .is_none()
+                .any(|rule| rule.id() == rule_id_in_config_file)
             {
-                return Err(LintError::RuleDoesNotExist {
+                errors.push(LintError::RuleDoesNotExist {
                     non_existing_id: rule_id_in_config_file.clone(),
+                    suggestion: closest_rule_id(rule_id_in_config_file),
                 });
             }
         }
 
+        let mut reported_conflicts: Vec<(&str, &str)> = Vec::new();
         for rule in USED_RULES {
             if self.get_lint_level(*rule) == LintLevel::Off {
                 continue;
             }
 
             for conflicting_rule in rule.conflicts_with() {
-                if self.get_lint_level(*conflicting_rule) > LintLevel::Off {
-                    return Err(LintError::RuleConflict {
+                if self.get_lint_level(*conflicting_rule) > LintLevel::Off
+                    && !reported_conflicts.contains(&(conflicting_rule.id(), rule.id()))
+                {
+                    reported_conflicts.push((rule.id(), conflicting_rule.id()));
+                    errors.push(LintError::RuleConflict {
                         rule_a: rule.id(),
                         rule_b: conflicting_rule.id(),
                     });
                 }
             }
         }
-        Ok(())
+        errors
+    }
+
+    /// Group names in `[groups]` that don't exist. A stale group only makes
+    /// the configuration less useful, so linting warns about it and only
+    /// `--check-config` treats it as an error.
+    #[must_use]
+    pub fn unknown_groups(&self) -> Vec<LintError> {
+        let mut configured_groups: Vec<&String> = self.groups.keys().collect();
+        configured_groups.sort();
+        configured_groups
+            .into_iter()
+            .filter(|group_name| !ALL_GROUPS.iter().any(|group| group.name == **group_name))
+            .map(|group_name| LintError::GroupDoesNotExist {
+                non_existing_id: group_name.clone(),
+            })
+            .collect()
+    }
+
+    /// Check a TOML configuration string and report all problems at once:
+    /// syntax errors and invalid levels, unknown top-level options, unknown
+    /// rule ids or group names, and conflicting rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns every problem found in the configuration.
+    pub fn check_str(toml_str: &str) -> Result<Self, Vec<LintError>> {
+        let config = Self::load_from_str(toml_str).map_err(|e| vec![e])?;
+
+        let mut errors: Vec<LintError> = unknown_keys(toml_str)
+            .into_iter()
+            .map(|key| LintError::UnknownConfigKey { key })
+            .collect();
+        errors.extend(config.validation_errors());
+        errors.extend(config.unknown_groups());
+
+        if errors.is_empty() {
+            Ok(config)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Render the effective configuration as TOML, with the resolved lint
+    /// level of every rule after applying group and rule overrides.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration cannot be serialized.
+    pub fn effective_toml(&self) -> Result<String, LintError> {
+        let mut table = match toml::Value::try_from(self)
+            .map_err(|source| LintError::ConfigSerialize { source })?
+        {
+            toml::Value::Table(table) => table,
+            _ => toml::Table::new(),
+        };
+
+        let levels = USED_RULES
+            .iter()
+            .map(|rule| {
+                let level = toml::Value::try_from(self.get_lint_level(*rule))
+                    .map_err(|source| LintError::ConfigSerialize { source })?;
+                Ok((rule.id().to_string(), level))
+            })
+            .collect::<Result<toml::Table, LintError>>()?;
+        table.insert("rules".to_string(), toml::Value::Table(levels));
+
+        toml::to_string_pretty(&table).map_err(|source| LintError::ConfigSerialize { source })
     }
 
     /// Get the effective lint level for a specific rule
@@ -171,6 +362,73 @@ impl Config {
 
         rule.level()
     }
+
+    /// Apply the `--select` and `--ignore` command line filters on top of the
+    /// configured levels. Each entry is a rule id, a group name or `all`.
+    ///
+    /// With a non-empty `select`, every rule outside the selection is turned
+    /// off. Rules selected by id are enabled even when off by default or in
+    /// the configuration file, while groups and `all` only narrow down the
+    /// rules that are already enabled. Ignored rules are always turned off,
+    /// also when they are selected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the first entry that matches nothing.
+    pub fn apply_rule_selection(
+        &mut self,
+        select: &[String],
+        ignore: &[String],
+    ) -> Result<(), LintError> {
+        let selected = select
+            .iter()
+            .map(String::as_str)
+            .map(rules_matching)
+            .collect::<Result<Vec<_>, _>>()?;
+        let ignored = ignore
+            .iter()
+            .map(String::as_str)
+            .map(rules_matching)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for rule in USED_RULES {
+            let id = rule.id();
+            let level = if ignored.iter().flatten().any(|r| r.id() == id)
+                || (!selected.is_empty() && !selected.iter().flatten().any(|r| r.id() == id))
+            {
+                LintLevel::Off
+            } else if select.iter().any(|selector| selector == id)
+                && self.get_lint_level(*rule) == LintLevel::Off
+            {
+                match rule.level() {
+                    LintLevel::Off => LintLevel::Warning,
+                    level => level,
+                }
+            } else {
+                continue;
+            };
+            self.rules.insert(id.to_string(), level);
+        }
+        Ok(())
+    }
+}
+
+/// Rules matched by a `--select` or `--ignore` entry
+fn rules_matching(selector: &str) -> Result<&'static [&'static dyn Rule], LintError> {
+    if selector == "all" {
+        return Ok(USED_RULES);
+    }
+    if let Some(group) = ALL_GROUPS.iter().find(|group| group.name == selector) {
+        return Ok(group.rules);
+    }
+    USED_RULES
+        .iter()
+        .position(|rule| rule.id() == selector)
+        .map(|index| &USED_RULES[index..=index])
+        .ok_or_else(|| LintError::UnknownRuleSelector {
+            selector: selector.to_string(),
+            suggestion: closest_rule_id(selector),
+        })
 }
 
 /// Search for `.nu-lint.toml` in the given directory, falling back to home
@@ -192,6 +450,88 @@ pub fn find_config_file_from(start_dir: &Path) -> Option<PathBuf> {
     None
 }
 
+/// Move the options in `[rules.<rule_id>]` tables to the top-level table of
+/// the rule and leave only the `level`, if any, in `rules`. Options of rules
+/// without any are dropped with a warning, so configuration files written
+/// for newer versions still load.
+fn split_rule_tables(mut table: toml::Table) -> toml::Table {
+    let Some(toml::Value::Table(rules)) = table.remove("rules") else {
+        return table;
+    };
+    let Ok(toml::Value::Table(known)) = toml::Value::try_from(Config::default()) else {
+        table.insert("rules".to_string(), toml::Value::Table(rules));
+        return table;
+    };
+
+    let mut levels = toml::Table::new();
+    for (rule_id, setting) in rules {
+        let toml::Value::Table(mut options) = setting else {
+            levels.insert(rule_id, setting);
+            continue;
+        };
+        if let Some(level) = options.remove("level") {
+            levels.insert(rule_id.clone(), level);
+        }
+        if options.is_empty() {
+            continue;
+        }
+        if !USED_RULES.iter().any(|rule| rule.id() == rule_id)
+            || !known.get(&rule_id).is_some_and(toml::Value::is_table)
+        {
+            log::warn!("Rule '{rule_id}' has no options, ignoring {options:?}");
+            continue;
+        }
+        match table.get_mut(&rule_id) {
+            Some(toml::Value::Table(existing)) => existing.extend(options),
+            _ => {
+                table.insert(rule_id, toml::Value::Table(options));
+            }
+        }
+    }
+    table.insert("rules".to_string(), toml::Value::Table(levels));
+    table
+}
+
+/// Top-level keys in a TOML configuration string that are not options of
+/// [`Config`]. Serde silently ignores them, which hides typos.
+fn unknown_keys(toml_str: &str) -> Vec<String> {
+    let Ok(table) = toml::from_str::<toml::Table>(toml_str) else {
+        return Vec::new();
+    };
+    let Ok(toml::Value::Table(known)) = toml::Value::try_from(Config::default()) else {
+        return Vec::new();
+    };
+    table
+        .keys()
+        .filter(|key| !known.contains_key(*key))
+        .cloned()
+        .collect()
+}
+
+/// Closest existing rule id to a misspelled one, if any is reasonably close.
+fn closest_rule_id(unknown: &str) -> Option<&'static str> {
+    USED_RULES
+        .iter()
+        .map(|rule| (rule.id(), edit_distance(unknown, rule.id())))
+        .filter(|(_, distance)| *distance <= unknown.len() / 3 + 1)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(id, _)| id)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,9 +550,250 @@ mod tests {
         assert_eq!(config.rules["other_rule"], LintLevel::Off);
     }
 
+    #[test]
+    fn test_load_trailing_comma_style() {
+        let config = Config::load_from_str(r#"trailing_comma = "always""#).unwrap();
+        assert_eq!(config.trailing_comma, TrailingComma::Always);
+        assert_eq!(Config::default().trailing_comma, TrailingComma::Never);
+    }
+
+    #[test]
+    fn test_load_rule_table_with_options() {
+        let toml_str = r#"
+        [rules.pipeline_too_long]
+        level = "error"
+        max_elements = 5
+
+        [rules.magic_number]
+        allowed_values = [0, 1, 2]
+        "#;
+        let config = Config::load_from_str(toml_str).unwrap();
+        assert_eq!(config.rules["pipeline_too_long"], LintLevel::Error);
+        assert_eq!(config.pipeline_too_long.max_elements, 5);
+        assert_eq!(config.magic_number.allowed_values, vec![0, 1, 2]);
+        assert!(
+            !config.rules.contains_key("magic_number"),
+            "a table without level keeps the default level"
+        );
+    }
+
+    #[test]
+    fn test_rule_table_ignores_unknown_options() {
+        let toml_str = r#"
+        [rules.unused_variable]
+        level = "hint"
+        future_option = true
+
+        [rules.pipeline_too_long]
+        max_elements = 5
+        future_option = "x"
+        "#;
+        let config = Config::check_str(toml_str).unwrap();
+        assert_eq!(config.rules["unused_variable"], LintLevel::Hint);
+        assert_eq!(config.pipeline_too_long.max_elements, 5);
+    }
+
+    #[test]
+    fn test_load_pipeline_too_long_threshold() {
+        let config = Config::load_from_str("pipeline_too_long.max_elements = 12").unwrap();
+        assert_eq!(config.pipeline_too_long.max_elements, 12);
+        assert_eq!(Config::default().pipeline_too_long.max_elements, 8);
+    }
+
+    #[test]
+    fn test_load_magic_number_exceptions() {
+        let toml_str = r#"
+        [magic_number]
+        allowed_values = [0, 1, 100]
+        ignored_commands = ["take", "skip"]
+        "#;
+        let config = Config::load_from_str(toml_str).unwrap();
+        assert_eq!(config.magic_number.allowed_values, vec![0, 1, 100]);
+        assert_eq!(config.magic_number.ignored_commands, vec!["take", "skip"]);
+    }
+
+    #[test]
+    fn test_load_par_each_commands() {
+        let config =
+            Config::load_from_str(r#"consider_par_each.expensive_commands = ["convert"]"#).unwrap();
+        assert_eq!(config.consider_par_each.expensive_commands, vec!["convert"]);
+        assert!(
+            Config::default()
+                .consider_par_each
+                .expensive_commands
+                .contains(&"http get".to_string()),
+            "network requests should be expensive by default"
+        );
+    }
+
+    #[test]
+    fn test_check_str_suggests_rule_for_typo() {
+        let toml_str = r#"
+        [rules]
+        unused_variabel = "off"
+    "#;
+
+        let errors = Config::check_str(toml_str).unwrap_err();
+        assert_eq!(
+            errors.len(),
+            1,
+            "Expected exactly one error, got {errors:?}"
+        );
+        let message = errors[0].to_string();
+        assert!(
+            message.contains("`unused_variabel`") && message.contains("`unused_variable`"),
+            "Expected a suggestion for the misspelled rule id, got: {message}"
+        );
+    }
+
+    #[test]
+    fn test_check_str_reports_all_problems() {
+        let toml_str = r#"
+        max_pipline_length = 100
+
+        [groups]
+        performence = "warning"
+
+        [rules]
+        not_a_rule = "error"
+    "#;
+
+        let errors = Config::check_str(toml_str).unwrap_err();
+        let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages.len(),
+            3,
+            "Expected three problems, got {messages:?}"
+        );
+        assert!(
+            messages[0].contains("max_pipline_length"),
+            "Expected unknown option first, got {messages:?}"
+        );
+    }
+
+    #[test]
+    fn test_check_str_rejects_invalid_level() {
+        let toml_str = r#"
+        [rules]
+        unused_variable = "loud"
+    "#;
+
+        assert!(
+            matches!(
+                Config::check_str(toml_str).unwrap_err().as_slice(),
+                [LintError::Config { .. }]
+            ),
+            "Expected a deserialization error for an invalid level"
+        );
+    }
+
+    #[test]
+    fn test_effective_toml_lists_every_rule() {
+        let config = Config::load_from_str(
+            r#"
+        [groups]
+        performance = "error"
+    "#,
+        )
+        .unwrap();
+        let effective = config.effective_toml().unwrap();
+        assert!(
+            effective.contains(r#"chained_append = "error""#),
+            "Group override should be resolved per rule:\n{effective}"
+        );
+        assert!(
+            effective.contains(r#"unused_variable = "warning""#),
+            "Default levels should be listed:\n{effective}"
+        );
+    }
+
     #[test]
     fn test_validate_passes_with_default_config() {
         let result = Config::default().validate();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_unknown_group_only_fails_check() {
+        let toml_str = "[groups]\nperformence = \"warning\"\n";
+        let config = Config::load_from_str(toml_str).unwrap();
+        assert!(config.validate().is_ok(), "linting still runs");
+        assert!(matches!(
+            config.unknown_groups().as_slice(),
+            [LintError::GroupDoesNotExist { non_existing_id }] if non_existing_id == "performence"
+        ));
+        assert_eq!(Config::check_str(toml_str).unwrap_err().len(), 1);
+    }
+
+    fn level_of(config: &Config, rule_id: &str) -> LintLevel {
+        let rule = USED_RULES.iter().find(|rule| rule.id() == rule_id).unwrap();
+        config.get_lint_level(*rule)
+    }
+
+    fn selectors(entries: &[&str]) -> Vec<String> {
+        entries.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_select_restricts_to_rules() {
+        let mut config = Config::default();
+        config
+            .apply_rule_selection(&selectors(&["unused_variable"]), &[])
+            .unwrap();
+        assert_eq!(level_of(&config, "unused_variable"), LintLevel::Warning);
+        assert_eq!(level_of(&config, "cat_to_open"), LintLevel::Off);
+    }
+
+    #[test]
+    fn test_select_by_id_enables_rule_that_is_off() {
+        let mut config = Config::load_from_str("[rules]\nunused_variable = \"off\"").unwrap();
+        config
+            .apply_rule_selection(&selectors(&["unused_variable", "magic_number"]), &[])
+            .unwrap();
+        assert_eq!(level_of(&config, "unused_variable"), LintLevel::Warning);
+        assert_eq!(level_of(&config, "magic_number"), LintLevel::Warning);
+    }
+
+    #[test]
+    fn test_select_group_keeps_configured_levels() {
+        let mut config = Config::load_from_str("[rules]\ncat_to_open = \"error\"").unwrap();
+        config
+            .apply_rule_selection(&selectors(&["posix"]), &[])
+            .unwrap();
+        assert_eq!(level_of(&config, "cat_to_open"), LintLevel::Error);
+        assert_eq!(level_of(&config, "unused_variable"), LintLevel::Off);
+    }
+
+    #[test]
+    fn test_ignore_wins_over_select() {
+        let mut config = Config::default();
+        config
+            .apply_rule_selection(
+                &selectors(&["all"]),
+                &selectors(&["posix", "unused_variable"]),
+            )
+            .unwrap();
+        assert_eq!(level_of(&config, "cat_to_open"), LintLevel::Off);
+        assert_eq!(level_of(&config, "unused_variable"), LintLevel::Off);
+        assert!(
+            USED_RULES
+                .iter()
+                .any(|rule| config.get_lint_level(*rule) > LintLevel::Off),
+            "rules outside the ignored ones stay enabled"
+        );
+    }
+
+    #[test]
+    fn test_unknown_selector_suggests_rule() {
+        let error = Config::default()
+            .apply_rule_selection(&[], &selectors(&["unused_variabel"]))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            LintError::UnknownRuleSelector {
+                suggestion: Some("unused_variable"),
+                ..
+            }
+        ));
+    }
 }
@@ -97,25 +97,6 @@ impl<'a> LintContext<'a> {
         }
     }
 
-    /// Create a new `LintContext` using the default configuration.
-    #[cfg(test)]
-    pub(crate) fn with_default_config(
-        source: &'a str,
-        ast: &'a Block,
-        engine_state: &'a EngineState,
-        working_set: &'a StateWorkingSet<'a>,
-        file_offset: usize,
-    ) -> Self {
-        Self::new(
-            source,
-            ast,
-            engine_state,
-            working_set,
-            file_offset,
-            Config::default_static(),
-        )
-    }
-
     #[must_use]
     pub const unsafe fn source(&self) -> &str {
         self.source
@@ -470,6 +451,16 @@ impl LintContext<'_> {
     /// (e.g. `~/.nu-lint.toml`) do not interfere with test results.
     #[track_caller]
     pub fn test_with_parsed_source<F, R>(source: &str, f: F) -> R
+    where
+        F: for<'b> FnOnce(LintContext<'b>) -> R,
+    {
+        Self::test_with_parsed_source_and_config(source, Config::default_static(), f)
+    }
+
+    /// Helper to create a test context with an explicit configuration, for
+    /// rules whose behaviour depends on configurable options.
+    #[track_caller]
+    pub fn test_with_parsed_source_and_config<F, R>(source: &str, config: &Config, f: F) -> R
     where
         F: for<'b> FnOnce(LintContext<'b>) -> R,
     {
@@ -478,12 +469,13 @@ impl LintContext<'_> {
         let engine_state = LintEngine::new_state();
         let (block, working_set, file_offset) = parse_source(engine_state, source.as_bytes(), None);
 
-        let context = LintContext::with_default_config(
+        let context = LintContext::new(
             source,
             &block,
             engine_state,
             &working_set,
             file_offset,
+            config,
         );
 
         f(context)
@@ -496,7 +488,21 @@ impl LintContext<'_> {
     where
         F: for<'b> FnOnce(&LintContext<'b>) -> Vec<violation::Violation>,
     {
-        Self::test_with_parsed_source(source, |context| {
+        Self::test_get_violations_with_config(source, Config::default_static(), f)
+    }
+
+    /// Same as [`Self::test_get_violations`], but with an explicit
+    /// configuration.
+    #[track_caller]
+    pub fn test_get_violations_with_config<F>(
+        source: &str,
+        config: &Config,
+        f: F,
+    ) -> Vec<violation::Violation>
+    where
+        F: for<'b> FnOnce(&LintContext<'b>) -> Vec<violation::Violation>,
+    {
+        Self::test_with_parsed_source_and_config(source, config, |context| {
             let file_offset = context.file_offset();
             let mut violations = f(&context);
             for v in &mut violations {
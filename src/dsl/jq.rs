@@ -8,7 +8,7 @@ use std::borrow::Cow;
 use jaq_core::{
     load::{
         lex::{Lexer, StrPart},
-        parse::{Parser, Term},
+        parse::{BinaryOp, Parser, Term},
     },
     path::{self, Opt, Part},
 };
@@ -85,6 +85,8 @@ pub enum NuEquivalent {
     GetThenIterate(FieldPath),
     GetIndex(IndexValue),
     CommandWithField { nu_cmd: &'static str, field: String },
+    HasKey(String),
+    WhereComparison(FieldPath, &'static str, String),
     DynamicGet { var_span: Span },
     DynamicGetWithPrefix { prefix: String, var_span: Span },
     DynamicIndex { var_span: Span },
@@ -108,6 +110,10 @@ impl NuEquivalent {
             Self::CommandWithField { nu_cmd, field } => {
                 format!("{nu_cmd} {}", maybe_quote_field(field))
             }
+            Self::WhereComparison(path, op, value) => {
+                format!("where {} {op} {value}", path.as_dotted())
+            }
+            Self::HasKey(key) => format!("\"{key}\" in ($in | columns)"),
             Self::DynamicGet { var_span } | Self::DynamicIndex { var_span } => {
                 format!("get {}", lint_ctx.span_text(*var_span))
             }
@@ -185,12 +191,25 @@ fn convert_builtin(name: &str) -> Option<NuEquivalent> {
         "sort" => "sort",
         "unique" => "uniq",
         "reverse" => "reverse",
+        "keys_unsorted" => "columns",
+        "to_entries" => "transpose key value",
+        "from_entries" => "transpose -r -d",
         _ => return None,
     };
     Some(NuEquivalent::Command(cmd))
 }
 
 fn convert_call_with_arg(name: &str, arg: &Term<&str>) -> Option<NuEquivalent> {
+    if name == "has" {
+        let key = extract_string_literal(arg)?;
+        return Some(NuEquivalent::HasKey(key.to_string()));
+    }
+    if name == "select"
+        && let Term::BinOp(left, BinaryOp::Cmp(cmp), right) = arg
+    {
+        return convert_comparison(left, cmp.as_str(), right);
+    }
+
     let field = extract_single_field_from_term(arg)?;
     let nu_cmd: &'static str = match name {
         "map" => "get",
@@ -205,6 +224,35 @@ fn convert_call_with_arg(name: &str, arg: &Term<&str>) -> Option<NuEquivalent> {
     })
 }
 
+/// `.field OP literal` inside `select`, such as `.age > 30`
+fn convert_comparison(
+    left: &Term<&str>,
+    op: &'static str,
+    right: &Term<&str>,
+) -> Option<NuEquivalent> {
+    let Term::Path(inner, path) = left else {
+        return None;
+    };
+    if !matches!(**inner, Term::Id) || !is_all_field_access(&path.0) {
+        return None;
+    }
+    let path = FieldPath::from_segments(extract_field_names(&path.0)?)?;
+
+    let value = match right {
+        Term::Num(n) => (*n).to_string(),
+        Term::Neg(inner) => match &**inner {
+            Term::Num(n) => format!("-{n}"),
+            _ => return None,
+        },
+        Term::Call(name @ ("true" | "false" | "null"), args) if args.is_empty() => {
+            (*name).to_string()
+        }
+        _ => format!("\"{}\"", extract_string_literal(right)?),
+    };
+
+    Some(NuEquivalent::WhereComparison(path, op, value))
+}
+
 fn convert_path(path: &path::Path<Term<&str>>) -> Option<NuEquivalent> {
     let parts = &path.0;
 
@@ -262,6 +310,16 @@ fn extract_single_field<'a>(part: &Part<Term<&'a str>>) -> Option<&'a str> {
     }
 }
 
+fn extract_string_literal<'a>(term: &Term<&'a str>) -> Option<&'a str> {
+    if let Term::Str(None, str_parts) = term
+        && let [StrPart::Str(s)] = str_parts.as_slice()
+    {
+        Some(*s)
+    } else {
+        None
+    }
+}
+
 fn extract_single_field_from_term<'a>(term: &'a Term<&str>) -> Option<&'a str> {
     if let Term::Path(inner, path) = term
         && matches!(**inner, Term::Id)
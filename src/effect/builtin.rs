@@ -1,6 +1,10 @@
-use nu_protocol::ast::{Argument, Call};
+use nu_protocol::{
+    Category,
+    ast::{Argument, Call},
+};
 
 use crate::{
+    ast::call::CallExt,
     context::LintContext,
     effect::{CommonEffect, is_dangerous_path},
 };
@@ -34,6 +38,37 @@ pub fn has_builtin_side_effect(
         .unwrap_or(false)
 }
 
+/// Whether the call only computes a value: a built-in data command that has
+/// none of the effects listed in [`BUILTIN_COMMAND_SIDE_EFFECTS`]. Custom
+/// commands are never considered side-effect free.
+pub fn is_side_effect_free(call: &Call, context: &LintContext) -> bool {
+    let decl = context.working_set.get_decl(call.decl_id);
+    if decl.block_id().is_some() {
+        return false;
+    }
+    let is_data_command = matches!(
+        decl.signature().category,
+        Category::Bits
+            | Category::Conversions
+            | Category::Date
+            | Category::Filters
+            | Category::Formats
+            | Category::Generators
+            | Category::Hash
+            | Category::Math
+            | Category::Strings
+    );
+    let command_name = call.get_call_name(context);
+    is_data_command
+        && ![
+            BuiltinEffect::PrintToStdout,
+            BuiltinEffect::CommonEffect(CommonEffect::FailsInNormalCircumstances),
+            BuiltinEffect::CommonEffect(CommonEffect::MayCauseDataLoss),
+        ]
+        .into_iter()
+        .any(|effect| has_builtin_side_effect(&command_name, effect, context, call))
+}
+
 pub fn has_recursive_flag(call: &Call, context: &LintContext) -> bool {
     call.arguments.iter().any(|arg| {
         let arg_text = extract_arg_text(arg, context);
@@ -77,7 +112,7 @@ fn io_category_can_error(context: &LintContext, call: &Call) -> bool {
             .get_decl(call.decl_id)
             .signature()
             .category,
-        nu_protocol::Category::Network | nu_protocol::Category::FileSystem
+        Category::Network | Category::FileSystem
     )
 }
 
@@ -98,7 +133,6 @@ fn http_can_error(_context: &LintContext, call: &Call) -> bool {
 }
 
 fn print_to_stdout(_context: &LintContext, call: &Call) -> bool {
-    use crate::ast::call::CallExt;
     !call.has_named_flag("stderr")
 }
 
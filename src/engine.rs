@@ -2,10 +2,10 @@ use std::{
     env, fs,
     io::{self, BufRead},
     path::{Path, PathBuf},
-    sync::{LazyLock, Mutex},
+    sync::LazyLock,
 };
 
-use ::ignore::WalkBuilder;
+use ::ignore::{WalkBuilder, overrides::OverrideBuilder};
 use nu_parser::parse;
 use nu_protocol::{
     Span, Value,
@@ -16,13 +16,33 @@ use rayon::prelude::*;
 
 use crate::{
     LintError, LintLevel,
+    cache::LintCache,
     config::Config,
     context::LintContext,
     ignore,
+    log::{buffered, write_buffered},
     rules::USED_RULES,
     violation::{SourceFile, Violation},
 };
 
+/// Keep at most `max_violations` violations, returning whether any were
+/// dropped.
+pub fn cap_violations(violations: &mut Vec<Violation>, max_violations: Option<usize>) -> bool {
+    let Some(max) = max_violations else {
+        return false;
+    };
+    let truncated = violations.len() > max;
+    violations.truncate(max);
+    truncated
+}
+
+fn read_source(path: &Path) -> Result<String, LintError> {
+    fs::read_to_string(path).map_err(|source| LintError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
 /// Parse Nushell source code into an AST and return both the Block and
 /// `StateWorkingSet`, along with the file's starting offset in the span space.
 pub fn parse_source<'a>(
@@ -68,11 +88,48 @@ fn is_nushell_file(path: &Path) -> bool {
             .unwrap_or(false)
 }
 
-/// Collect .nu files from a directory, respecting git ignore files
+/// Which files are skipped when collecting the files of a directory
+#[derive(Debug, Clone)]
+pub struct FileFilter {
+    /// Skip files ignored by `.gitignore`, `.ignore` and similar files
+    pub respect_ignore_files: bool,
+    /// Gitignore-style globs of paths to skip, relative to the directory
+    pub exclude: Vec<String>,
+}
+
+impl Default for FileFilter {
+    fn default() -> Self {
+        Self {
+            respect_ignore_files: true,
+            exclude: Vec::new(),
+        }
+    }
+}
+
+/// Collect .nu files from a directory, respecting git ignore files and the
+/// excluded globs of `filter`. Files are sorted by path, so output doesn't
+/// depend on the directory order of the file system.
 #[must_use]
-pub fn collect_nu_files_from_dir(dir: &Path) -> Vec<PathBuf> {
-    WalkBuilder::new(dir)
-        .standard_filters(true)
+pub fn collect_nu_files_from_dir(dir: &Path, filter: &FileFilter) -> Vec<PathBuf> {
+    let mut overrides = OverrideBuilder::new(dir);
+    for glob in &filter.exclude {
+        if let Err(err) = overrides.add(&format!("!{glob}")) {
+            log::warn!("Invalid exclude pattern '{glob}': {err}");
+        }
+    }
+    // Hidden files are skipped either way
+    let mut walker = WalkBuilder::new(dir);
+    walker
+        .standard_filters(filter.respect_ignore_files)
+        .hidden(true);
+    match overrides.build() {
+        Ok(overrides) => {
+            walker.overrides(overrides);
+        }
+        Err(err) => log::warn!("Invalid exclude patterns: {err}"),
+    }
+
+    let mut files: Vec<PathBuf> = walker
         .build()
         .filter_map(|result| match result {
             Ok(entry) => {
@@ -84,7 +141,9 @@ pub fn collect_nu_files_from_dir(dir: &Path) -> Vec<PathBuf> {
                 None
             }
         })
-        .collect()
+        .collect();
+    files.sort();
+    files
 }
 
 /// Collect all Nushell files to lint from given paths
@@ -93,6 +152,14 @@ pub fn collect_nu_files_from_dir(dir: &Path) -> Vec<PathBuf> {
 /// For directories: recursively collects `.nu` files, respecting `.gitignore`
 #[must_use]
 pub fn collect_nu_files(paths: &[PathBuf]) -> Vec<PathBuf> {
+    collect_nu_files_filtered(paths, &FileFilter::default())
+}
+
+/// Collect all Nushell files to lint from given paths like
+/// [`collect_nu_files`], skipping files in directories according to
+/// `filter`. Files given explicitly are always included.
+#[must_use]
+pub fn collect_nu_files_filtered(paths: &[PathBuf], filter: &FileFilter) -> Vec<PathBuf> {
     paths
         .iter()
         .flat_map(|path| {
@@ -108,7 +175,7 @@ pub fn collect_nu_files(paths: &[PathBuf]) -> Vec<PathBuf> {
                     vec![]
                 }
             } else if path.is_dir() {
-                collect_nu_files_from_dir(path)
+                collect_nu_files_from_dir(path, filter)
             } else {
                 vec![]
             }
@@ -172,16 +239,26 @@ impl LintEngine {
     ///
     /// Returns an error if the file cannot be read.
     pub(crate) fn lint_file(&self, path: &Path) -> Result<Vec<Violation>, LintError> {
+        Ok(self.lint_file_source(path, &read_source(path)?))
+    }
+
+    /// Lint the already read content of the file at `path`
+    fn lint_file_source(&self, path: &Path, source: &str) -> Vec<Violation> {
         log::debug!("Linting file: {}", path.display());
-        let source = fs::read_to_string(path).map_err(|source| LintError::Io {
-            path: path.to_path_buf(),
-            source,
-        })?;
+        let ignore_index = ignore::IgnoreIndex::new(source);
+        if ignore_index.disables_file() {
+            log::info!(
+                "Skipping {}: linting is disabled by `# nu-lint: disable-file`",
+                path.display()
+            );
+            return Vec::new();
+        }
 
         let file_path = fs::canonicalize(path).ok();
         let (block, working_set, file_offset) =
             parse_source(self.engine_state, source.as_bytes(), file_path.as_deref());
-        let mut violations = self.lint_parsed(&source, &block, &working_set, file_offset);
+        let mut violations =
+            self.lint_parsed(source, &ignore_index, &block, &working_set, file_offset);
 
         for violation in &mut violations {
             violation.file = Some(path.into());
@@ -193,41 +270,107 @@ impl LintEngine {
                 .cmp(&b.file_span().start)
                 .then(a.lint_level.cmp(&b.lint_level))
         });
-        Ok(violations)
+        violations
+    }
+
+    /// Lint the file unless the cache knows its content to have no
+    /// violations, returning the cache key of the content alongside
+    fn lint_file_keyed(
+        &self,
+        path: &Path,
+        cache: Option<&LintCache>,
+    ) -> Result<(Option<u64>, Vec<Violation>), LintError> {
+        let Some(cache) = cache else {
+            return self.lint_file(path).map(|violations| (None, violations));
+        };
+        let source = read_source(path)?;
+        let key = LintCache::key(&source);
+        if cache.is_clean(path, key) {
+            log::debug!("Skipping {}: unchanged without violations", path.display());
+            return Ok((Some(key), Vec::new()));
+        }
+        Ok((Some(key), self.lint_file_source(path, &source)))
     }
 
     /// Lint multiple files, optionally in parallel
-    ///
-    /// Returns a tuple of (violations, `has_errors`) where `has_errors`
-    /// indicates if any files failed to be read/parsed.
     #[must_use]
     pub fn lint_files(&self, files: &[PathBuf]) -> Vec<Violation> {
-        let violations_mutex = Mutex::new(Vec::new());
-
-        let process_file = |path: &PathBuf| match self.lint_file(path) {
-            Ok(violations) => {
-                violations_mutex
-                    .lock()
-                    .expect("Failed to lock violations mutex")
-                    .extend(violations);
-            }
-            Err(e) => {
-                log::error!("Error linting {}: {}", path.display(), e);
-            }
+        self.lint_files_capped(files, None).0
+    }
+
+    /// Lint multiple files, stopping once more than `max_violations` have been
+    /// found.
+    ///
+    /// Violations are kept in the order of `files`, so the same ones are
+    /// returned whether files are linted in parallel or not. With a cap, files
+    /// are linted in batches of one per worker thread and no further batches
+    /// are started once the cap is exceeded. The returned flag tells whether
+    /// violations were dropped.
+    #[must_use]
+    pub fn lint_files_capped(
+        &self,
+        files: &[PathBuf],
+        max_violations: Option<usize>,
+    ) -> (Vec<Violation>, bool) {
+        self.lint_files_cached(files, max_violations, None)
+    }
+
+    /// Like [`Self::lint_files_capped`], but skip the files the cache knows
+    /// to have no violations, and record the outcome of every linted file in
+    /// it. Keys are computed from the content that is linted, so files edited
+    /// during the run are not remembered with the wrong content.
+    pub(crate) fn lint_files_cached(
+        &self,
+        files: &[PathBuf],
+        max_violations: Option<usize>,
+        mut cache: Option<&mut LintCache>,
+    ) -> (Vec<Violation>, bool) {
+        let known = cache.as_deref();
+        // Log output is written per file after each batch, so the output of
+        // files linted in parallel does not interleave
+        let process_file = |path: &PathBuf| {
+            buffered(|| {
+                self.lint_file_keyed(path, known).unwrap_or_else(|e| {
+                    log::error!("Error linting {}: {}", path.display(), e);
+                    (None, Vec::new())
+                })
+            })
+        };
+
+        let batch_size = match (self.config.sequential, max_violations) {
+            (true, _) => 1,
+            (false, None) => files.len(),
+            (false, Some(_)) => rayon::current_num_threads(),
         };
 
-        if self.config.sequential {
-            for path in files {
-                log::debug!("Processing file: {}", path.display());
-                process_file(path);
+        let mut violations = Vec::new();
+        let mut outcomes = Vec::new();
+        for batch in files.chunks(batch_size.max(1)) {
+            let results: Vec<_> = if self.config.sequential {
+                batch.iter().map(process_file).collect()
+            } else {
+                batch.par_iter().map(process_file).collect()
+            };
+            for (path, ((key, file_violations), logs)) in batch.iter().zip(results) {
+                write_buffered(&logs);
+                if let Some(key) = key {
+                    outcomes.push((path, key, file_violations.is_empty()));
+                }
+                violations.extend(file_violations);
+            }
+
+            if max_violations.is_some_and(|max| violations.len() > max) {
+                break;
             }
-        } else {
-            files.par_iter().for_each(process_file);
         }
 
-        violations_mutex
-            .into_inner()
-            .expect("Failed to unwrap violations mutex")
+        if let Some(cache) = cache.as_deref_mut() {
+            for (path, key, clean) in outcomes {
+                cache.record(path, key, clean);
+            }
+        }
+        let truncated = cap_violations(&mut violations, max_violations);
+        (violations, truncated)
     }
 
     /// Lint content from standard input
@@ -246,14 +389,21 @@ impl LintEngine {
 
     #[must_use]
     pub fn lint_str(&self, source: &str) -> Vec<Violation> {
+        let ignore_index = ignore::IgnoreIndex::new(source);
+        if ignore_index.disables_file() {
+            log::info!("Skipping source: linting is disabled by `# nu-lint: disable-file`");
+            return Vec::new();
+        }
+
         let (block, working_set, file_offset) =
             parse_source(self.engine_state, source.as_bytes(), None);
-        self.lint_parsed(source, &block, &working_set, file_offset)
+        self.lint_parsed(source, &ignore_index, &block, &working_set, file_offset)
     }
 
     fn lint_parsed(
         &self,
         source: &str,
+        ignore_index: &ignore::IgnoreIndex,
         block: &Block,
         working_set: &StateWorkingSet,
         file_offset: usize,
@@ -273,7 +423,6 @@ impl LintEngine {
             violation.normalize_spans(file_offset);
         }
 
-        let ignore_index = ignore::IgnoreIndex::new(source);
         violations
             .into_iter()
             .filter(|v| {
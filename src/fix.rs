@@ -1,11 +1,17 @@
 use std::{
-    cmp::Reverse, collections::HashMap, fmt::Write, fs, io::Error as IoError, path::PathBuf,
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::Write,
+    fs,
+    io::Error as IoError,
+    path::PathBuf,
     vec::Vec,
 };
 
 use crate::{
     engine::LintEngine,
-    format::format_diff_context,
+    format::format_unified_diff,
+    hash::fnv1a,
     violation::{Fix, Violation},
 };
 
@@ -16,11 +22,49 @@ pub struct FixResult {
     pub original_content: String,
     pub fixed_content: String,
     pub fixes_applied: usize,
+    /// Fixes left out because their replacements conflict
+    pub skipped: Vec<SkippedFix>,
+    /// Rules still firing when fixing did not converge
+    pub unconverged_rules: Vec<String>,
+}
+
+/// A fix that was not applied because its replacements overlap those of
+/// another fix, or each other
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedFix {
+    pub rule_id: String,
+    pub explanation: String,
+}
+
+impl SkippedFix {
+    fn new(violation: &Violation) -> Self {
+        Self {
+            rule_id: violation
+                .rule_id
+                .as_deref()
+                .unwrap_or("unknown")
+                .to_string(),
+            explanation: violation
+                .fix
+                .as_ref()
+                .map(|fix| fix.explanation.to_string())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn log(&self) {
+        log::warn!(
+            "Skipped fix from rule '{}' due to conflicting replacements: {}",
+            self.rule_id,
+            self.explanation
+        );
+    }
 }
 
 /// Apply fixes to standard input content
 ///
 /// Returns the fixed content as a string
+#[cfg(test)]
 #[must_use]
 pub fn apply_fixes_to_stdin(violations: &[Violation]) -> Option<String> {
     // Filter violations that come from standard input and have fixes
@@ -44,7 +88,7 @@ pub fn apply_fixes_to_stdin(violations: &[Violation]) -> Option<String> {
         .and_then(|v| v.source.as_ref())
         .map(std::borrow::Cow::as_ref)?;
 
-    let fixed_content = apply_fixes_to_content(original_content, &stdin_violations);
+    let (fixed_content, _skipped) = apply_fixes_to_content(original_content, &stdin_violations);
 
     Some(fixed_content)
 }
@@ -76,7 +120,12 @@ fn apply_fix_to_file(
     let original_content = fs::read_to_string(file_path)?;
 
     // Apply fixes iteratively, re-linting after each fix
-    let (fixed_content, fixes_applied) = apply_fixes_iteratively(&original_content, lint_engine);
+    let IterativeFixResult {
+        content: fixed_content,
+        fixes_applied,
+        skipped,
+        unconverged_rules,
+    } = apply_fixes_iteratively(&original_content, lint_engine);
 
     log::debug!(
         "File: {}, Fixes: {}, Original len: {}, Fixed len: {}",
@@ -99,71 +148,162 @@ fn apply_fix_to_file(
         original_content,
         fixed_content,
         fixes_applied,
+        skipped,
+        unconverged_rules,
     })
 }
 
-/// Apply fixes iteratively, re-linting after each fix to get fresh spans
+/// Outcome of [`apply_fixes_iteratively`]
+#[derive(Debug)]
+pub struct IterativeFixResult {
+    pub content: String,
+    pub fixes_applied: usize,
+    /// Fixes left out because their replacements overlap each other
+    pub skipped: Vec<SkippedFix>,
+    /// Rules that still had fixes to apply when fixing stopped, because the
+    /// iteration limit was reached or the fixes started undoing each other.
+    /// Empty when fixing converged.
+    pub unconverged_rules: Vec<String>,
+}
+
+impl IterativeFixResult {
+    /// Whether fixing stopped because no fixable violations were left
+    #[must_use]
+    pub const fn converged(&self) -> bool {
+        self.unconverged_rules.is_empty()
+    }
+}
+
+/// Apply fixes in passes, re-linting after each pass to get fresh spans.
+///
+/// A pass applies all fixes that do not conflict with each other, see
+/// [`partition_fixes`]; the conflicting ones are retried in the next pass.
+/// At most `max_fix_iterations` passes of the configuration are made. Fixing
+/// also stops when a pass leaves the content unchanged or turns it back into
+/// an earlier version, which happens when the fixes of two rules trigger each
+/// other. The rules still firing are then reported in the result.
 #[must_use]
-pub fn apply_fixes_iteratively(content: &str, lint_engine: &LintEngine) -> (String, usize) {
+pub fn apply_fixes_iteratively(content: &str, lint_engine: &LintEngine) -> IterativeFixResult {
+    let max_passes = lint_engine.config.max_fix_iterations;
     let mut current_content = content.to_string();
+    let mut seen = HashSet::from([content_hash(content)]);
     let mut total_fixes_applied = 0;
-    let max_iterations = 100; // Prevent infinite loops
+    let mut skipped: Vec<SkippedFix> = Vec::new();
+    let mut converged = false;
 
-    for iteration in 0..max_iterations {
+    for pass in 0..max_passes {
         // Re-lint the current content to get violations with fresh spans
         let violations = lint_engine.lint_str(&current_content);
+        let fixable = applicable_fixes(&violations, &mut skipped);
 
-        // Find the first violation that has a fix
-        let fixable_violation = violations.iter().find(|v| v.fix.is_some());
-
-        if fixable_violation.is_none() {
-            // No more fixes to apply
+        if fixable.is_empty() {
             log::debug!(
-                "Iterative fix complete after {iteration} iterations, {total_fixes_applied} fixes \
-                 applied"
+                "Iterative fix complete after {pass} passes, {total_fixes_applied} fixes applied"
             );
+            converged = true;
             break;
         }
 
-        // Apply just the first fix
-        let violation = fixable_violation.unwrap();
-        let fix = violation.fix.as_ref().unwrap();
-
-        // Apply all replacements from this one fix
-        let new_content = apply_single_fix_to_content(&current_content, fix);
+        // Fixes conflicting with an applied one get fresh spans in the next pass
+        let (applied, _) = partition_fixes(&fixable);
+        let fixes: Vec<&Fix> = applied.iter().filter_map(|v| v.fix.as_ref()).collect();
+        let new_content = apply_replacements(&current_content, &fixes);
 
-        if new_content == current_content {
-            log::warn!("Fix did not change content, stopping to avoid infinite loop");
+        if !seen.insert(content_hash(&new_content)) {
+            log::warn!(
+                "Fixes from rules {} did not change content or undid earlier fixes, stopping to \
+                 avoid infinite loop",
+                rules_still_firing(&violations).join(", ")
+            );
             break;
         }
 
         current_content = new_content;
-        total_fixes_applied += 1;
+        total_fixes_applied += applied.len();
 
         log::debug!(
-            "Applied fix {} from rule '{}' at iteration {}",
-            total_fixes_applied,
-            violation.rule_id.as_deref().unwrap_or("unknown"),
-            iteration
+            "Applied {} fixes in pass {pass}, {total_fixes_applied} in total",
+            applied.len()
         );
     }
 
-    if total_fixes_applied >= max_iterations {
-        log::warn!("Reached maximum iteration limit ({max_iterations})");
+    let unconverged_rules = if converged {
+        Vec::new()
+    } else {
+        rules_still_firing(&lint_engine.lint_str(&current_content))
+    };
+    if !unconverged_rules.is_empty() {
+        log::warn!(
+            "Fixes did not converge after {total_fixes_applied} fixes (limit {max_passes} \
+             passes), rules still firing: {}",
+            unconverged_rules.join(", ")
+        );
     }
 
-    (current_content, total_fixes_applied)
+    IterativeFixResult {
+        content: current_content,
+        fixes_applied: total_fixes_applied,
+        skipped,
+        unconverged_rules,
+    }
 }
 
-/// Apply a single fix's replacements to content
-fn apply_single_fix_to_content(content: &str, fix: &Fix) -> String {
-    let mut replacements = fix.replacements.clone();
+fn content_hash(content: &str) -> u64 {
+    fnv1a(&[content.as_bytes()])
+}
+
+/// Sorted rule ids of the violations with a fix that could be applied
+fn rules_still_firing(violations: &[Violation]) -> Vec<String> {
+    let rules: BTreeSet<&str> = violations
+        .iter()
+        .filter(|v| {
+            v.fix
+                .as_ref()
+                .is_some_and(|fix| !has_overlapping_replacements(fix))
+        })
+        .map(|v| v.rule_id.as_deref().unwrap_or("unknown"))
+        .collect();
+    rules.into_iter().map(String::from).collect()
+}
+
+/// Violations with a fix that can be applied on its own. A fix whose own
+/// replacements overlap is never applied; those are recorded in `skipped`
+/// once.
+fn applicable_fixes<'a>(
+    violations: &'a [Violation],
+    skipped: &mut Vec<SkippedFix>,
+) -> Vec<&'a Violation> {
+    let mut fixable = Vec::new();
+    for violation in violations {
+        let Some(fix) = &violation.fix else {
+            continue;
+        };
+        if !has_overlapping_replacements(fix) {
+            fixable.push(violation);
+            continue;
+        }
+        let note = SkippedFix::new(violation);
+        if !skipped.contains(&note) {
+            note.log();
+            skipped.push(note);
+        }
+    }
+    fixable
+}
+
+/// Apply the replacements of fixes that do not overlap to content
+fn apply_replacements(content: &str, fixes: &[&Fix]) -> String {
+    let mut replacements: Vec<_> = fixes
+        .iter()
+        .flat_map(|fix| fix.replacements.iter())
+        .collect();
 
     if replacements.is_empty() {
         return content.to_string();
     }
 
-    // Sort replacements by span start in reverse order
+    // Sort replacements by span start in reverse order to apply from end to start
+    // This ensures that earlier positions remain valid as we modify the string
     replacements.sort_by_key(|b| Reverse(b.file_span().start));
 
     let mut result = content.to_string();
@@ -213,59 +353,129 @@ fn group_violations_by_file(violations: &[Violation]) -> HashMap<PathBuf, Vec<&V
     grouped
 }
 
-/// Apply fixes to source code content
-fn apply_fixes_to_content(content: &str, violations: &[&Violation]) -> String {
-    // Collect all replacements from all violations
-    let mut replacements = Vec::new();
-    for violation in violations {
-        if let Some(fix) = &violation.fix {
-            replacements.extend(fix.replacements.clone());
-        }
-    }
-
-    if replacements.is_empty() {
-        return content.to_string();
-    }
-
-    // Sort replacements by span start in reverse order to apply from end to start
-    // This ensures that earlier positions remain valid as we modify the string
-    replacements.sort_by_key(|b| Reverse(b.file_span().start));
+/// Start and end of each replacement of a fix
+fn replacement_spans(fix: &Fix) -> Vec<(usize, usize)> {
+    fix.replacements
+        .iter()
+        .map(|r| (r.file_span().start, r.file_span().end))
+        .collect()
+}
 
-    // Deduplicate replacements with identical spans
-    // This prevents applying the same fix multiple times
-    replacements.dedup_by(|a, b| {
-        a.file_span().start == b.file_span().start && a.file_span().end == b.file_span().end
-    });
+/// Whether two replacement spans cannot both be applied. Identical spans
+/// count as overlapping, so two insertions at the same position conflict.
+fn spans_overlap((start, end): (usize, usize), (other_start, other_end): (usize, usize)) -> bool {
+    (start, end) == (other_start, other_end) || (start < other_end && other_start < end)
+}
 
-    let mut result = content.to_string();
-    let content_bytes = content.as_bytes();
+/// Whether replacements of one fix overlap. Several insertions at the same
+/// position are fine within a fix, since they are applied in a fixed order.
+fn has_overlapping_replacements(fix: &Fix) -> bool {
+    let spans = replacement_spans(fix);
+    spans.iter().enumerate().any(|(i, &(start, end))| {
+        spans[i + 1..]
+            .iter()
+            .any(|&(other_start, other_end)| start < other_end && other_start < end)
+    })
+}
 
-    for replacement in replacements {
-        let start = replacement.file_span().start;
-        let end = replacement.file_span().end;
+/// Whether two fixes make exactly the same replacements
+fn same_replacements(fix: &Fix, other: &Fix) -> bool {
+    fix.replacements.len() == other.replacements.len()
+        && fix
+            .replacements
+            .iter()
+            .zip(&other.replacements)
+            .all(|(a, b)| {
+                a.file_span() == b.file_span() && a.replacement_text == b.replacement_text
+            })
+}
 
-        // Validate span bounds against original content
-        if start > content_bytes.len() || end > content_bytes.len() || start > end {
-            log::warn!(
-                "Invalid replacement span: start={}, end={}, content_len={}",
-                start,
-                end,
-                content_bytes.len()
-            );
+/// Split violations with fixes into those whose fixes can be applied together
+/// in one pass and those whose fixes are skipped because they conflict.
+///
+/// Fixes are taken in order of the start of their violation: of two fixes
+/// with overlapping replacements the first is applied and the second skipped.
+/// A fix whose own replacements overlap is skipped as well. A fix making
+/// exactly the same replacements as an applied one is in neither list, since
+/// applying the first already has its effect.
+#[must_use]
+pub fn partition_fixes<'a>(
+    violations: &[&'a Violation],
+) -> (Vec<&'a Violation>, Vec<&'a Violation>) {
+    let mut sorted: Vec<&Violation> = violations.to_vec();
+    sorted.sort_by_key(|v| v.file_span().start);
+
+    let mut taken: Vec<(usize, usize)> = Vec::new();
+    let mut applied: Vec<&Violation> = Vec::new();
+    let mut skipped = Vec::new();
+    for violation in sorted {
+        let Some(fix) = &violation.fix else {
             continue;
-        }
-
-        // Check UTF-8 boundaries
-        if !result.is_char_boundary(start) || !result.is_char_boundary(end) {
-            log::warn!("Replacement span not on UTF-8 boundary: start={start}, end={end}");
+        };
+        if applied
+            .iter()
+            .filter_map(|v| v.fix.as_ref())
+            .any(|other| same_replacements(fix, other))
+        {
             continue;
         }
-
-        // Apply the replacement to the result string
-        result.replace_range(start..end, &replacement.replacement_text);
+        let spans = replacement_spans(fix);
+        let conflicts = has_overlapping_replacements(fix)
+            || spans
+                .iter()
+                .any(|&span| taken.iter().any(|&other| spans_overlap(span, other)));
+        if conflicts {
+            skipped.push(violation);
+        } else {
+            taken.extend(spans);
+            applied.push(violation);
+        }
     }
+    (applied, skipped)
+}
 
-    result
+/// Select the fixes that can be applied together in one pass, see
+/// [`partition_fixes`]
+#[must_use]
+pub fn non_overlapping_fixes<'a>(violations: &[&'a Violation]) -> Vec<&'a Fix> {
+    partition_fixes(violations)
+        .0
+        .into_iter()
+        .filter_map(|v| v.fix.as_ref())
+        .collect()
+}
+
+/// Apply fixes to source code content, returning the fixes skipped because
+/// they conflict
+#[cfg(test)]
+fn apply_fixes_to_content(content: &str, violations: &[&Violation]) -> (String, Vec<SkippedFix>) {
+    let (applied, conflicting) = partition_fixes(violations);
+    let skipped: Vec<SkippedFix> = conflicting.into_iter().map(SkippedFix::new).collect();
+    skipped.iter().for_each(SkippedFix::log);
+
+    let fixes: Vec<&Fix> = applied.iter().filter_map(|v| v.fix.as_ref()).collect();
+    (apply_replacements(content, &fixes), skipped)
+}
+
+/// List the fixes of a file that were skipped and the rules that kept
+/// firing when fixing did not converge
+fn write_skipped_fixes(output: &mut String, result: &FixResult) {
+    for skipped in &result.skipped {
+        writeln!(
+            output,
+            "    skipped fix from `{}` (conflicting replacements): {}",
+            skipped.rule_id, skipped.explanation
+        )
+        .unwrap();
+    }
+    if !result.unconverged_rules.is_empty() {
+        writeln!(
+            output,
+            "    fixes did not converge, rules still firing: {}",
+            result.unconverged_rules.join(", ")
+        )
+        .unwrap();
+    }
 }
 
 /// Format fix results for output
@@ -289,10 +499,16 @@ pub fn format_fix_results(results: &[FixResult], dry_run: bool) -> String {
 
         for result in results {
             writeln!(output, "File: {}", result.file_path.display()).unwrap();
-            writeln!(output, "Fixes to apply: {}\n", result.fixes_applied).unwrap();
+            writeln!(output, "Fixes to apply: {}", result.fixes_applied).unwrap();
+            write_skipped_fixes(&mut output, result);
+            output.push('\n');
 
-            // Generate and display unified diff
-            let diff = format_diff_context(&result.original_content, &result.fixed_content);
+            let diff = format_unified_diff(
+                &result.file_path.display().to_string(),
+                &result.original_content,
+                &result.fixed_content,
+                true,
+            );
             output.push_str(&diff);
             output.push('\n');
         }
@@ -314,6 +530,7 @@ pub fn format_fix_results(results: &[FixResult], dry_run: bool) -> String {
                 if result.fixes_applied == 1 { "" } else { "es" }
             )
             .unwrap();
+            write_skipped_fixes(&mut output, result);
         }
     }
 
@@ -361,8 +578,135 @@ mod tests {
             external_detections: vec![],
         };
 
-        let fixed = apply_fixes_to_content(content, &[&violation]);
+        let (fixed, skipped) = apply_fixes_to_content(content, &[&violation]);
         assert_eq!(fixed, "let a = 5; let b = 10");
+        assert!(skipped.is_empty());
+    }
+
+    fn fix_violation(start: usize, replacements: Vec<Replacement>) -> Violation {
+        use crate::span::FileSpan;
+
+        Violation {
+            rule_id: Some(Cow::Borrowed("test_rule")),
+            lint_level: Severity::Warning,
+            message: Cow::Borrowed("Test"),
+            span: FileSpan::new(start, start + 1).into(),
+            primary_label: None,
+            extra_labels: vec![],
+            long_description: None,
+            fix: Some(Fix {
+                explanation: "Test fix".into(),
+                replacements,
+            }),
+            file: Some(SourceFile::from("test.nu")),
+            source: None,
+            doc_url: None,
+            short_description: None,
+            diagnostic_tags: vec![],
+            external_detections: vec![],
+        }
+    }
+
+    #[test]
+    fn test_non_overlapping_fixes_skip_whole_conflicting_fix() {
+        use crate::span::FileSpan;
+
+        let content = "let x = 5; let y = 10";
+        let first = fix_violation(
+            4,
+            vec![Replacement::with_file_span(FileSpan::new(4, 5), "a")],
+        );
+        // Overlaps the first fix in one of its two replacements
+        let second = fix_violation(
+            4,
+            vec![
+                Replacement::with_file_span(FileSpan::new(4, 9), "b = 6"),
+                Replacement::with_file_span(FileSpan::new(15, 16), "c"),
+            ],
+        );
+        let third = fix_violation(
+            19,
+            vec![Replacement::with_file_span(FileSpan::new(19, 21), "11")],
+        );
+        let duplicate = fix_violation(
+            4,
+            vec![Replacement::with_file_span(FileSpan::new(4, 5), "a")],
+        );
+
+        let violations = [&third, &first, &second, &duplicate];
+        let (applied, skipped) = partition_fixes(&violations);
+        assert!(applied.iter().map(|v| v.file_span().start).eq([4, 19]));
+        assert_eq!(skipped.len(), 1, "only the overlapping fix is skipped");
+        assert_eq!(
+            skipped[0].fix.as_ref().unwrap().replacements[0].replacement_text,
+            "b = 6"
+        );
+        assert_eq!(non_overlapping_fixes(&violations).len(), 2);
+
+        let (fixed, skipped) = apply_fixes_to_content(content, &violations);
+        assert_eq!(fixed, "let a = 5; let y = 11");
+        assert_eq!(
+            skipped,
+            [SkippedFix {
+                rule_id: "test_rule".to_string(),
+                explanation: "Test fix".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_fix_with_overlapping_replacements_is_skipped() {
+        use crate::span::FileSpan;
+
+        let conflicting = fix_violation(
+            0,
+            vec![
+                Replacement::with_file_span(FileSpan::new(0, 3), "const"),
+                Replacement::with_file_span(FileSpan::new(2, 5), "x"),
+            ],
+        );
+        let (applied, skipped) = partition_fixes(&[&conflicting]);
+        assert!(applied.is_empty());
+        assert_eq!(skipped.len(), 1);
+    }
+
+    #[test]
+    fn test_format_fix_results_lists_skipped_fixes() {
+        let result = FixResult {
+            file_path: PathBuf::from("a.nu"),
+            original_content: "let x = 1\n".to_string(),
+            fixed_content: "let y = 1\n".to_string(),
+            fixes_applied: 1,
+            unconverged_rules: vec![],
+            skipped: vec![SkippedFix {
+                rule_id: "test_rule".to_string(),
+                explanation: "Test fix".to_string(),
+            }],
+        };
+        let output = format_fix_results(&[result], false);
+        assert!(
+            output.contains("skipped fix from `test_rule` (conflicting replacements): Test fix"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_dry_run_shows_unified_diff() {
+        let result = FixResult {
+            file_path: PathBuf::from("a.nu"),
+            original_content: "a\nb\n".to_string(),
+            fixed_content: "a\nnew\nb\n".to_string(),
+            fixes_applied: 1,
+            unconverged_rules: vec![],
+            skipped: vec![],
+        };
+        let output = format_fix_results(&[result], true);
+        assert!(output.contains("@@ -1,2 +1,3 @@"), "{output}");
+        assert!(output.contains("+new"), "{output}");
+        assert!(
+            !output.contains("-b"),
+            "an insertion removes no lines: {output}"
+        );
     }
 
     #[test]
@@ -376,7 +720,11 @@ mod tests {
         let config = Config::default();
         let engine = LintEngine::new(config);
 
-        let (fixed, count) = apply_fixes_iteratively(content, &engine);
+        let IterativeFixResult {
+            content: fixed,
+            fixes_applied: count,
+            ..
+        } = apply_fixes_iteratively(content, &engine);
 
         // Should apply at least one fix without panicking
         assert!(count > 0, "Expected at least one fix to be applied");
@@ -410,7 +758,11 @@ mod tests {
         let config = Config::default();
         let engine = LintEngine::new(config);
 
-        let (fixed, count) = apply_fixes_iteratively(content, &engine);
+        let IterativeFixResult {
+            content: fixed,
+            fixes_applied: count,
+            ..
+        } = apply_fixes_iteratively(content, &engine);
 
         // Should apply multiple fixes without corruption
         assert!(count > 0, "Expected at least one fix to be applied");
@@ -437,16 +789,18 @@ mod tests {
         let config = Config::default();
         let engine = LintEngine::new(config);
 
-        let (fixed, count) = apply_fixes_iteratively(content, &engine);
+        let result = apply_fixes_iteratively(content, &engine);
+        let (fixed, count) = (result.content.as_str(), result.fixes_applied);
 
         // Should converge within reasonable iterations
         assert!(
             count < 50,
             "Should converge within 50 iterations, got {count}"
         );
+        assert!(result.converged(), "{:?}", result.unconverged_rules);
 
         // Re-linting the fixed content should produce no fixable violations
-        let violations_after = engine.lint_str(&fixed);
+        let violations_after = engine.lint_str(fixed);
         let fixable_after = violations_after.iter().filter(|v| v.fix.is_some()).count();
 
         assert_eq!(
@@ -455,6 +809,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_iteration_limit_reports_rules_still_firing() {
+        use crate::{config::Config, engine::LintEngine};
+
+        // Without any pass the fixes of the content are left unapplied
+        let content = "^curl https://example.com err> /dev/null | str trim\n";
+        let engine = LintEngine::new(Config {
+            max_fix_iterations: 0,
+            ..Config::default()
+        });
+
+        let result = apply_fixes_iteratively(content, &engine);
+        assert_eq!(result.fixes_applied, 0);
+        assert_eq!(result.content, content);
+        assert!(!result.converged());
+        let fixable: BTreeSet<String> = engine
+            .lint_str(content)
+            .iter()
+            .filter(|v| v.fix.is_some())
+            .filter_map(|v| v.rule_id.as_deref().map(String::from))
+            .collect();
+        assert!(!result.unconverged_rules.is_empty());
+        assert!(
+            result
+                .unconverged_rules
+                .iter()
+                .all(|rule| fixable.contains(rule))
+        );
+    }
+
     #[test]
     fn test_iterative_fixes_preserve_utf8() {
         // Test that iterative fixes correctly handle UTF-8 boundaries
@@ -465,7 +849,11 @@ mod tests {
         let config = Config::default();
         let engine = LintEngine::new(config);
 
-        let (fixed, count) = apply_fixes_iteratively(content, &engine);
+        let IterativeFixResult {
+            content: fixed,
+            fixes_applied: count,
+            ..
+        } = apply_fixes_iteratively(content, &engine);
 
         // Should apply fixes without UTF-8 boundary panics
         assert!(count > 0, "Expected at least one fix to be applied");
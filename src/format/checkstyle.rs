@@ -0,0 +1,87 @@
+use std::fmt::Write;
+
+use miette::Severity;
+
+use super::compact::{build_source_cache, byte_offset_to_line_col};
+use crate::violation::Violation;
+
+const fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Advice => "info",
+    }
+}
+
+/// Escape text for use in an XML attribute value
+fn escape_xml(text: &str) -> String {
+    text.chars()
+        .fold(String::with_capacity(text.len()), |mut escaped, ch| {
+            match ch {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&apos;"),
+                '\n' => escaped.push_str("&#10;"),
+                _ => escaped.push(ch),
+            }
+            escaped
+        })
+}
+
+/// Format violations as checkstyle XML, with one `<file>` element per file
+/// in the order the files were first reported.
+#[must_use]
+pub fn format_checkstyle(violations: &[Violation]) -> String {
+    let sources = build_source_cache(violations);
+
+    let mut files: Vec<(&str, Vec<&Violation>)> = Vec::new();
+    for v in violations {
+        let file = v.file.as_ref().map_or("<stdin>", |f| f.as_str());
+        match files.iter_mut().find(|(name, _)| *name == file) {
+            Some((_, file_violations)) => file_violations.push(v),
+            None => files.push((file, vec![v])),
+        }
+    }
+
+    let mut xml =
+        String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n");
+    for (file, file_violations) in files {
+        let source = sources.get(file).map_or("", String::as_str);
+        writeln!(xml, "  <file name=\"{}\">", escape_xml(file)).unwrap();
+        for v in file_violations {
+            let (line, column) = byte_offset_to_line_col(source, v.file_span().start);
+            writeln!(
+                xml,
+                "    <error line=\"{line}\" column=\"{column}\" severity=\"{}\" message=\"{}\" \
+                 source=\"{}\"/>",
+                severity_label(v.lint_level),
+                escape_xml(&v.message),
+                escape_xml(v.rule_id.as_deref().unwrap_or("unknown")),
+            )
+            .unwrap();
+        }
+        xml.push_str("  </file>\n");
+    }
+    xml.push_str("</checkstyle>");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_special_characters() {
+        assert_eq!(
+            escape_xml(r#"use `a < b && "c"`"#),
+            "use `a &lt; b &amp;&amp; &quot;c&quot;`"
+        );
+    }
+
+    #[test]
+    fn plain_text_is_unchanged() {
+        assert_eq!(escape_xml("unused variable x"), "unused variable x");
+    }
+}
@@ -34,7 +34,7 @@ pub fn format_compact(violations: &[Violation]) -> String {
         .join("\n")
 }
 
-fn build_source_cache(violations: &[Violation]) -> HashMap<&str, String> {
+pub(super) fn build_source_cache(violations: &[Violation]) -> HashMap<&str, String> {
     violations.iter().fold(HashMap::new(), |mut cache, v| {
         let file_name = v.file.as_ref().map_or("<stdin>", |f| f.as_str());
         cache.entry(file_name).or_insert_with(|| {
@@ -46,8 +46,10 @@ fn build_source_cache(violations: &[Violation]) -> HashMap<&str, String> {
     })
 }
 
-/// Convert a byte offset in `source` to a 1-based `(line, col)` pair.
-fn byte_offset_to_line_col(source: &str, offset: usize) -> (usize, usize) {
+/// Convert a byte offset in `source` to a 1-based `(line, col)` pair. The
+/// column counts characters, so multibyte text earlier on the line does not
+/// shift it.
+pub(super) fn byte_offset_to_line_col(source: &str, offset: usize) -> (usize, usize) {
     let offset = offset.min(source.len());
 
     let line_starts: Vec<usize> = once(0)
@@ -62,12 +64,16 @@ fn byte_offset_to_line_col(source: &str, offset: usize) -> (usize, usize) {
     let line_index = line_starts
         .partition_point(|&start| start <= offset)
         .saturating_sub(1);
-    let col = offset - line_starts[line_index];
+    let line_start = line_starts[line_index];
+    let col = source[line_start..]
+        .char_indices()
+        .take_while(|(i, _)| line_start + i < offset)
+        .count();
 
     (line_index + 1, col + 1)
 }
 
-const fn severity_label(severity: Severity) -> &'static str {
+pub(super) const fn severity_label(severity: Severity) -> &'static str {
     match severity {
         Severity::Error => "error",
         Severity::Warning => "warning",
@@ -109,4 +115,41 @@ mod tests {
     fn byte_offset_empty_source() {
         assert_eq!(byte_offset_to_line_col("", 0), (1, 1));
     }
+
+    #[test]
+    fn column_counts_characters_not_bytes() {
+        // 'é' and '🦀' take 2 and 4 bytes, the offset points at 'x'
+        let source = "let é🦀 = x";
+        let offset = source.find('x').unwrap();
+        assert_eq!(byte_offset_to_line_col(source, offset), (1, 10));
+    }
+
+    #[test]
+    fn column_on_second_line_after_multibyte_comment() {
+        let source = "# ünï\nfoo";
+        assert_eq!(byte_offset_to_line_col(source, source.len()), (2, 4));
+    }
+
+    #[test]
+    fn compact_column_after_emoji_and_accented_identifier() {
+        use crate::{Config, LintEngine};
+
+        let source = "# déjà vu\nlet café = \"🚀\"; print $café; let unused = 2\n";
+        let violations = LintEngine::new(Config::default()).lint_stdin(source);
+        let violation = violations
+            .iter()
+            .find(|v| v.rule_id.as_deref() == Some("unused_variable"))
+            .expect("unused variable is reported");
+        let byte_col = violation.file_span().start - source.find('\n').unwrap();
+        let expected = source.lines().nth(1).unwrap()[..byte_col - 1]
+            .chars()
+            .count()
+            + 1;
+        assert_ne!(expected, byte_col, "multibyte text precedes the violation");
+        let output = format_compact(&violations);
+        assert!(
+            output.contains(&format!("<stdin>:2:{expected}: ")),
+            "{output}"
+        );
+    }
 }
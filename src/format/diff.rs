@@ -0,0 +1,284 @@
+use std::fmt::Write;
+
+use owo_colors::OwoColorize;
+
+/// Unchanged lines shown around each change
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Most edits the shortest edit script is searched for. Memory grows with
+/// the square of the edits, so beyond this the changed part of the file is
+/// shown as replaced as a whole.
+const MAX_EDITS: usize = 1000;
+
+/// Line-based diff with the fewest removed and added lines. The common
+/// prefix and suffix are split off first, the changed middle is diffed with
+/// Myers' algorithm, whose time and memory grow with the number of edits
+/// rather than with the size of the file.
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Line<'a>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut lines: Vec<Line> = old[..prefix].iter().map(|line| Line::Same(line)).collect();
+    if let Some(trace) = edit_trace(old_mid, new_mid) {
+        lines.extend(backtrack(old_mid, new_mid, &trace));
+    } else {
+        lines.extend(old_mid.iter().map(|line| Line::Removed(line)));
+        lines.extend(new_mid.iter().map(|line| Line::Added(line)));
+    }
+    lines.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|line| Line::Same(line)),
+    );
+    lines
+}
+
+/// Forward pass of Myers' algorithm. Diagonal `k` holds the lines where
+/// `x - y == k`, for `x` lines of `old` and `y` lines of `new` consumed.
+/// Entry `d` of the trace has the furthest `x` reached on diagonals `-d..=d`
+/// (stored at `k + d`) with `d - 1` edits. Returns `None` when more than
+/// [`MAX_EDITS`] edits are needed.
+fn edit_trace(old: &[&str], new: &[&str]) -> Option<Vec<Vec<usize>>> {
+    let max = MAX_EDITS.min(old.len() + new.len());
+    // furthest[max + k] is the furthest `x` on diagonal `k`
+    let mut furthest = vec![0; 2 * max + 2];
+    let mut trace = Vec::new();
+    for d in 0..=max {
+        trace.push(furthest[max - d..=max + d].to_vec());
+        for index in (max - d..=max + d).step_by(2) {
+            let down =
+                index == max - d || (index != max + d && furthest[index - 1] < furthest[index + 1]);
+            let mut x = if down {
+                furthest[index + 1]
+            } else {
+                furthest[index - 1] + 1
+            };
+            let mut y = x + max - index;
+            while x < old.len() && y < new.len() && old[x] == new[y] {
+                x += 1;
+                y += 1;
+            }
+            furthest[index] = x;
+            if x >= old.len() && y >= new.len() {
+                return Some(trace);
+            }
+        }
+    }
+    None
+}
+
+/// Walk the trace of [`edit_trace`] back from the end of both versions
+fn backtrack<'a>(old: &[&'a str], new: &[&'a str], trace: &[Vec<usize>]) -> Vec<Line<'a>> {
+    let mut lines = Vec::new();
+    let (mut x, mut y) = (old.len(), new.len());
+    for (d, furthest) in trace.iter().enumerate().skip(1).rev() {
+        let index = x + d - y;
+        let down = index == 0 || (index != 2 * d && furthest[index - 1] < furthest[index + 1]);
+        let previous = if down { index + 1 } else { index - 1 };
+        let previous_x = furthest[previous];
+        let previous_y = previous_x + d - previous;
+        while x > previous_x && y > previous_y {
+            x -= 1;
+            y -= 1;
+            lines.push(Line::Same(old[x]));
+        }
+        if down {
+            y -= 1;
+            lines.push(Line::Added(new[y]));
+        } else {
+            x -= 1;
+            lines.push(Line::Removed(old[x]));
+        }
+    }
+    lines.extend(old[..x].iter().rev().map(|line| Line::Same(line)));
+    lines.reverse();
+    lines
+}
+
+/// Ranges of diff lines that form hunks: changes with their context, merged
+/// when their context would overlap
+fn hunk_ranges(lines: &[Line]) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for (index, _) in lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Line::Same(_)))
+    {
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + 1 + CONTEXT_LINES).min(lines.len());
+        match ranges.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => ranges.push((start, end)),
+        }
+    }
+    ranges
+}
+
+/// Number of lines of the old (or new) version among the diff lines
+fn count_lines(lines: &[Line], old: bool) -> usize {
+    lines
+        .iter()
+        .filter(|line| match line {
+            Line::Same(_) => true,
+            Line::Removed(_) => old,
+            Line::Added(_) => !old,
+        })
+        .count()
+}
+
+/// Start and length of a hunk in `@@` notation, where an empty range starts
+/// at the line before it
+const fn hunk_position(lines_before: usize, len: usize) -> (usize, usize) {
+    if len == 0 {
+        (lines_before, 0)
+    } else {
+        (lines_before + 1, len)
+    }
+}
+
+/// Format the changes between two versions of a file as a unified diff with
+/// `--- a/<path>` and `+++ b/<path>` headers. Returns an empty string when
+/// nothing changed.
+#[must_use]
+pub fn format_unified_diff(path: &str, original: &str, fixed: &str, color: bool) -> String {
+    let old: Vec<&str> = original.lines().collect();
+    let new: Vec<&str> = fixed.lines().collect();
+    let lines = diff_lines(&old, &new);
+    let ranges = hunk_ranges(&lines);
+    if ranges.is_empty() {
+        return String::new();
+    }
+
+    let paint = |text: String, style: fn(&str) -> String| {
+        if color { style(&text) } else { text }
+    };
+
+    let mut output = String::new();
+    writeln!(
+        output,
+        "{}",
+        paint(format!("--- a/{path}"), |t| t.bold().to_string())
+    )
+    .unwrap();
+    writeln!(
+        output,
+        "{}",
+        paint(format!("+++ b/{path}"), |t| t.bold().to_string())
+    )
+    .unwrap();
+
+    for (start, end) in ranges {
+        let (old_start, old_len) = hunk_position(
+            count_lines(&lines[..start], true),
+            count_lines(&lines[start..end], true),
+        );
+        let (new_start, new_len) = hunk_position(
+            count_lines(&lines[..start], false),
+            count_lines(&lines[start..end], false),
+        );
+
+        let header = format!("@@ -{old_start},{old_len} +{new_start},{new_len} @@");
+        writeln!(output, "{}", paint(header, |t| t.cyan().to_string())).unwrap();
+        for line in &lines[start..end] {
+            let text = match line {
+                Line::Same(text) => format!(" {text}"),
+                Line::Removed(text) => paint(format!("-{text}"), |t| t.red().to_string()),
+                Line::Added(text) => paint(format!("+{text}"), |t| t.green().to_string()),
+            };
+            writeln!(output, "{text}").unwrap();
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unchanged_content_has_no_diff() {
+        assert_eq!(
+            format_unified_diff("a.nu", "let x = 1\n", "let x = 1\n", false),
+            ""
+        );
+    }
+
+    #[test]
+    fn single_change_with_context() {
+        let original = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let fixed = "a\nb\nc\nd\nE\nf\ng\nh\n";
+        assert_eq!(
+            format_unified_diff("a.nu", original, fixed, false),
+            "--- a/a.nu\n+++ b/a.nu\n@@ -2,7 +2,7 @@\n b\n c\n d\n-e\n+E\n f\n g\n h\n"
+        );
+    }
+
+    #[test]
+    fn distant_changes_make_separate_hunks() {
+        let original: String = (1..=20).map(|n| format!("{n}\n")).collect();
+        let fixed: String = (1..=20)
+            .map(|n| match n {
+                2 => "two\n".to_string(),
+                19 => "nineteen\n".to_string(),
+                _ => format!("{n}\n"),
+            })
+            .collect();
+        let diff = format_unified_diff("a.nu", &original, &fixed, false);
+        assert_eq!(diff.matches("@@ -").count(), 2, "{diff}");
+        assert!(diff.contains("@@ -1,5 +1,5 @@"), "{diff}");
+        assert!(diff.contains("@@ -16,5 +16,5 @@"), "{diff}");
+    }
+
+    #[test]
+    fn inserted_and_removed_lines() {
+        let diff = format_unified_diff("a.nu", "a\nb\n", "a\nnew\nb\n", false);
+        assert!(diff.ends_with("@@ -1,2 +1,3 @@\n a\n+new\n b\n"), "{diff}");
+
+        let diff = format_unified_diff("a.nu", "old\n", "", false);
+        assert!(diff.ends_with("@@ -1,1 +0,0 @@\n-old\n"), "{diff}");
+    }
+
+    #[test]
+    fn color_is_optional() {
+        let colored = format_unified_diff("a.nu", "a\n", "b\n", true);
+        assert!(colored.contains('\x1b'), "colored output uses ANSI escapes");
+        let plain = format_unified_diff("a.nu", "a\n", "b\n", false);
+        assert!(!plain.contains('\x1b'));
+    }
+
+    #[test]
+    fn large_file_with_distant_changes() {
+        let original: String = (1..=20_000).map(|n| format!("{n}\n")).collect();
+        let fixed = format!("first\n{}last\n", &original[2..original.len() - 6]);
+        let diff = format_unified_diff("a.nu", &original, &fixed, false);
+        assert!(diff.contains("@@ -1,4 +1,4 @@\n-1\n+first\n 2\n"), "{diff}");
+        assert!(diff.ends_with(" 19999\n-20000\n+last\n"), "{diff}");
+    }
+
+    #[test]
+    fn many_edits_replace_the_changed_part() {
+        let original: String = (0..MAX_EDITS).map(|n| format!("{n}\n")).collect();
+        let fixed: String = (0..MAX_EDITS).map(|n| format!("{n}!\n")).collect();
+        let diff = format_unified_diff("a.nu", &original, &fixed, false);
+        let header = format!("@@ -1,{MAX_EDITS} +1,{MAX_EDITS} @@\n-0\n-1\n");
+        assert!(diff.contains(&header), "{diff}");
+        assert_eq!(
+            diff.lines().filter(|line| line.starts_with('-')).count(),
+            MAX_EDITS + 1
+        );
+    }
+}
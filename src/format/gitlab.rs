@@ -0,0 +1,94 @@
+use miette::Severity;
+use serde::Serialize;
+
+use super::compact::{build_source_cache, byte_offset_to_line_col};
+use crate::{hash::fnv1a, violation::Violation};
+
+/// Entry of a GitLab Code Quality report
+#[derive(Serialize)]
+struct CodeQualityIssue<'a> {
+    description: &'a str,
+    check_name: &'a str,
+    fingerprint: String,
+    severity: &'static str,
+    location: Location<'a>,
+}
+
+#[derive(Serialize)]
+struct Location<'a> {
+    path: &'a str,
+    lines: Lines,
+}
+
+#[derive(Serialize)]
+struct Lines {
+    begin: usize,
+}
+
+const fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "major",
+        Severity::Warning => "minor",
+        Severity::Advice => "info",
+    }
+}
+
+/// Hash of the fields that identify an issue. GitLab compares fingerprints
+/// between pipelines, so the hash must not change between Rust versions.
+fn fingerprint(parts: &[&str]) -> String {
+    let parts: Vec<&[u8]> = parts.iter().map(|part| part.as_bytes()).collect();
+    format!("{:016x}", fnv1a(&parts))
+}
+
+/// Format violations as a GitLab Code Quality report, a JSON array shown in
+/// merge request widgets.
+#[must_use]
+pub fn format_gitlab(violations: &[Violation]) -> String {
+    let sources = build_source_cache(violations);
+
+    let issues: Vec<CodeQualityIssue> = violations
+        .iter()
+        .map(|v| {
+            let path = v.file.as_ref().map_or("<stdin>", |f| f.as_str());
+            let source = sources.get(path).map_or("", String::as_str);
+            let (line, _) = byte_offset_to_line_col(source, v.file_span().start);
+            let check_name = v.rule_id.as_deref().unwrap_or("unknown");
+            CodeQualityIssue {
+                description: &v.message,
+                check_name,
+                fingerprint: fingerprint(&[path, check_name, &line.to_string(), &v.message]),
+                severity: severity_label(v.lint_level),
+                location: Location {
+                    path,
+                    lines: Lines { begin: line },
+                },
+            }
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&issues).expect("violations serialize to JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_is_stable() {
+        assert_eq!(
+            fingerprint(&["a.nu", "unused_variable", "2", "msg"]),
+            fingerprint(&["a.nu", "unused_variable", "2", "msg"]),
+            "Equal issues should share a fingerprint"
+        );
+        assert_eq!(fingerprint(&[]), "cbf29ce484222325");
+    }
+
+    #[test]
+    fn fingerprint_separates_fields() {
+        assert_ne!(
+            fingerprint(&["ab", "c"]),
+            fingerprint(&["a", "bc"]),
+            "Moving text between fields should change the fingerprint"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use serde::Serialize;
+
+use super::compact::{build_source_cache, byte_offset_to_line_col, severity_label};
+use crate::{span::FileSpan, violation::Violation};
+
+/// One violation in the JSON output. Fields are only ever added, so parsers
+/// can rely on the existing ones.
+#[derive(Serialize)]
+struct JsonViolation<'a> {
+    file: &'a str,
+    /// 1-based line of the start of the span
+    line: usize,
+    /// 1-based column of the start of the span
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+    /// Byte offsets into the file
+    span: JsonSpan,
+    severity: &'static str,
+    rule_id: &'a str,
+    message: &'a str,
+    /// Longer explanation of the rule, if it has one
+    help: Option<&'a str>,
+    fix_available: bool,
+    /// Replacements of the fix, to be applied together
+    fixes: Vec<JsonReplacement<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonSpan {
+    start: usize,
+    end: usize,
+}
+
+impl From<FileSpan> for JsonSpan {
+    fn from(span: FileSpan) -> Self {
+        Self {
+            start: span.start,
+            end: span.end,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonReplacement<'a> {
+    span: JsonSpan,
+    replacement: &'a str,
+}
+
+/// Format violations as a JSON array, one object per violation.
+#[must_use]
+pub fn format_json(violations: &[Violation]) -> String {
+    let sources = build_source_cache(violations);
+
+    let entries: Vec<JsonViolation> = violations
+        .iter()
+        .map(|v| {
+            let file = v.file.as_ref().map_or("<stdin>", |f| f.as_str());
+            let source = sources.get(file).map_or("", String::as_str);
+            let span = v.file_span();
+            let (line, column) = byte_offset_to_line_col(source, span.start);
+            let (end_line, end_column) = byte_offset_to_line_col(source, span.end);
+            let fixes = v
+                .fix
+                .iter()
+                .flat_map(|fix| &fix.replacements)
+                .map(|r| JsonReplacement {
+                    span: r.file_span().into(),
+                    replacement: &r.replacement_text,
+                })
+                .collect();
+            JsonViolation {
+                file,
+                line,
+                column,
+                end_line,
+                end_column,
+                span: span.into(),
+                severity: severity_label(v.lint_level),
+                rule_id: v.rule_id.as_deref().unwrap_or("unknown"),
+                message: &v.message,
+                help: v.long_description.as_deref(),
+                fix_available: v.fix.is_some(),
+                fixes,
+            }
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&entries).expect("violations serialize to JSON")
+}
@@ -1,12 +1,24 @@
+mod checkstyle;
 mod compact;
+mod diff;
+mod gitlab;
+mod json;
 mod pretty;
+mod sarif;
+mod stats;
 
 use std::fs;
 
+pub use checkstyle::format_checkstyle;
 pub use compact::format_compact;
+pub use diff::format_unified_diff;
+pub use gitlab::format_gitlab;
+pub use json::format_json;
 use miette::Severity;
-pub use pretty::{format_diff_context, format_pretty};
+pub use pretty::format_pretty;
+pub use sarif::format_sarif;
 use serde::Serialize;
+pub use stats::{RuleCount, Statistics};
 
 use crate::violation::{SourceFile, Violation};
 
@@ -18,6 +30,14 @@ pub enum Format {
     Pretty,
     /// One-line-per-violation format (gcc/eslint style)
     Compact,
+    /// JSON array of violations
+    Json,
+    /// SARIF 2.1.0 log for code scanning tools such as GitHub Code Scanning
+    Sarif,
+    /// GitLab Code Quality report
+    Gitlab,
+    /// Checkstyle XML report
+    Checkstyle,
 }
 
 /// Format and output linting results
@@ -26,6 +46,10 @@ pub fn format_output(violations: &[Violation], format: Format) -> String {
     match format {
         Format::Pretty => format_pretty(violations),
         Format::Compact => format_compact(violations),
+        Format::Json => format_json(violations),
+        Format::Sarif => format_sarif(violations),
+        Format::Gitlab => format_gitlab(violations),
+        Format::Checkstyle => format_checkstyle(violations),
     }
 }
 
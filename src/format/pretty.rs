@@ -221,46 +221,6 @@ fn format_diff_inline(old_text: &str, new_text: &str) -> String {
     }
 }
 
-/// Format a diff between old and new text with line numbers (fix application
-/// output). Shows only changed lines with their line numbers.
-#[must_use]
-pub fn format_diff_context(original: &str, fixed: &str) -> String {
-    let original_lines: Vec<&str> = original.lines().collect();
-    let fixed_lines: Vec<&str> = fixed.lines().collect();
-
-    if original_lines == fixed_lines {
-        return String::from("No changes\n");
-    }
-
-    let mut output = String::new();
-    let max_lines = original_lines.len().max(fixed_lines.len());
-
-    for i in 0..max_lines {
-        let orig = original_lines.get(i);
-        let fix = fixed_lines.get(i);
-
-        match (orig, fix) {
-            (Some(o), Some(f)) if o != f => {
-                writeln!(output, "{}", format!("-{:>4} {o}", i + 1).red()).unwrap();
-                writeln!(output, "{}", format!("+{:>4} {f}", i + 1).green()).unwrap();
-            }
-            (Some(o), None) => {
-                writeln!(output, "{}", format!("-{:>4} {o}", i + 1).red()).unwrap();
-            }
-            (None, Some(f)) => {
-                writeln!(output, "{}", format!("+{:>4} {f}", i + 1).green()).unwrap();
-            }
-            _ => {}
-        }
-    }
-
-    if output.is_empty() {
-        String::from("No changes\n")
-    } else {
-        output
-    }
-}
-
 /// Format a URL as a clickable terminal hyperlink (OSC 8 escape sequence).
 fn format_clickable_url(url: &str) -> String {
     format!("\x1b]8;;{url}\x1b\\{url}\x1b]8;;\x1b\\")
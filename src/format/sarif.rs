@@ -0,0 +1,315 @@
+use std::{env, fmt::Write as _, path::Path};
+
+use miette::Severity;
+use serde::Serialize;
+
+use super::compact::{build_source_cache, byte_offset_to_line_col};
+use crate::{LintLevel, rules::USED_RULES, violation::Violation};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+/// Base id that relative artifact URIs are resolved against
+const SRCROOT: &str = "%SRCROOT%";
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run<'a> {
+    tool: Tool,
+    /// Columns count Unicode code points, not bytes or UTF-16 code units
+    column_kind: &'static str,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<ReportingDescriptor>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    id: &'static str,
+    short_description: Message<'static>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+    default_configuration: Configuration,
+}
+
+#[derive(Serialize)]
+struct Configuration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct Message<'a> {
+    text: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    rule_id: &'a str,
+    level: &'static str,
+    message: Message<'a>,
+    /// Empty for standard input, which has no URI
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<Location>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+const fn severity_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Advice => "note",
+    }
+}
+
+const fn lint_level(level: LintLevel) -> &'static str {
+    match level {
+        LintLevel::Off => "none",
+        LintLevel::Hint => "note",
+        LintLevel::Warning => "warning",
+        LintLevel::Error => "error",
+    }
+}
+
+fn rule_descriptors() -> Vec<ReportingDescriptor> {
+    let mut rules: Vec<ReportingDescriptor> = USED_RULES
+        .iter()
+        .map(|rule| ReportingDescriptor {
+            id: rule.id(),
+            short_description: Message {
+                text: rule.short_description(),
+            },
+            help_uri: rule.source_link(),
+            default_configuration: Configuration {
+                level: lint_level(rule.level()),
+            },
+        })
+        .collect();
+    rules.sort_by_key(|rule| rule.id);
+    rules
+}
+
+/// Percent-encode every byte that may not appear literally in a URI path
+/// segment, such as spaces, `%`, `#`, `?` and non-ASCII text.
+fn encode_segment(segment: &str) -> String {
+    segment.bytes().fold(String::new(), |mut encoded, byte| {
+        if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@".contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            write!(encoded, "%{byte:02X}").unwrap();
+        }
+        encoded
+    })
+}
+
+fn encode_path(path: &str) -> String {
+    path.split('/')
+        .map(encode_segment)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Paths under `root` become URIs relative to `%SRCROOT%`; other absolute
+/// paths become `file://` URIs.
+fn artifact_location(file: &str, root: Option<&Path>) -> ArtifactLocation {
+    let path = Path::new(file);
+    let relative = if path.is_absolute() {
+        root.and_then(|root| path.strip_prefix(root).ok())
+    } else {
+        Some(path)
+    };
+    if let Some(relative) = relative {
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        let mut relative = relative.as_str();
+        while let Some(rest) = relative.strip_prefix("./") {
+            relative = rest;
+        }
+        return ArtifactLocation {
+            uri: encode_path(relative),
+            uri_base_id: Some(SRCROOT),
+        };
+    }
+    let absolute = file.replace('\\', "/");
+    let separator = if absolute.starts_with('/') { "" } else { "/" };
+    ArtifactLocation {
+        uri: format!("file://{separator}{}", encode_path(&absolute)),
+        uri_base_id: None,
+    }
+}
+
+/// Format violations as a SARIF 2.1.0 log, as consumed by GitHub Code
+/// Scanning. Artifact URIs are relative to the working directory.
+#[must_use]
+pub fn format_sarif(violations: &[Violation]) -> String {
+    format_sarif_from(violations, env::current_dir().ok().as_deref())
+}
+
+fn format_sarif_from(violations: &[Violation], root: Option<&Path>) -> String {
+    let sources = build_source_cache(violations);
+
+    let results = violations
+        .iter()
+        .map(|v| {
+            let locations = v
+                .file
+                .as_ref()
+                .filter(|file| !file.is_stdin())
+                .map(|file| {
+                    let source = sources.get(file.as_str()).map_or("", String::as_str);
+                    let span = v.file_span();
+                    let (start_line, start_column) = byte_offset_to_line_col(source, span.start);
+                    let (end_line, end_column) = byte_offset_to_line_col(source, span.end);
+                    Location {
+                        physical_location: PhysicalLocation {
+                            artifact_location: artifact_location(file.as_str(), root),
+                            region: Region {
+                                start_line,
+                                start_column,
+                                end_line,
+                                end_column,
+                            },
+                        },
+                    }
+                })
+                .into_iter()
+                .collect();
+            SarifResult {
+                rule_id: v.rule_id.as_deref().unwrap_or("unknown"),
+                level: severity_level(v.lint_level),
+                message: Message { text: &v.message },
+                locations,
+            }
+        })
+        .collect();
+
+    let log = SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: [Run {
+            tool: Tool {
+                driver: Driver {
+                    name: env!("CARGO_PKG_NAME"),
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: env!("CARGO_PKG_REPOSITORY"),
+                    rules: rule_descriptors(),
+                },
+            },
+            column_kind: "unicodeCodePoints",
+            results,
+        }],
+    };
+
+    serde_json::to_string_pretty(&log).expect("violations serialize to SARIF")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_descriptors_are_sorted_and_complete() {
+        let rules = rule_descriptors();
+        assert_eq!(rules.len(), USED_RULES.len(), "every rule is described");
+        assert!(
+            rules.windows(2).all(|pair| pair[0].id < pair[1].id),
+            "rules are sorted by id"
+        );
+    }
+
+    #[test]
+    fn path_with_space_is_percent_encoded() {
+        assert_eq!(
+            artifact_location("./scripts/my script.nu", None),
+            ArtifactLocation {
+                uri: "scripts/my%20script.nu".to_string(),
+                uri_base_id: Some(SRCROOT),
+            }
+        );
+        assert_eq!(artifact_location("50%#1?.nu", None).uri, "50%25%231%3F.nu");
+    }
+
+    #[test]
+    fn absolute_paths_are_relative_to_root_or_file_uris() {
+        let root = Path::new("/repo");
+        assert_eq!(
+            artifact_location("/repo/src/a b.nu", Some(root)),
+            ArtifactLocation {
+                uri: "src/a%20b.nu".to_string(),
+                uri_base_id: Some(SRCROOT),
+            }
+        );
+        assert_eq!(
+            artifact_location("/tmp/a b.nu", Some(root)),
+            ArtifactLocation {
+                uri: "file:///tmp/a%20b.nu".to_string(),
+                uri_base_id: None,
+            }
+        );
+    }
+
+    #[test]
+    fn stdin_results_have_no_locations() {
+        use crate::{Config, LintEngine};
+
+        let violations = LintEngine::new(Config::default()).lint_stdin("let unused = 2\n");
+        assert!(!violations.is_empty(), "unused variable is reported");
+        let log: serde_json::Value =
+            serde_json::from_str(&format_sarif_from(&violations, None)).unwrap();
+        let results = log["runs"][0]["results"].as_array().unwrap();
+        assert!(
+            results
+                .iter()
+                .all(|result| result.get("locations").is_none()),
+            "{results:?}"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::{collections::HashMap, iter::once};
+
+use serde::Serialize;
+
+use super::{Format, compact::severity_label};
+use crate::violation::Violation;
+
+#[derive(Serialize)]
+pub struct RuleCount {
+    pub rule_id: String,
+    pub count: usize,
+    pub percentage: f64,
+    /// Severity the rule reported its violations with
+    pub severity: &'static str,
+}
+
+/// How often each rule fired across a set of linted files
+#[derive(Serialize)]
+pub struct Statistics {
+    pub files: usize,
+    pub violations: usize,
+    /// Sorted by count, most frequent first
+    pub rules: Vec<RuleCount>,
+}
+
+impl Statistics {
+    #[must_use]
+    pub fn from_violations(violations: &[Violation], files: usize) -> Self {
+        let counts = violations.iter().fold(HashMap::new(), |mut counts, v| {
+            counts
+                .entry(v.rule_id.as_deref().unwrap_or("unknown"))
+                .or_insert((0, severity_label(v.lint_level)))
+                .0 += 1;
+            counts
+        });
+
+        let total = violations.len();
+        let mut rules: Vec<RuleCount> = counts
+            .into_iter()
+            .map(|(rule_id, (count, severity))| RuleCount {
+                rule_id: rule_id.to_string(),
+                count,
+                percentage: percentage(count, total),
+                severity,
+            })
+            .collect();
+        rules.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.rule_id.cmp(&b.rule_id))
+        });
+
+        Self {
+            files,
+            violations: total,
+            rules,
+        }
+    }
+
+    #[must_use]
+    pub fn format_table(&self) -> String {
+        let id_width = self
+            .rules
+            .iter()
+            .map(|r| r.rule_id.len())
+            .chain(once("RULE".len()))
+            .max()
+            .unwrap_or_default();
+
+        let header = format!(
+            "{:<id_width$}  {:<8}  {:>7}  {:>7}",
+            "RULE", "SEVERITY", "COUNT", "PERCENT"
+        );
+        let rows = self.rules.iter().map(|r| {
+            format!(
+                "{:<id_width$}  {:<8}  {:>7}  {:>6.1}%",
+                r.rule_id, r.severity, r.count, r.percentage
+            )
+        });
+        let totals = format!(
+            "\n{} violation(s) in {} file(s)",
+            self.violations, self.files
+        );
+
+        once(header)
+            .chain(rows)
+            .chain(once(totals))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[must_use]
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Json => {
+                serde_json::to_string_pretty(self).expect("statistics serialize to JSON")
+            }
+            Format::Pretty
+            | Format::Compact
+            | Format::Sarif
+            | Format::Gitlab
+            | Format::Checkstyle => self.format_table(),
+        }
+    }
+}
+
+#[allow(
+    clippy::cast_precision_loss,
+    reason = "violation counts are far below 2^52"
+)]
+fn percentage(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 * 100.0 / total as f64
+    }
+}
@@ -0,0 +1,15 @@
+/// FNV-1a hash of `parts`, each followed by a zero byte so text cannot move
+/// between parts without changing the hash. Unlike `DefaultHasher`, the
+/// result does not change between Rust versions, so it can be stored.
+#[must_use]
+pub fn fnv1a(parts: &[&[u8]]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    parts
+        .iter()
+        .flat_map(|part| part.iter().copied().chain([0]))
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        })
+}
@@ -15,24 +15,113 @@ pub fn parse_ignore_comment(line: &str) -> Option<Vec<&str>> {
         })
 }
 
+/// Line a suppression comment applies to
+#[derive(Debug, PartialEq, Eq)]
+enum Target {
+    /// The line of the comment itself
+    SameLine,
+    /// The next line with code
+    NextLine,
+    /// The next line with code for a standalone comment, otherwise the line
+    /// of the comment
+    Auto,
+    /// Every line of the file
+    File,
+}
+
+/// Rules suppressed by a comment
+#[derive(Debug, PartialEq, Eq)]
+enum Suppressed {
+    All,
+    Rules(HashSet<String>),
+}
+
+impl Suppressed {
+    fn contains(&self, rule_id: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Rules(rules) => rules.contains(rule_id),
+        }
+    }
+
+    fn extend(&mut self, other: Self) {
+        match (self, other) {
+            (Self::All, _) => {}
+            (this, Self::All) => *this = Self::All,
+            (Self::Rules(rules), Self::Rules(other)) => rules.extend(other),
+        }
+    }
+}
+
+/// Parse a suppression comment: `# nu-lint-ignore: rules`,
+/// `# nu-lint: disable-line [rules]`, `# nu-lint: disable-next-line [rules]`
+/// or `# nu-lint: disable-file [rules]`. Without rules, the `nu-lint:` forms
+/// suppress every rule.
+fn parse_directive(comment: &str) -> Option<(Target, Suppressed)> {
+    if let Some(rules) = parse_ignore_comment(comment) {
+        let rules = rules.into_iter().map(String::from).collect();
+        return Some((Target::Auto, Suppressed::Rules(rules)));
+    }
+
+    let directive = comment
+        .trim()
+        .strip_prefix('#')?
+        .trim()
+        .strip_prefix("nu-lint:")?
+        .trim_start();
+    let (target, rules_part) = if let Some(rest) = directive.strip_prefix("disable-next-line") {
+        (Target::NextLine, rest)
+    } else if let Some(rest) = directive.strip_prefix("disable-line") {
+        (Target::SameLine, rest)
+    } else if let Some(rest) = directive.strip_prefix("disable-file") {
+        (Target::File, rest)
+    } else {
+        return None;
+    };
+    if rules_part.starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '-') {
+        return None;
+    }
+
+    let rules: HashSet<String> = rules_part
+        .trim_start_matches([' ', '\t', '='])
+        .split([',', ' ', '\t'])
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect();
+    let suppressed = if rules.is_empty() {
+        Suppressed::All
+    } else {
+        Suppressed::Rules(rules)
+    };
+    Some((target, suppressed))
+}
+
 /// Precomputed index of ignore comments for efficient O(1) lookups.
-/// Built once per file, maps target line numbers to sets of ignored rule IDs.
+/// Built once per file, maps target line numbers to the suppressed rules.
 pub struct IgnoreIndex {
-    /// Map from line number to set of rule IDs to ignore on that line
-    ignored_lines: HashMap<usize, HashSet<String>>,
+    /// Map from line number to the rules to ignore on that line
+    ignored_lines: HashMap<usize, Suppressed>,
+    /// Rules ignored in the whole file by `# nu-lint: disable-file`
+    ignored_in_file: Option<Suppressed>,
     /// Byte offset of each line start (for offset-to-line conversion)
     line_offsets: Vec<usize>,
 }
 
 impl IgnoreIndex {
     /// Build an ignore index from source code.
-    /// Scans for `# nu-lint-ignore:` comments in two forms:
+    /// Scans for suppression comments in two forms:
     /// 1. Inline comments on the same line as code
     /// 2. Standalone comments on previous line (skipping attributes and empty
     ///    lines)
+    ///
+    /// `# nu-lint-ignore:` applies to the line it is on when inline and to
+    /// the next line otherwise. `# nu-lint: disable-line` always applies to
+    /// its own line and `# nu-lint: disable-next-line` to the next line.
+    /// `# nu-lint: disable-file` applies to the whole file, wherever it is.
     pub fn new(source: &str) -> Self {
         let lines: Vec<&str> = source.lines().collect();
-        let mut ignored_lines = HashMap::new();
+        let mut ignored_lines: HashMap<usize, Suppressed> = HashMap::new();
+        let mut ignored_in_file: Option<Suppressed> = None;
 
         let mut line_offsets = vec![0];
         for (pos, ch) in source.char_indices() {
@@ -42,39 +131,60 @@ impl IgnoreIndex {
         }
 
         for (line_num, line) in lines.iter().enumerate() {
-            if let Some(rules) = parse_ignore_comment(line) {
-                let rule_set: HashSet<String> = rules.iter().map(|&s| String::from(s)).collect();
-                let target = find_target_line(&lines, line_num + 1);
-                ignored_lines
-                    .entry(target)
-                    .or_insert_with(HashSet::new)
-                    .extend(rule_set);
-            } else if let Some(comment_start) = line.find('#') {
-                let comment_part = &line[comment_start..];
-                if let Some(rules) = parse_ignore_comment(comment_part) {
-                    let rule_set: HashSet<String> =
-                        rules.iter().map(|&s| String::from(s)).collect();
-                    ignored_lines
-                        .entry(line_num)
-                        .or_insert_with(HashSet::new)
-                        .extend(rule_set);
+            let standalone = line.trim_start().starts_with('#');
+            let Some((target, suppressed)) = line
+                .find('#')
+                .and_then(|comment_start| parse_directive(&line[comment_start..]))
+            else {
+                continue;
+            };
+            let target_line = match target {
+                Target::File => {
+                    match &mut ignored_in_file {
+                        Some(existing) => existing.extend(suppressed),
+                        None => ignored_in_file = Some(suppressed),
+                    }
+                    continue;
+                }
+                Target::SameLine => line_num,
+                Target::Auto if !standalone => line_num,
+                Target::NextLine | Target::Auto => find_target_line(&lines, line_num + 1),
+            };
+            match ignored_lines.get_mut(&target_line) {
+                Some(existing) => existing.extend(suppressed),
+                None => {
+                    ignored_lines.insert(target_line, suppressed);
                 }
             }
         }
 
         Self {
             ignored_lines,
+            ignored_in_file,
             line_offsets,
         }
     }
 
+    /// Whether `# nu-lint: disable-file` without rules turns off linting of
+    /// the whole file
+    pub fn disables_file(&self) -> bool {
+        matches!(self.ignored_in_file, Some(Suppressed::All))
+    }
+
     /// Check if a violation at the given byte offset should be ignored for a
     /// rule.
     pub fn should_ignore(&self, byte_offset: usize, rule_id: &str) -> bool {
+        if self
+            .ignored_in_file
+            .as_ref()
+            .is_some_and(|suppressed| suppressed.contains(rule_id))
+        {
+            return true;
+        }
         let line = self.offset_to_line(byte_offset);
         self.ignored_lines
             .get(&line)
-            .is_some_and(|rules| rules.contains(rule_id))
+            .is_some_and(|suppressed| suppressed.contains(rule_id))
     }
 
     /// Convert a byte offset to a line number (0-indexed)
@@ -181,4 +291,71 @@ mod tests {
         assert!(index.should_ignore(0, "rule_b"));
         assert!(!index.should_ignore(0, "rule_c"));
     }
+
+    #[test]
+    fn disable_line_with_rule() {
+        let source = "let x = 1 # nu-lint: disable-line my_rule\nlet y = 2";
+        let index = IgnoreIndex::new(source);
+        assert!(index.should_ignore(0, "my_rule"));
+        assert!(!index.should_ignore(0, "other_rule"));
+        assert!(!index.should_ignore(source.find("let y").unwrap(), "my_rule"));
+    }
+
+    #[test]
+    fn bare_disable_line_ignores_all_rules() {
+        let source = "let x = 1 # nu-lint: disable-line";
+        let index = IgnoreIndex::new(source);
+        assert!(index.should_ignore(0, "my_rule"));
+        assert!(index.should_ignore(0, "other_rule"));
+    }
+
+    #[test]
+    fn disable_next_line_skips_blank_lines() {
+        let source = "# nu-lint: disable-next-line rule_a, rule_b\n\n   \nlet x = 1";
+        let index = IgnoreIndex::new(source);
+        let let_offset = source.find("let").unwrap();
+        assert!(index.should_ignore(let_offset, "rule_a"));
+        assert!(index.should_ignore(let_offset, "rule_b"));
+        assert!(!index.should_ignore(let_offset, "rule_c"));
+    }
+
+    #[test]
+    fn inline_disable_next_line_targets_next_line() {
+        let source = "let x = 1 # nu-lint: disable-next-line my_rule\nlet y = 2";
+        let index = IgnoreIndex::new(source);
+        assert!(!index.should_ignore(0, "my_rule"));
+        assert!(index.should_ignore(source.find("let y").unwrap(), "my_rule"));
+    }
+
+    #[test]
+    fn directives_on_same_line_combine() {
+        let source = "# nu-lint: disable-next-line rule_a\nlet x = 1 # nu-lint: disable-line";
+        let index = IgnoreIndex::new(source);
+        let let_offset = source.find("let").unwrap();
+        assert!(index.should_ignore(let_offset, "rule_a"));
+        assert!(index.should_ignore(let_offset, "rule_b"));
+    }
+
+    #[test]
+    fn unknown_directive_is_ignored() {
+        assert_eq!(parse_directive("# nu-lint: disable-lines my_rule"), None);
+        assert_eq!(parse_directive("# nu-lint: enable-line my_rule"), None);
+    }
+
+    #[test]
+    fn disable_file_without_rules() {
+        let source = "let x = 1\n# nu-lint: disable-file\nlet y = 2";
+        let index = IgnoreIndex::new(source);
+        assert!(index.disables_file());
+        assert!(index.should_ignore(0, "my_rule"));
+    }
+
+    #[test]
+    fn disable_file_with_rules() {
+        let source = "let x = 1\nlet y = 2 # nu-lint: disable-file rule_a\n";
+        let index = IgnoreIndex::new(source);
+        assert!(!index.disables_file());
+        assert!(index.should_ignore(0, "rule_a"));
+        assert!(!index.should_ignore(0, "rule_b"));
+    }
 }
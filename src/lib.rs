@@ -1,4 +1,5 @@
 mod ast;
+mod cache;
 pub mod cli;
 mod config;
 mod context;
@@ -8,6 +9,7 @@ mod engine;
 mod fix;
 mod format;
 mod format_conversions;
+mod hash;
 mod ignore;
 pub mod log;
 #[cfg(feature = "lsp")]
@@ -21,7 +23,7 @@ use std::{error::Error, fmt, io, path::PathBuf};
 
 pub use config::{Config, LintLevel};
 pub use engine::LintEngine;
-pub use fix::apply_fixes_iteratively;
+pub use fix::{IterativeFixResult, apply_fixes_iteratively};
 use toml::{de, ser};
 use violation::{Fix, Replacement};
 
@@ -41,6 +43,17 @@ pub enum LintError {
     },
     RuleDoesNotExist {
         non_existing_id: String,
+        suggestion: Option<&'static str>,
+    },
+    GroupDoesNotExist {
+        non_existing_id: String,
+    },
+    UnknownRuleSelector {
+        selector: String,
+        suggestion: Option<&'static str>,
+    },
+    UnknownConfigKey {
+        key: String,
     },
     RuleConflict {
         rule_a: &'static str,
@@ -55,11 +68,41 @@ impl fmt::Display for LintError {
             Self::Io { path, source } => {
                 write!(f, "failed to read '{}': {source}", path.display())
             }
-            Self::RuleDoesNotExist { non_existing_id } => write!(
+            Self::RuleDoesNotExist {
+                non_existing_id,
+                suggestion,
+            } => {
+                write!(
+                    f,
+                    "Rule declared in config with id `{non_existing_id}` does not exist in this \
+                     version."
+                )?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " Did you mean `{suggestion}`?")?;
+                }
+                Ok(())
+            }
+            Self::GroupDoesNotExist { non_existing_id } => write!(
                 f,
-                "Rule declared in config with id `{non_existing_id}` does not exist in this \
+                "Group declared in config with name `{non_existing_id}` does not exist in this \
                  version."
             ),
+            Self::UnknownRuleSelector {
+                selector,
+                suggestion,
+            } => {
+                write!(
+                    f,
+                    "`{selector}` is neither a rule id, a group name nor `all`."
+                )?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " Did you mean `{suggestion}`?")?;
+                }
+                Ok(())
+            }
+            Self::UnknownConfigKey { key } => {
+                write!(f, "unknown configuration option `{key}`")
+            }
             Self::Config { source } => write!(f, "invalid configuration: {source}"),
             Self::ConfigSerialize { source } => {
                 write!(f, "failed to serialize configuration: {source}")
@@ -87,9 +130,12 @@ impl Error for LintError {
             Self::Io { source, .. } => Some(source),
             Self::Config { source } => Some(source),
             Self::ConfigSerialize { source } => Some(source),
-            Self::RuleConflict { .. } | Self::RuleDoesNotExist { .. } | Self::NoConfigLocation => {
-                None
-            }
+            Self::RuleConflict { .. }
+            | Self::RuleDoesNotExist { .. }
+            | Self::GroupDoesNotExist { .. }
+            | Self::UnknownRuleSelector { .. }
+            | Self::UnknownConfigKey { .. }
+            | Self::NoConfigLocation => None,
         }
     }
 }
@@ -1,3 +1,71 @@
+use std::{
+    cell::RefCell,
+    io::{self, Write},
+    mem,
+};
+
+thread_local! {
+    /// Log output collected on this thread while [`buffered`] runs
+    static BUFFER: RefCell<Option<Vec<u8>>> = const { RefCell::new(None) };
+}
+
+/// Writer of `--verbose` output: standard error, or the buffer of the current
+/// thread while [`buffered`] runs
+struct BufferedStderr;
+
+impl Write for BufferedStderr {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        BUFFER.with_borrow_mut(|buffer| match buffer {
+            Some(buffer) => {
+                buffer.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            None => io::stderr().write(buf),
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stderr().flush()
+    }
+}
+
+/// Run `f` and return the log output it produced on this thread instead of
+/// writing it, so the output of files linted in parallel can be written in
+/// the order of the files with [`write_buffered`].
+pub fn buffered<T>(f: impl FnOnce() -> T) -> (T, Vec<u8>) {
+    let outer = BUFFER.with_borrow_mut(|buffer| buffer.replace(Vec::new()));
+    let result = f();
+    let output = BUFFER.with_borrow_mut(|buffer| mem::replace(buffer, outer));
+    (result, output.unwrap_or_default())
+}
+
+/// Write log output returned by [`buffered`]
+pub fn write_buffered(output: &[u8]) {
+    if !output.is_empty() {
+        let _ = BufferedStderr.write_all(output);
+    }
+}
+
+/// Initialize logger for `--verbose`. Shows debug+ messages from nu-lint code
+/// on standard error.
+pub fn init_verbose_log() {
+    use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
+
+    // Set up log -> tracing bridge
+    tracing_log::LogTracer::init().ok();
+
+    let filter = EnvFilter::new("nu_lint=debug");
+
+    let _ = tracing_subscriber::registry()
+        .with(filter)
+        .with(
+            fmt::layer()
+                .with_writer(|| BufferedStderr)
+                .with_target(false),
+        )
+        .try_init();
+}
+
 /// Initialize logger for tests. Output is captured and only shown on failure.
 /// Only shows debug+ messages from nu-lint code.
 pub fn init_test_log() {
@@ -47,3 +115,48 @@ pub fn init_lsp_log() -> Option<LogGuard> {
     set_global_default(subscriber).ok();
     Some(guard)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn buffered_collects_output_of_its_thread() {
+        let ((), output) = buffered(|| {
+            let ((), inner) = buffered(|| BufferedStderr.write_all(b"inner\n").unwrap());
+            assert_eq!(inner, b"inner\n");
+            BufferedStderr.write_all(b"outer\n").unwrap();
+        });
+        assert_eq!(output, b"outer\n", "nested output is kept apart");
+    }
+
+    #[test]
+    fn buffered_output_does_not_interleave_across_threads() {
+        let outputs: Vec<Vec<u8>> = thread::scope(|scope| {
+            let workers: Vec<_> = ["a", "b"]
+                .into_iter()
+                .map(|name| {
+                    scope.spawn(move || {
+                        buffered(|| {
+                            for line in 0..100 {
+                                writeln!(BufferedStderr, "{name} {line}").unwrap();
+                            }
+                        })
+                        .1
+                    })
+                })
+                .collect();
+            workers.into_iter().map(|w| w.join().unwrap()).collect()
+        });
+        for (name, output) in ["a", "b"].iter().zip(outputs) {
+            let output = String::from_utf8(output).unwrap();
+            assert_eq!(output.lines().count(), 100);
+            assert!(
+                output.lines().all(|line| line.starts_with(name)),
+                "{output}"
+            );
+        }
+    }
+}
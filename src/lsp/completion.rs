@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
 use lsp_types::{
-    CodeAction, CodeActionKind, CodeActionOrCommand, Command, Diagnostic, Range, TextEdit, Uri,
-    WorkspaceEdit,
+    CodeAction, CodeActionKind, CodeActionOrCommand, Command, Diagnostic, Position, Range,
+    TextEdit, Uri, WorkspaceEdit,
 };
 
 use super::{
@@ -10,7 +10,7 @@ use super::{
     diagnostic::{LineIndex, ranges_overlap, violation_to_diagnostic},
     state::DocumentState,
 };
-use crate::violation::Fix;
+use crate::{fix::non_overlapping_fixes, violation::Fix};
 
 fn workspace_edit(uri: &Uri, edits: Vec<TextEdit>) -> WorkspaceEdit {
     WorkspaceEdit {
@@ -27,33 +27,84 @@ fn ignore_kind(rule_id: &str) -> CodeActionKind {
     CodeActionKind::from(format!("quickfix.nu-lint.ignore.{rule_id}"))
 }
 
+fn ignore_file_kind(rule_id: &str) -> CodeActionKind {
+    CodeActionKind::from(format!("quickfix.nu-lint.ignore-file.{rule_id}"))
+}
+
 fn disable_kind(rule_id: &str) -> CodeActionKind {
     CodeActionKind::from(format!("quickfix.nu-lint.disable.{rule_id}"))
 }
 
-pub fn ignore_comment_edit(content: &str, byte_offset: usize, rule_id: &str) -> TextEdit {
+fn insert_at(position: Position, new_text: String) -> TextEdit {
+    TextEdit {
+        range: Range {
+            start: position,
+            end: position,
+        },
+        new_text,
+    }
+}
+
+/// Insert `# nu-lint: disable-next-line` above the line of the violation,
+/// indented like that line, or add the rule to such a comment if there is
+/// one already.
+pub fn disable_next_line_edit(content: &str, byte_offset: usize, rule_id: &str) -> TextEdit {
     let line_index = LineIndex::new(content);
     let violation_line = line_index.offset_to_line(byte_offset);
+
+    if let Some(previous_line) = violation_line.checked_sub(1) {
+        let previous = line_index.line_content(content, previous_line);
+        if previous
+            .trim_start()
+            .starts_with("# nu-lint: disable-next-line")
+        {
+            let end = line_index.line_start(previous_line) + previous.trim_end().len();
+            return insert_at(
+                line_index.offset_to_position(end, content),
+                format!(", {rule_id}"),
+            );
+        }
+    }
+
     let line_start = line_index.line_start(violation_line);
-    let line_content = line_index.line_content(content, violation_line);
-    let trimmed_line = line_content.trim_end();
+    let line = line_index.line_content(content, violation_line);
+    let indentation = &line[..line.len() - line.trim_start().len()];
+    insert_at(
+        line_index.offset_to_position(line_start, content),
+        format!("{indentation}# nu-lint: disable-next-line {rule_id}\n"),
+    )
+}
 
-    let edit_offset = line_start + trimmed_line.len();
-    let edit_position = line_index.offset_to_position(edit_offset, content);
+/// Insert `# nu-lint: disable-file` at the top of the file, below a shebang,
+/// or add the rule to such a comment if there is one already.
+pub fn disable_file_edit(content: &str, rule_id: &str) -> TextEdit {
+    let line_index = LineIndex::new(content);
+    let first_line = usize::from(content.starts_with("#!"));
+    let existing = line_index.line_content(content, first_line);
+
+    if existing.starts_with("# nu-lint: disable-file ") {
+        let end = line_index.line_start(first_line) + existing.trim_end().len();
+        return insert_at(
+            line_index.offset_to_position(end, content),
+            format!(", {rule_id}"),
+        );
+    }
 
-    let new_text = if trimmed_line.contains("# nu-lint-ignore:") {
-        format!(", {rule_id}")
+    let offset = if first_line == 0 {
+        0
+    } else if content.contains('\n') {
+        line_index.line_start(first_line)
     } else {
-        format!(" # nu-lint-ignore: {rule_id}")
+        // The shebang is the only line
+        return insert_at(
+            line_index.offset_to_position(content.len(), content),
+            format!("\n# nu-lint: disable-file {rule_id}"),
+        );
     };
-
-    TextEdit {
-        range: Range {
-            start: edit_position,
-            end: edit_position,
-        },
-        new_text,
-    }
+    insert_at(
+        line_index.offset_to_position(offset, content),
+        format!("# nu-lint: disable-file {rule_id}\n"),
+    )
 }
 
 fn quickfix_action(
@@ -85,17 +136,17 @@ fn quickfix_action(
     })
 }
 
-fn ignore_line_action(
+fn disable_next_line_action(
     uri: &Uri,
     rule_id: &str,
     byte_offset: usize,
     diagnostic: Diagnostic,
     content: &str,
 ) -> CodeActionOrCommand {
-    let edit = ignore_comment_edit(content, byte_offset, rule_id);
+    let edit = disable_next_line_edit(content, byte_offset, rule_id);
 
     CodeActionOrCommand::CodeAction(CodeAction {
-        title: format!("Ignore `{rule_id}` on this line"),
+        title: format!("Disable `{rule_id}` for this line"),
         kind: Some(ignore_kind(rule_id)),
         diagnostics: Some(vec![diagnostic]),
         edit: Some(workspace_edit(uri, vec![edit])),
@@ -103,6 +154,23 @@ fn ignore_line_action(
     })
 }
 
+fn disable_file_action(
+    uri: &Uri,
+    rule_id: &str,
+    diagnostic: Diagnostic,
+    content: &str,
+) -> CodeActionOrCommand {
+    let edit = disable_file_edit(content, rule_id);
+
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title: format!("Disable `{rule_id}` for the whole file"),
+        kind: Some(ignore_file_kind(rule_id)),
+        diagnostics: Some(vec![diagnostic]),
+        edit: Some(workspace_edit(uri, vec![edit])),
+        ..Default::default()
+    })
+}
+
 fn disable_rule_action(
     rule_id: &str,
     diagnostic: Diagnostic,
@@ -187,13 +255,19 @@ pub fn build_code_actions(
         }
 
         if options.include_ignore {
-            actions.push(ignore_line_action(
+            actions.push(disable_next_line_action(
                 uri,
                 rule_id,
                 span.start,
                 diagnostic.clone(),
                 &doc_state.content,
             ));
+            actions.push(disable_file_action(
+                uri,
+                rule_id,
+                diagnostic.clone(),
+                &doc_state.content,
+            ));
         }
 
         actions.push(disable_rule_action(
@@ -223,9 +297,12 @@ fn fix_all_action(uri: &Uri, doc_state: &DocumentState) -> Option<CodeActionOrCo
         .map(|v| violation_to_diagnostic(v, &doc_state.content, &doc_state.line_index, uri))
         .collect();
 
-    let mut edits: Vec<_> = fixable
+    // Overlapping edits are rejected by editors, so leave out fixes that
+    // conflict with an earlier one. They are offered again after this fix.
+    let fixes = non_overlapping_fixes(&fixable);
+    let edits: Vec<_> = fixes
         .iter()
-        .flat_map(|v| &v.fix.as_ref().unwrap().replacements)
+        .flat_map(|fix| &fix.replacements)
         .map(|r| {
             let span = r.file_span();
             TextEdit {
@@ -237,14 +314,8 @@ fn fix_all_action(uri: &Uri, doc_state: &DocumentState) -> Option<CodeActionOrCo
         })
         .collect();
 
-    edits.sort_by(|a, b| {
-        (b.range.start.line, b.range.start.character)
-            .cmp(&(a.range.start.line, a.range.start.character))
-    });
-    edits.dedup_by(|a, b| ranges_overlap(&a.range, &b.range));
-
     Some(CodeActionOrCommand::CodeAction(CodeAction {
-        title: format!("Fix all auto-fixable problems ({} fixes)", edits.len()),
+        title: format!("Fix all auto-fixable problems ({} fixes)", fixes.len()),
         kind: Some(CodeActionKind::SOURCE_FIX_ALL),
         diagnostics: Some(diagnostics),
         edit: Some(workspace_edit(uri, edits)),
@@ -252,3 +323,71 @@ fn fix_all_action(uri: &Uri, doc_state: &DocumentState) -> Option<CodeActionOrCo
         ..Default::default()
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(content: &str, edit: &TextEdit) -> String {
+        let line_index = LineIndex::new(content);
+        let offset = (0..=content.len())
+            .find(|&offset| line_index.offset_to_position(offset, content) == edit.range.start)
+            .unwrap();
+        format!(
+            "{}{}{}",
+            &content[..offset],
+            edit.new_text,
+            &content[offset..]
+        )
+    }
+
+    #[test]
+    fn disable_next_line_matches_indentation() {
+        let content = "def main [] {\n    let x = 1\n}";
+        let edit = disable_next_line_edit(content, content.find("let").unwrap(), "unused_variable");
+        assert_eq!(
+            apply(content, &edit),
+            "def main [] {\n    # nu-lint: disable-next-line unused_variable\n    let x = 1\n}"
+        );
+    }
+
+    #[test]
+    fn disable_next_line_extends_existing_comment() {
+        let content = "# nu-lint: disable-next-line rule_a\nlet x = 1";
+        let edit = disable_next_line_edit(content, content.find("let").unwrap(), "rule_b");
+        assert_eq!(
+            apply(content, &edit),
+            "# nu-lint: disable-next-line rule_a, rule_b\nlet x = 1"
+        );
+    }
+
+    #[test]
+    fn disable_file_goes_below_shebang() {
+        let content = "#!/usr/bin/env nu\nlet x = 1";
+        let edit = disable_file_edit(content, "unused_variable");
+        assert_eq!(
+            apply(content, &edit),
+            "#!/usr/bin/env nu\n# nu-lint: disable-file unused_variable\nlet x = 1"
+        );
+    }
+
+    #[test]
+    fn disable_file_extends_existing_comment() {
+        let content = "# nu-lint: disable-file rule_a\nlet x = 1";
+        let edit = disable_file_edit(content, "rule_b");
+        assert_eq!(
+            apply(content, &edit),
+            "# nu-lint: disable-file rule_a, rule_b\nlet x = 1"
+        );
+    }
+
+    #[test]
+    fn disable_file_at_top_without_shebang() {
+        let content = "let x = 1";
+        let edit = disable_file_edit(content, "unused_variable");
+        assert_eq!(
+            apply(content, &edit),
+            "# nu-lint: disable-file unused_variable\nlet x = 1"
+        );
+    }
+}
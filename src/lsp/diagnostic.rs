@@ -37,6 +37,8 @@ impl LineIndex {
         Self { line_offsets }
     }
 
+    /// Position of a byte offset. The character is counted in UTF-16 code
+    /// units, the default position encoding of LSP.
     pub fn offset_to_position(&self, offset: usize, source: &str) -> Position {
         let line = self
             .line_offsets
@@ -47,7 +49,7 @@ impl LineIndex {
         let end_offset = offset.min(source.len());
         let column = source
             .get(line_start..end_offset)
-            .map_or(0, |s| s.chars().count());
+            .map_or(0, |s| s.encode_utf16().count());
 
         Position {
             line: to_lsp_u32(line),
@@ -270,6 +272,17 @@ mod tests {
         assert_eq!(range.end.character, 5);
     }
 
+    #[test]
+    fn offset_to_position_counts_utf16_units() {
+        // 'é' is one UTF-16 unit, '🦀' is a surrogate pair of two
+        let source = "# é🦀\nlet x = 'é🦀' | str length";
+        let index = LineIndex::new(source);
+        let pos = index.offset_to_position(source.find('\n').unwrap(), source);
+        assert_eq!((pos.line, pos.character), (0, 5));
+        let pos = index.offset_to_position(source.find('|').unwrap(), source);
+        assert_eq!((pos.line, pos.character), (1, 14));
+    }
+
     #[test]
     fn span_to_range_multiline() {
         let source = "def foo [] {\n    bar\n}";
@@ -1,4 +1,4 @@
-use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind};
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Range};
 
 use crate::{rules::groups::groups_for_rule, violation::Violation};
 
@@ -27,7 +27,12 @@ fn violation_to_hover_markdown(v: &Violation) -> String {
     lines.join("\n")
 }
 
-pub fn build_hover<'a>(violations: impl Iterator<Item = &'a Violation>) -> Option<Hover> {
+/// Hover with the documentation of the violations, separated by rules.
+/// `range` is highlighted by the editor while the hover is shown.
+pub fn build_hover<'a>(
+    violations: impl Iterator<Item = &'a Violation>,
+    range: Option<Range>,
+) -> Option<Hover> {
     let markdown: String = violations
         .map(violation_to_hover_markdown)
         .collect::<Vec<_>>()
@@ -38,6 +43,64 @@ pub fn build_hover<'a>(violations: impl Iterator<Item = &'a Violation>) -> Optio
             kind: MarkupKind::Markdown,
             value: markdown,
         }),
-        range: None,
+        range,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use miette::Severity;
+
+    use super::*;
+    use crate::{span::FileSpan, violation::Detection};
+
+    fn violation() -> Violation {
+        let mut violation = Violation::from_detected(
+            Detection::from_file_span("Variable `x` is never used", FileSpan::new(4, 5)),
+            None,
+            Some("Remove the variable or use it."),
+        );
+        violation.rule_id = Some(Cow::Borrowed("unused_variable"));
+        violation.lint_level = Severity::Warning;
+        violation.short_description = Some("Variable declared but never used");
+        violation.doc_url = Some("https://www.nushell.sh/book/variables.html");
+        violation
+    }
+
+    #[test]
+    fn hover_shows_rule_metadata() {
+        let Some(Hover {
+            contents: HoverContents::Markup(markup),
+            ..
+        }) = build_hover([violation()].iter(), None)
+        else {
+            panic!("expected markdown hover");
+        };
+        assert_eq!(markup.kind, MarkupKind::Markdown);
+        assert!(markup.value.starts_with("### `unused_variable` ("));
+        assert!(markup.value.contains("*Variable declared but never used*"));
+        assert!(markup.value.contains("Remove the variable or use it."));
+        assert!(
+            markup
+                .value
+                .contains("[Documentation](https://www.nushell.sh/book/variables.html)")
+        );
+    }
+
+    #[test]
+    fn hover_separates_violations() {
+        let violations = [violation(), violation()];
+        let hover = build_hover(violations.iter(), None).unwrap();
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("expected markdown hover");
+        };
+        assert_eq!(markup.value.matches("\n\n---\n\n").count(), 1);
+    }
+
+    #[test]
+    fn no_hover_without_violations() {
+        assert!(build_hover(std::iter::empty(), None).is_none());
+    }
+}
@@ -3,14 +3,20 @@ use std::path::{Path, PathBuf};
 use lsp_server::{Connection, ExtractError, Message, Notification, Request, RequestId, Response};
 use lsp_types::{
     CodeActionKind, CodeActionOptions, CodeActionProviderCapability, Diagnostic,
-    ExecuteCommandOptions, ExecuteCommandParams, HoverProviderCapability, InitializeParams,
-    PublishDiagnosticsParams, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
-    TextDocumentSyncOptions, TextDocumentSyncSaveOptions, Uri,
+    DidChangeWatchedFilesRegistrationOptions, ExecuteCommandOptions, ExecuteCommandParams,
+    FileSystemWatcher, GlobPattern, HoverProviderCapability, InitializeParams,
+    PublishDiagnosticsParams, Registration, RegistrationParams, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
+    TextDocumentSyncSaveOptions, Uri,
     notification::{
-        DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument, DidOpenTextDocument,
-        DidSaveTextDocument, Notification as NotificationTrait, PublishDiagnostics,
+        DidChangeConfiguration, DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument,
+        DidOpenTextDocument, DidSaveTextDocument, Notification as NotificationTrait,
+        PublishDiagnostics,
+    },
+    request::{
+        CodeActionRequest, ExecuteCommand, HoverRequest, RegisterCapability,
+        Request as RequestTrait,
     },
-    request::{CodeActionRequest, ExecuteCommand, HoverRequest, Request as RequestTrait},
 };
 
 use super::{
@@ -68,8 +74,15 @@ fn is_config_file(uri: &Uri, workspace_root: Option<&Path>) -> bool {
     workspace_root.is_some_and(|root| Path::new(uri.path().as_str()) == root.join(".nu-lint.toml"))
 }
 
+/// Reload the configuration and lint all open documents again. Editors send
+/// several notifications for a single edit of the configuration file (change,
+/// save, watched file, configuration change), so nothing is linted when the
+/// configuration stayed the same.
 fn reload_config_and_relint(connection: &Connection, state: &mut ServerState) {
-    state.reload_config();
+    if !state.reload_config() {
+        tracing::debug!("Configuration unchanged, not linting again");
+        return;
+    }
     for uri in state.open_document_uris() {
         if let Some(doc) = state.get_document(&uri) {
             let content = doc.content.clone();
@@ -79,6 +92,42 @@ fn reload_config_and_relint(connection: &Connection, state: &mut ServerState) {
     }
 }
 
+/// Ask the client to notify the server of changes to configuration files
+/// that are not open in the editor, if it supports registering watchers.
+fn register_config_watcher(connection: &Connection, params: &InitializeParams) {
+    let supported = params
+        .capabilities
+        .workspace
+        .as_ref()
+        .and_then(|workspace| workspace.did_change_watched_files.as_ref())
+        .and_then(|watched_files| watched_files.dynamic_registration)
+        .unwrap_or(false);
+    if !supported {
+        return;
+    }
+
+    let options = DidChangeWatchedFilesRegistrationOptions {
+        watchers: vec![FileSystemWatcher {
+            glob_pattern: GlobPattern::String("**/.nu-lint.toml".to_string()),
+            kind: None,
+        }],
+    };
+    let request = Request::new(
+        RequestId::from("nu-lint/watch-config".to_string()),
+        RegisterCapability::METHOD.to_string(),
+        RegistrationParams {
+            registrations: vec![Registration {
+                id: "nu-lint/watch-config".to_string(),
+                method: DidChangeWatchedFiles::METHOD.to_string(),
+                register_options: serde_json::to_value(options).ok(),
+            }],
+        },
+    );
+    if let Err(e) = connection.sender.send(Message::Request(request)) {
+        tracing::error!("Failed to register config file watcher: {e}");
+    }
+}
+
 pub fn run_lsp_server() {
     let (connection, io_threads) = Connection::stdio();
 
@@ -128,6 +177,7 @@ pub fn run_lsp_server() {
     let config = load_config_from_workspace(workspace_root.as_deref());
     tracing::info!("nu-lint LSP server initialized");
 
+    register_config_watcher(&connection, &params);
     let mut state = ServerState::new(config, workspace_root);
 
     for msg in &connection.receiver {
@@ -211,6 +261,11 @@ fn handle_notification(connection: &Connection, state: &mut ServerState, mut not
         publish_diagnostics(connection, uri, vec![]);
     });
 
+    notif = try_notif::<DidChangeConfiguration, _>(notif, |_| {
+        tracing::info!("Client configuration changed, reloading configuration");
+        reload_config_and_relint(connection, state);
+    });
+
     let _ = try_notif::<DidChangeWatchedFiles, _>(notif, |params| {
         if params
             .changes
@@ -33,8 +33,9 @@ impl ServerState {
         }
     }
 
-    /// Reload configuration from workspace or home config file
-    pub fn reload_config(&mut self) {
+    /// Reload configuration from workspace or home config file, returning
+    /// whether it changed
+    pub fn reload_config(&mut self) -> bool {
         let config_path = self
             .workspace_root
             .as_ref()
@@ -52,7 +53,11 @@ impl ServerState {
             })
             .unwrap_or_default();
 
+        if config == self.engine.config {
+            return false;
+        }
         self.engine = LintEngine::new(config);
+        true
     }
 
     /// Get the workspace root path
@@ -141,15 +146,58 @@ impl ServerState {
         let doc_state = self.documents.get(&params.text_document.uri)?;
         let pos = &params.position;
 
-        let violations_at_pos = doc_state.violations.iter().filter(|v| {
-            let span = v.file_span();
-            let range =
-                doc_state
-                    .line_index
-                    .span_to_range(&doc_state.content, span.start, span.end);
-            range.start <= *pos && *pos <= range.end
-        });
+        let violations_at_pos: Vec<(&Violation, Range)> = doc_state
+            .violations
+            .iter()
+            .filter_map(|v| {
+                let span = v.file_span();
+                let range =
+                    doc_state
+                        .line_index
+                        .span_to_range(&doc_state.content, span.start, span.end);
+                (range.start <= *pos && *pos <= range.end).then_some((v, range))
+            })
+            .collect();
+
+        // Highlight the innermost violation, the one the cursor is most
+        // likely pointing at
+        let range = violations_at_pos
+            .iter()
+            .map(|(v, range)| (v.file_span().end - v.file_span().start, *range))
+            .min_by_key(|(len, _)| *len)
+            .map(|(_, range)| range);
+
+        build_hover(violations_at_pos.into_iter().map(|(v, _)| v), range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
 
-        build_hover(violations_at_pos)
+    use super::*;
+
+    #[test]
+    fn reload_reports_whether_config_changed() {
+        let workspace = tempfile::tempdir().unwrap();
+        fs::write(
+            workspace.path().join(".nu-lint.toml"),
+            "[rules]\nunused_variable = \"error\"\n",
+        )
+        .unwrap();
+        let mut state = ServerState::new(Config::default(), Some(workspace.path().to_path_buf()));
+
+        assert!(
+            state.reload_config(),
+            "config file differs from the defaults"
+        );
+        assert!(!state.reload_config(), "file did not change since");
+
+        fs::write(
+            workspace.path().join(".nu-lint.toml"),
+            "[rules]\nunused_variable = \"off\"\n",
+        )
+        .unwrap();
+        assert!(state.reload_config());
     }
 }
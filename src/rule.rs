@@ -8,6 +8,8 @@ use std::{
 
 use lsp_types::DiagnosticTag;
 
+#[cfg(test)]
+use crate::Config;
 use crate::{
     Fix, LintLevel,
     context::LintContext,
@@ -80,6 +82,7 @@ pub trait DetectFix: Send + Sync + 'static {
 pub trait Rule: Send + Sync {
     fn id(&self) -> &'static str;
     fn short_description(&self) -> &'static str;
+    fn long_description(&self) -> Option<&'static str>;
     fn source_link(&self) -> Option<&'static str>;
     fn level(&self) -> LintLevel;
     fn has_auto_fix(&self) -> bool;
@@ -97,6 +100,10 @@ impl<T: DetectFix> Rule for T {
         DetectFix::short_description(self)
     }
 
+    fn long_description(&self) -> Option<&'static str> {
+        DetectFix::long_description(self)
+    }
+
     fn source_link(&self) -> Option<&'static str> {
         DetectFix::source_link(self)
     }
@@ -121,7 +128,7 @@ impl<T: DetectFix> Rule for T {
         self.detect(context)
             .into_iter()
             .map(|(detected, fix_data)| {
-                let long_description = self.long_description();
+                let long_description = DetectFix::long_description(self);
                 let fix = self.fix(context, &fix_data);
                 Violation::from_detected(detected, fix, long_description)
             })
@@ -159,6 +166,10 @@ impl dyn Rule {
         LintContext::test_get_violations(code, |context| self.check(context))
     }
 
+    fn run_check_with_config(&self, code: &str, config: &Config) -> Vec<Violation> {
+        LintContext::test_get_violations_with_config(code, config, |context| self.check(context))
+    }
+
     #[track_caller]
     fn first_violation(&self, code: &str) -> Violation {
         let violations = self.run_check(code);
@@ -189,25 +200,22 @@ impl dyn Rule {
     /// Assumes there is only one violation and fix in the code (with zero or
     /// more replacements)
     pub fn apply_first_fix(&self, code: &str) -> String {
-        use std::cmp::Reverse;
+        apply_fix(code, self.first_violation(code))
+    }
 
+    /// Assert that the first violation in the code comes without a fix
+    #[track_caller]
+    pub fn assert_no_fix(&self, code: &str) {
         let violation = self.first_violation(code);
-        let fix = violation.fix.expect("Expected violation to have a fix");
         assert!(
-            !fix.replacements.is_empty(),
-            "Expected fix to have replacements"
+            violation.fix.is_none(),
+            "Expected rule '{}' to offer no fix, but it offered `{}`",
+            self.id(),
+            violation
+                .fix
+                .as_ref()
+                .map_or_else(String::new, |fix| fix.explanation.to_string())
         );
-
-        let mut replacements = fix.replacements;
-        replacements.sort_by_key(|b| Reverse(b.file_span().start));
-
-        let mut result = code.to_string();
-        for replacement in replacements {
-            let start = replacement.file_span().start;
-            let end = replacement.file_span().end;
-            result.replace_range(start..end, &replacement.replacement_text);
-        }
-        result
     }
 
     #[track_caller]
@@ -271,6 +279,18 @@ impl dyn Rule {
         );
     }
 
+    /// Assert that the first violation is reported on exactly `expected_text`
+    #[track_caller]
+    pub fn assert_reported_on(&self, code: &str, expected_text: &str) {
+        let span = self.first_violation(code).file_span();
+        assert_eq!(
+            &code[span.start..span.end],
+            expected_text,
+            "Expected rule '{}' to report on `{expected_text}`",
+            self.id()
+        );
+    }
+
     #[track_caller]
     pub fn assert_labels_contain(&self, code: &str, expected_text: &str) {
         let violation = self.first_violation(code);
@@ -298,4 +318,69 @@ impl dyn Rule {
             "Expected fixed code to not contain '{erased_text}', but it still appears in: {fixed}"
         );
     }
+
+    #[track_caller]
+    pub fn assert_detects_with_config(&self, code: &str, config: &Config) {
+        let violations = self.run_check_with_config(code, config);
+        assert!(
+            !violations.is_empty(),
+            "Expected rule '{}' to detect violations with config {config:?}, but found none",
+            self.id()
+        );
+    }
+
+    #[track_caller]
+    pub fn assert_ignores_with_config(&self, code: &str, config: &Config) {
+        let violations = self.run_check_with_config(code, config);
+        assert!(
+            violations.is_empty(),
+            "Expected rule '{}' to ignore code with config {config:?}, but found {} violations",
+            self.id(),
+            violations.len()
+        );
+    }
+
+    #[track_caller]
+    pub fn assert_fixed_is_with_config(
+        &self,
+        bad_code: &str,
+        expected_code: &str,
+        config: &Config,
+    ) {
+        let violation = self
+            .run_check_with_config(bad_code, config)
+            .into_iter()
+            .next()
+            .expect("Expected a violation with the given config");
+        let fixed = apply_fix(bad_code, violation);
+        assert!(
+            fixed == expected_code,
+            "Expected fixed code to be `{expected_code}` with config {config:?}, but it was \
+             `{fixed}`"
+        );
+    }
+}
+
+/// Apply all replacements of the fix of `violation` to `code`
+#[cfg(test)]
+#[track_caller]
+fn apply_fix(code: &str, violation: Violation) -> String {
+    use std::cmp::Reverse;
+
+    let fix = violation.fix.expect("Expected violation to have a fix");
+    assert!(
+        !fix.replacements.is_empty(),
+        "Expected fix to have replacements"
+    );
+
+    let mut replacements = fix.replacements;
+    replacements.sort_by_key(|b| Reverse(b.file_span().start));
+
+    let mut result = code.to_string();
+    for replacement in replacements {
+        let start = replacement.file_span().start;
+        let end = replacement.file_span().end;
+        result.replace_range(start..end, &replacement.replacement_text);
+    }
+    result
 }
@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn detects_bare_sys() {
+    RULE.assert_detects("sys");
+}
+
+#[test]
+fn detects_bare_sys_assigned() {
+    RULE.assert_detects("let info = sys");
+}
+
+#[test]
+fn detects_sys_followed_by_get() {
+    RULE.assert_detects("sys | get host");
+}
+
+#[test]
+fn detects_sys_followed_by_nested_get() {
+    RULE.assert_detects("sys | get host.name");
+}
+
+#[test]
+fn detects_bare_sys_in_function() {
+    let bad_code = r#"
+def memory [] {
+    sys | get mem | get total
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
@@ -0,0 +1,11 @@
+use super::RULE;
+
+#[test]
+fn fix_calls_subcommand_directly() {
+    RULE.assert_fixed_is("sys | get host", "sys host");
+}
+
+#[test]
+fn fix_keeps_rest_of_pipeline() {
+    RULE.assert_fixed_is("sys | get mem | get total", "sys mem | get total");
+}
@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn ignores_sys_host() {
+    RULE.assert_ignores("sys host");
+}
+
+#[test]
+fn ignores_sys_mem_with_get() {
+    RULE.assert_ignores("sys mem | get total");
+}
+
+#[test]
+fn ignores_other_subcommands() {
+    RULE.assert_ignores("sys cpu | length; sys disks; sys net; sys temp; sys users");
+}
+
+#[test]
+fn ignores_word_sys_as_string() {
+    RULE.assert_ignores("print sys");
+}
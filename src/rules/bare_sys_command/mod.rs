@@ -0,0 +1,141 @@
+use nu_protocol::{
+    Span,
+    ast::{Expr, Expression, PathMember, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::call::CallExt,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+const SUBCOMMANDS: &[&str] = &["host", "cpu", "mem", "disks", "net", "temp", "users"];
+
+struct FixData {
+    /// Span of `sys | get <field>`
+    span: Span,
+    subcommand: &'static str,
+}
+
+/// Returns the subcommand matching `get <field>`, when the cell path selects
+/// exactly one known `sys` section.
+fn subcommand_from_get(expr: &Expression, context: &LintContext) -> Option<&'static str> {
+    let Expr::Call(call) = &expr.expr else {
+        return None;
+    };
+    if !call.is_call_to_command("get", context) || call.arguments.len() != 1 {
+        return None;
+    }
+    let Expr::CellPath(cell_path) = &call.get_first_positional_arg()?.expr else {
+        return None;
+    };
+    let [PathMember::String { val, .. }] = cell_path.members.as_slice() else {
+        return None;
+    };
+    SUBCOMMANDS.iter().copied().find(|sub| sub == val)
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, Option<FixData>)> {
+    pipeline
+        .elements
+        .iter()
+        .enumerate()
+        .filter_map(|(i, element)| {
+            let Expr::Call(call) = &element.expr.expr else {
+                return None;
+            };
+            if call.get_call_name(context) != "sys" || call.get_first_positional_arg().is_some() {
+                return None;
+            }
+
+            let next = pipeline.elements.get(i + 1);
+            let subcommand = next.and_then(|next| subcommand_from_get(&next.expr, context));
+
+            let detection = if let Some(subcommand) = subcommand {
+                Detection::from_global_span(
+                    format!("Bare `sys` is deprecated, use `sys {subcommand}` directly"),
+                    call.head,
+                )
+                .with_primary_label("deprecated without subcommand")
+                .with_extra_label(
+                    format!("select the `{subcommand}` section"),
+                    next.map_or(call.head, |next| next.expr.span),
+                )
+            } else {
+                Detection::from_global_span(
+                    format!(
+                        "Bare `sys` is deprecated, call one of its subcommands instead: {}",
+                        SUBCOMMANDS
+                            .iter()
+                            .map(|sub| format!("`sys {sub}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    call.head,
+                )
+                .with_primary_label("deprecated without subcommand")
+            };
+
+            let fix_data = subcommand.zip(next).map(|(subcommand, next)| FixData {
+                span: Span::new(element.expr.span.start, next.expr.span.end),
+                subcommand,
+            });
+
+            Some((detection, fix_data))
+        })
+        .collect()
+}
+
+struct BareSysCommand;
+
+impl DetectFix for BareSysCommand {
+    type FixInput<'a> = Option<FixData>;
+
+    fn id(&self) -> &'static str {
+        "bare_sys_command"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Bare `sys` without subcommand is deprecated"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Calling `sys` without a subcommand was deprecated in favour of the dedicated \
+             subcommands `sys host`, `sys cpu`, `sys mem`, `sys disks`, `sys net`, `sys temp` and \
+             `sys users`. They return only the section you need. When the section is selected \
+             with `get` right after `sys`, the subcommand can be called directly.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/sys.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.ast.detect_in_pipelines(context, check_pipeline)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let FixData { span, subcommand } = fix_data.as_ref()?;
+        Some(Fix {
+            explanation: format!("Call `sys {subcommand}` directly").into(),
+            replacements: vec![Replacement::new(*span, format!("sys {subcommand}"))],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &BareSysCommand;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
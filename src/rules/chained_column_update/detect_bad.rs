@@ -0,0 +1,34 @@
+use super::RULE;
+
+#[test]
+fn detects_two_updates_same_column() {
+    RULE.assert_detects(r"$rows | update name { str trim } | update name { str downcase }");
+}
+
+#[test]
+fn detects_update_then_upsert() {
+    RULE.assert_detects(r"$rows | update size { into int } | upsert size {|r| $r.size * 2 }");
+}
+
+#[test]
+fn detects_long_chain_once() {
+    RULE.assert_count(
+        r"$rows | update a { str trim } | update a { str upcase } | update a { str reverse }",
+        1,
+    );
+}
+
+#[test]
+fn detects_inside_closure() {
+    let bad_code = r"
+def clean [] {
+    $in | update path { path expand } | update path { path basename }
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_quoted_and_bare_column() {
+    RULE.assert_detects(r#"$rows | update "name" { str trim } | update name { str upcase }"#);
+}
@@ -0,0 +1,23 @@
+use super::RULE;
+
+#[test]
+fn ignore_different_columns() {
+    RULE.assert_ignores(r"$rows | update name { str trim } | update size { into int }");
+}
+
+#[test]
+fn ignore_single_update() {
+    RULE.assert_ignores(r"$rows | update name { str trim | str downcase }");
+}
+
+#[test]
+fn ignore_stage_in_between() {
+    RULE.assert_ignores(
+        r"$rows | update name { str trim } | where name != '' | update name { str downcase }",
+    );
+}
+
+#[test]
+fn ignore_dynamic_column() {
+    RULE.assert_ignores(r"$rows | update $col { str trim } | update $col { str downcase }");
+}
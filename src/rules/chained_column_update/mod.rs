@@ -0,0 +1,118 @@
+use nu_protocol::{
+    Span,
+    ast::{Expr, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+const COLUMN_COMMANDS: &[&str] = &["update", "upsert", "insert"];
+
+/// Column written by an `update`/`upsert`/`insert` stage, as written in the
+/// source. Only literal column names are compared.
+fn updated_column<'a>(element: &Expr, context: &'a LintContext) -> Option<&'a str> {
+    let Expr::Call(call) = element else {
+        return None;
+    };
+    if !COLUMN_COMMANDS
+        .iter()
+        .any(|name| call.is_call_to_command(name, context))
+    {
+        return None;
+    }
+    let column = call.get_first_positional_arg()?;
+    matches!(
+        column.expr,
+        Expr::CellPath(_) | Expr::String(_) | Expr::RawString(_)
+    )
+    .then(|| context.expr_text(column).trim_matches(['"', '\'', '`']))
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    let columns: Vec<Option<&str>> = pipeline
+        .elements
+        .iter()
+        .map(|element| updated_column(&element.expr.expr, context))
+        .collect();
+
+    let mut detections = Vec::new();
+    let mut start = 0;
+    while start < columns.len() {
+        let Some(column) = columns[start] else {
+            start += 1;
+            continue;
+        };
+        let end = start
+            + columns[start..]
+                .iter()
+                .take_while(|other| **other == Some(column))
+                .count();
+
+        if end - start >= 2 {
+            let first = &pipeline.elements[start].expr;
+            let last = &pipeline.elements[end - 1].expr;
+            detections.push(
+                Detection::from_global_span(
+                    format!(
+                        "{} consecutive stages write column '{column}'; combine them into one",
+                        end - start
+                    ),
+                    Span::new(first.span.start, last.span.end),
+                )
+                .with_primary_label("same column updated repeatedly")
+                .with_extra_label("first write", first.span)
+                .with_extra_label("last write", last.span),
+            );
+        }
+        start = end;
+    }
+    detections
+}
+
+struct ChainedColumnUpdate;
+
+impl DetectFix for ChainedColumnUpdate {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "chained_column_update"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Adjacent `update`/`upsert` stages on the same column"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Several `update`, `upsert` or `insert` stages in a row that write the same column \
+             can be merged into a single stage whose closure applies all transformations, for \
+             example `update name {|row| $row.name | str trim | str downcase }`. This is not \
+             fixed automatically, because merging the closures depends on how each one uses its \
+             parameter.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/update.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.ast.detect_in_pipelines(context, check_pipeline))
+    }
+}
+
+pub static RULE: &dyn Rule = &ChainedColumnUpdate;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
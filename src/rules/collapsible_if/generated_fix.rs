@@ -17,3 +17,21 @@ fn test_fix_with_parentheses() {
     let bad_code = r"if ($enabled) { if ($ready) { start } }";
     RULE.assert_fixed_contains(bad_code, r"if ($enabled) and ($ready) { start }");
 }
+
+#[test]
+fn test_fix_parenthesizes_or_conditions() {
+    let bad_code = r#"if $a or $b { if $c or $d { print "yes" } }"#;
+    RULE.assert_fixed_contains(bad_code, r#"if ($a or $b) and ($c or $d) { print "yes" }"#);
+}
+
+#[test]
+fn test_fix_parenthesizes_not_condition() {
+    let bad_code = r#"if not $quiet { if $verbose { print "log" } }"#;
+    RULE.assert_fixed_contains(bad_code, r#"if (not $quiet) and $verbose { print "log" }"#);
+}
+
+#[test]
+fn test_fix_keeps_and_condition_bare() {
+    let bad_code = r#"if $a and $b { if $c { print "yes" } }"#;
+    RULE.assert_fixed_contains(bad_code, r#"if $a and $b and $c { print "yes" }"#);
+}
@@ -1,6 +1,6 @@
 use nu_protocol::{
     Span,
-    ast::{Block, Call, Expr},
+    ast::{Block, Boolean, Call, Expr, Expression, Operator},
 };
 
 use crate::{
@@ -28,10 +28,32 @@ fn get_nested_single_if<'a>(call: &Call, context: &'a LintContext<'a>) -> Option
     get_single_if_call(context.working_set.get_block(then_block_id), context)
 }
 
+/// Whether joining the condition with `and` would regroup it: `or` and `xor`
+/// bind weaker than `and`, and `not` would swallow the other condition
+fn needs_parentheses(condition: &Expression) -> bool {
+    match &condition.expr {
+        Expr::BinaryOp(_, op, _) => matches!(
+            op.expr,
+            Expr::Operator(Operator::Boolean(Boolean::Or | Boolean::Xor))
+        ),
+        Expr::UnaryNot(_) => true,
+        _ => false,
+    }
+}
+
+fn condition_text(condition: &Expression, context: &LintContext) -> String {
+    let text = context.span_text(condition.span).trim();
+    if needs_parentheses(condition) {
+        format!("({text})")
+    } else {
+        text.to_string()
+    }
+}
+
 pub struct FixData {
     replace_span: Span,
-    outer_condition: Span,
-    inner_condition: Span,
+    outer_condition: String,
+    inner_condition: String,
     inner_body: Span,
 }
 
@@ -48,6 +70,15 @@ impl DetectFix for CollapsibleIf {
         "Nested if-statements collapsible with `and`"
     }
 
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "An `if` without `else` whose body is just another `if` without `else` runs the inner \
+             body only when both conditions hold, which `if $a and $b { ... }` says in one line. \
+             Conditions using `or`, `xor` or `not` are wrapped in parentheses so that merging \
+             them keeps their meaning.",
+        )
+    }
+
     fn source_link(&self) -> Option<&'static str> {
         Some("https://www.nushell.sh/book/control_flow.html")
     }
@@ -93,8 +124,8 @@ impl DetectFix for CollapsibleIf {
 
                 let fix_data = FixData {
                     replace_span: call.span(),
-                    outer_condition: outer_condition.span,
-                    inner_condition: inner_condition.span,
+                    outer_condition: condition_text(outer_condition, ctx),
+                    inner_condition: condition_text(inner_condition, ctx),
                     inner_body: inner_body.span,
                 };
 
@@ -105,11 +136,12 @@ impl DetectFix for CollapsibleIf {
     }
 
     fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
-        let outer_cond = context.span_text(fix_data.outer_condition).trim();
-        let inner_cond = context.span_text(fix_data.inner_condition).trim();
         let body = context.span_text(fix_data.inner_body).trim();
 
-        let fix_text = format!("if {outer_cond} and {inner_cond} {body}");
+        let fix_text = format!(
+            "if {} and {} {body}",
+            fix_data.outer_condition, fix_data.inner_condition
+        );
 
         Some(Fix {
             explanation: "Collapse nested if statements".into(),
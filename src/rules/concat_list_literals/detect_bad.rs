@@ -0,0 +1,33 @@
+use super::RULE;
+
+#[test]
+fn detects_single_element_lists() {
+    let bad_code = r#"
+def build [a: list<int>, x: int, y: int, z: int] {
+    $a ++ [$x] ++ [$y] ++ [$z]
+}
+"#;
+    RULE.assert_count(bad_code, 1);
+}
+
+#[test]
+fn detects_two_literals() {
+    let bad_code = r#"let items = [1] ++ [2]"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_literals_around_variable() {
+    let bad_code = r#"
+def build [middle: list<string>] {
+    ["a"] ++ ["b"] ++ $middle ++ ["y"] ++ ["z"]
+}
+"#;
+    RULE.assert_count(bad_code, 1);
+}
+
+#[test]
+fn detects_multi_element_literals() {
+    let bad_code = r#"let items = [1 2] ++ [3 4]"#;
+    RULE.assert_detects(bad_code);
+}
@@ -0,0 +1,32 @@
+use super::RULE;
+
+#[test]
+fn fix_merges_trailing_literals() {
+    let bad_code = r#"
+def build [a: list<int>, x: int, y: int, z: int] {
+    $a ++ [$x] ++ [$y] ++ [$z]
+}
+"#;
+    let expected = r#"
+def build [a: list<int>, x: int, y: int, z: int] {
+    $a ++ [$x $y $z]
+}
+"#;
+    RULE.assert_fixed_is(bad_code, expected);
+}
+
+#[test]
+fn fix_merges_each_run() {
+    let bad_code = r#"
+def build [middle: list<string>] {
+    ["a"] ++ ["b"] ++ $middle ++ ["y"] ++ ["z"]
+}
+"#;
+    RULE.assert_fixed_contains(bad_code, r#"["a" "b"] ++ $middle ++ ["y" "z"]"#);
+}
+
+#[test]
+fn fix_keeps_multi_element_literals() {
+    let bad_code = r#"let items = [1 2] ++ [3 4]"#;
+    RULE.assert_fixed_is(bad_code, r#"let items = [1 2 3 4]"#);
+}
@@ -0,0 +1,37 @@
+use super::RULE;
+
+#[test]
+fn ignores_string_concatenation() {
+    let good_code = r#"let greeting = "hello" ++ " " ++ "world""#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_single_literal() {
+    let good_code = r#"
+def build [a: list<int>, x: int] {
+    $a ++ [$x]
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_literals_separated_by_variable() {
+    let good_code = r#"
+def build [middle: list<int>] {
+    [1] ++ $middle ++ [2]
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_merged_literal() {
+    let good_code = r#"
+def build [a: list<int>, x: int, y: int] {
+    $a ++ [$x $y]
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
@@ -0,0 +1,159 @@
+use nu_protocol::{
+    Span,
+    ast::{Expr, Expression, Math, Operator},
+};
+
+use crate::{
+    LintLevel,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    chain_span: Span,
+    /// Each run of adjacent list literals, outermost brackets included
+    runs: Vec<Vec<Span>>,
+}
+
+fn is_concat(op: &Expression) -> bool {
+    matches!(op.expr, Expr::Operator(Operator::Math(Math::Concatenate)))
+}
+
+/// Flattens a left- or right-nested chain of `++` into its operands.
+fn flatten_concat<'a>(expr: &'a Expression, operands: &mut Vec<&'a Expression>) {
+    match &expr.expr {
+        Expr::BinaryOp(lhs, op, rhs) if is_concat(op) => {
+            flatten_concat(lhs, operands);
+            flatten_concat(rhs, operands);
+        }
+        _ => operands.push(expr),
+    }
+}
+
+/// Groups adjacent list literal operands, keeping only groups of two or more.
+fn list_literal_runs(operands: &[&Expression]) -> Vec<Vec<Span>> {
+    operands
+        .chunk_by(|a, b| matches!(a.expr, Expr::List(_)) && matches!(b.expr, Expr::List(_)))
+        .filter(|run| run.len() > 1)
+        .map(|run| run.iter().map(|operand| operand.span).collect())
+        .collect()
+}
+
+fn check_concat(expr: &Expression) -> Option<(Detection, FixData)> {
+    let Expr::BinaryOp(_, op, _) = &expr.expr else {
+        return None;
+    };
+    if !is_concat(op) {
+        return None;
+    }
+
+    let mut operands = Vec::new();
+    flatten_concat(expr, &mut operands);
+    let runs = list_literal_runs(&operands);
+    let merged: usize = runs.iter().map(Vec::len).sum();
+    if merged == 0 {
+        return None;
+    }
+
+    let detection = runs.iter().flatten().fold(
+        Detection::from_global_span(
+            format!("{merged} list literals joined with `++` can be written as one list"),
+            expr.span,
+        )
+        .with_primary_label("concatenation of literals"),
+        |detection, span| detection.with_extra_span(*span),
+    );
+
+    Some((
+        detection,
+        FixData {
+            chain_span: expr.span,
+            runs,
+        },
+    ))
+}
+
+struct ConcatListLiterals;
+
+impl DetectFix for ConcatListLiterals {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "concat_list_literals"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Adjacent list literals joined with `++` instead of one list"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`$a ++ [$x] ++ [$y] ++ [$z]` builds intermediate lists one element at a time. \
+             Adjacent list literals in a `++` chain can be merged into a single literal, as in \
+             `$a ++ [$x $y $z]`, which is shorter and creates fewer copies.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/operators.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let detections =
+            context.detect_with_fix_data(|expr, _ctx| check_concat(expr).into_iter().collect());
+
+        // A chain `a ++ b ++ c` is nested, report only its outermost `++`
+        let chain_spans: Vec<Span> = detections
+            .iter()
+            .map(|(_, fix_data)| fix_data.chain_span)
+            .collect();
+        detections
+            .into_iter()
+            .filter(|(_, fix_data)| {
+                let span = fix_data.chain_span;
+                !chain_spans
+                    .iter()
+                    .any(|outer| *outer != span && outer.contains_span(span))
+            })
+            .collect()
+    }
+
+    fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let replacements = fix_data
+            .runs
+            .iter()
+            .map(|run| {
+                let items: Vec<&str> = run
+                    .iter()
+                    .map(|span| {
+                        context
+                            .span_text(Span::new(span.start + 1, span.end - 1))
+                            .trim()
+                    })
+                    .filter(|items| !items.is_empty())
+                    .collect();
+                let span = Span::new(run.first()?.start, run.last()?.end);
+                Some(Replacement::new(span, format!("[{}]", items.join(" "))))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Fix {
+            explanation: "Merge adjacent list literals".into(),
+            replacements,
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &ConcatListLiterals;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
@@ -0,0 +1,28 @@
+use super::RULE;
+use crate::{Config, config::ParEachCandidates};
+
+#[test]
+fn detects_http_request_per_element() {
+    RULE.assert_detects(r"$urls | each {|url| http get $url }");
+}
+
+#[test]
+fn detects_external_command_per_element() {
+    RULE.assert_detects(r"ls *.png | each {|img| ^optipng $img.name }");
+}
+
+#[test]
+fn detects_nested_expensive_call() {
+    RULE.assert_detects(r"ls | each {|f| if $f.type == file { open $f.name | hash sha256 } }");
+}
+
+#[test]
+fn detects_configured_command() {
+    let config = Config {
+        consider_par_each: ParEachCandidates {
+            expensive_commands: vec!["sleep".to_string()],
+        },
+        ..Config::default()
+    };
+    RULE.assert_detects_with_config(r"[1 2 3] | each {|n| sleep 1sec; $n }", &config);
+}
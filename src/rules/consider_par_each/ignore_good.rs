@@ -0,0 +1,16 @@
+use super::RULE;
+
+#[test]
+fn ignores_cheap_closure() {
+    RULE.assert_ignores(r"[1 2 3] | each {|n| $n * 2 }");
+}
+
+#[test]
+fn ignores_par_each() {
+    RULE.assert_ignores(r"$urls | par-each {|url| http get $url }");
+}
+
+#[test]
+fn ignores_each_without_input() {
+    RULE.assert_ignores(r"each {|url| http get $url }");
+}
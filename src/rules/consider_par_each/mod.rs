@@ -0,0 +1,112 @@
+use nu_protocol::{
+    Span,
+    ast::{Expr, Expression, Pipeline, Traverse},
+};
+
+use crate::{
+    LintLevel,
+    ast::call::CallExt,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Name and span of the slow commands called anywhere in the closure
+fn expensive_calls(closure: &Expression, context: &LintContext) -> Vec<(String, Span)> {
+    let Expr::Closure(block_id) = &closure.expr else {
+        return vec![];
+    };
+    let expensive = &context.config.consider_par_each.expensive_commands;
+    let mut calls = Vec::new();
+    context.working_set.get_block(*block_id).flat_map(
+        context.working_set,
+        &|expr| match &expr.expr {
+            Expr::Call(call) => {
+                let name = call.get_call_name(context);
+                if expensive.contains(&name) {
+                    vec![(name, call.head)]
+                } else {
+                    vec![]
+                }
+            }
+            Expr::ExternalCall(head, _) => {
+                vec![(context.span_text(head.span).to_string(), head.span)]
+            }
+            _ => vec![],
+        },
+        &mut calls,
+    );
+    calls
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    pipeline
+        .elements
+        .iter()
+        .skip(1)
+        .filter_map(|element| {
+            let Expr::Call(call) = &element.expr.expr else {
+                return None;
+            };
+            if !call.is_call_to_command("each", context) {
+                return None;
+            }
+            let calls = expensive_calls(call.get_first_positional_arg()?, context);
+            let (name, span) = calls.first()?;
+            Some(
+                Detection::from_global_span(
+                    format!(
+                        "`each` runs `{name}` for every element one at a time; consider \
+                         `par-each` if the order of the results doesn't matter"
+                    ),
+                    call.head,
+                )
+                .with_primary_label("sequential iteration")
+                .with_extra_label("slow command", *span),
+            )
+        })
+        .collect()
+}
+
+struct ConsiderParEach;
+
+impl DetectFix for ConsiderParEach {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "consider_par_each"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`each` calling slow commands that could run in parallel"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`par-each` runs the closure on several threads, which helps when every iteration \
+             waits on an external command, the network or the disk. It returns results in a \
+             different order unless `--keep-order` is given, and side effects interleave, so no \
+             fix is offered. The commands that count as slow are listed in \
+             `consider_par_each.expensive_commands`; external commands always count.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/par-each.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Off
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.ast.detect_in_pipelines(context, check_pipeline))
+    }
+}
+
+pub static RULE: &dyn Rule = &ConsiderParEach;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
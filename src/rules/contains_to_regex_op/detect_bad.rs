@@ -52,3 +52,13 @@ fn test_detect_str_contains_with_whitespace_pattern() {
     let bad_code = r#"let has_space = ($text | str contains ' test ')"#;
     RULE.assert_detects(bad_code);
 }
+
+#[test]
+fn test_detect_str_contains_in_where_row_condition() {
+    RULE.assert_detects(r#"open log.txt | lines | where ($it | str contains "foo")"#);
+}
+
+#[test]
+fn test_detect_str_contains_in_where_closure() {
+    RULE.assert_detects(r#"open log.txt | lines | where {|line| $line | str contains "foo" }"#);
+}
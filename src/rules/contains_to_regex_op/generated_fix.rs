@@ -35,3 +35,15 @@ fn test_fix_variable() {
     let bad_code = "($str | str contains $a)";
     RULE.assert_fixed_contains(bad_code, "=~ $a");
 }
+
+#[test]
+fn test_fix_str_contains_in_where_closure() {
+    let bad_code = r#"lines | where {|line| $line | str contains "foo" }"#;
+    RULE.assert_fixed_contains(bad_code, r#"where {|line| $line =~ "foo" }"#);
+}
+
+#[test]
+fn test_fix_str_contains_in_where_row_condition() {
+    let bad_code = r#"lines | where ($it | str contains "foo")"#;
+    RULE.assert_fixed_contains(bad_code, r#"where $it =~ "foo""#);
+}
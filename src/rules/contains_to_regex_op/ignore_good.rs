@@ -88,3 +88,13 @@ if ($text | str contains -i needle) {
 "#;
     RULE.assert_ignores(good_code);
 }
+
+#[test]
+fn test_ignore_where_closure_with_regex_special_chars() {
+    RULE.assert_ignores(r#"lines | where {|line| $line | str contains "a.b" }"#);
+}
+
+#[test]
+fn test_ignore_str_contains_after_other_stages() {
+    RULE.assert_ignores(r"let found = ($text | str trim | str contains 'a')");
+}
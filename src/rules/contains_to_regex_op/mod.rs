@@ -1,4 +1,7 @@
-use nu_protocol::ast::{Expr, Expression};
+use nu_protocol::{
+    Span,
+    ast::{Expr, Expression, Pipeline},
+};
 
 use crate::{
     Fix, LintLevel, Replacement,
@@ -45,9 +48,15 @@ fn extract_str_contains_data<'a>(
     };
 
     let block = context.working_set.get_block(block_id);
-    let pipeline = block.pipelines.first()?;
+    str_contains_pipeline(block.pipelines.first()?, context)
+}
 
-    if pipeline.elements.len() < 2 {
+/// Value and pattern of a `VALUE | str contains PATTERN` pipeline
+fn str_contains_pipeline<'a>(
+    pipeline: &'a Pipeline,
+    context: &LintContext,
+) -> Option<(&'a Expression, &'a Expression)> {
+    if pipeline.elements.len() != 2 {
         return None;
     }
 
@@ -72,7 +81,50 @@ fn extract_str_contains_data<'a>(
     Some((&first_element.expr, pattern_arg))
 }
 
+/// A `where {|x| $x | str contains PATTERN }` closure, whose body is the
+/// `str contains` pipeline itself rather than a parenthesized subexpression
+fn check_where_closure(expr: &Expression, context: &LintContext) -> Option<(Detection, FixData)> {
+    let Expr::Call(call) = &expr.expr else {
+        return None;
+    };
+    if !call.is_call_to_command("where", context) {
+        return None;
+    }
+    let Expr::Closure(block_id) = &call.get_first_positional_arg()?.expr else {
+        return None;
+    };
+
+    let block = context.working_set.get_block(*block_id);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return None;
+    };
+    let (string_expr, pattern_expr) = str_contains_pipeline(pipeline, context)?;
+    if !is_valid_pattern(pattern_expr, context) {
+        return None;
+    }
+
+    let span = Span::new(string_expr.span.start, pipeline.elements[1].expr.span.end);
+    let violation = Detection::from_global_span(
+        "Use '=~' operator instead of verbose 'str contains' check in 'where' predicate",
+        span,
+    )
+    .with_primary_label("verbose str contains pattern");
+
+    let fix_data = FixData {
+        full_expr_span: span,
+        string_span: string_expr.span,
+        pattern_span: pattern_expr.span,
+        is_negated: false,
+    };
+
+    Some((violation, fix_data))
+}
+
 fn check_contains_pattern(expr: &Expression, context: &LintContext) -> Vec<(Detection, FixData)> {
+    if let Some(detected) = check_where_closure(expr, context) {
+        return vec![detected];
+    }
+
     let (is_negated, inner_expr) = match &expr.expr {
         Expr::UnaryNot(inner) => (true, inner.as_ref()),
         _ => (false, expr),
@@ -121,6 +173,15 @@ impl DetectFix for UseRegexOperators {
         "Use =~ and !~ operators instead of verbose 'str contains' checks"
     }
 
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`$text =~ 'foo'` tests for a substring without piping into `str contains`. This also \
+             applies to `where` predicates, written either as `where ($it | str contains 'foo')` \
+             or as a closure. Patterns with regex metacharacters are left alone, since `=~` would \
+             interpret them as a regular expression.",
+        )
+    }
+
     fn source_link(&self) -> Option<&'static str> {
         Some("https://www.nushell.sh/book/operators.html")
     }
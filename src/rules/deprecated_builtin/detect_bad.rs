@@ -0,0 +1,41 @@
+use super::RULE;
+
+#[test]
+fn detects_str_collect() {
+    RULE.assert_detects(r"['a' 'b'] | str collect");
+}
+
+#[test]
+fn detects_str_collect_with_separator() {
+    RULE.assert_detects(r"['a' 'b'] | str collect ', '");
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r"
+def csv-line [fields: list<string>] {
+    $fields | str collect ','
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_empty_question_mark() {
+    RULE.assert_detects(r"[] | empty?");
+}
+
+#[test]
+fn detects_build_string() {
+    RULE.assert_detects(r"build-string 'a' 'b'");
+}
+
+#[test]
+fn detects_let_env() {
+    RULE.assert_detects(r"let-env FOO = 'bar'");
+}
+
+#[test]
+fn detects_nu_scope() {
+    RULE.assert_detects(r"$nu.scope.commands | length");
+}
@@ -0,0 +1,19 @@
+use super::RULE;
+
+#[test]
+fn fix_keeps_separator() {
+    RULE.assert_fixed_is(
+        r#"['a' 'b'] | str collect ", ""#,
+        r#"['a' 'b'] | str join ", ""#,
+    );
+}
+
+#[test]
+fn fix_without_separator() {
+    RULE.assert_fixed_is(r"['a' 'b'] | str collect", r"['a' 'b'] | str join");
+}
+
+#[test]
+fn fix_empty_question_mark() {
+    RULE.assert_fixed_is(r"[] | empty?", r"[] | is-empty");
+}
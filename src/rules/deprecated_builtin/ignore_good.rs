@@ -0,0 +1,36 @@
+use super::RULE;
+
+#[test]
+fn ignore_str_join() {
+    RULE.assert_ignores(r"['a' 'b'] | str join ', '");
+}
+
+#[test]
+fn ignore_collect() {
+    RULE.assert_ignores(r"[1 2] | collect");
+}
+
+#[test]
+fn ignore_other_str_subcommand() {
+    RULE.assert_ignores(r"'abc' | str length");
+}
+
+#[test]
+fn ignore_is_empty() {
+    RULE.assert_ignores(r"[] | is-empty");
+}
+
+#[test]
+fn ignore_env_assignment() {
+    RULE.assert_ignores(r"$env.FOO = 'bar'");
+}
+
+#[test]
+fn ignore_other_nu_fields() {
+    RULE.assert_ignores(r"$nu.home-path | path join 'x'");
+}
+
+#[test]
+fn ignore_scope_command() {
+    RULE.assert_ignores(r"scope commands | length");
+}
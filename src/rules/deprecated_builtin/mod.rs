@@ -0,0 +1,169 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use lsp_types::DiagnosticTag;
+use nu_protocol::{
+    NU_VARIABLE_ID, Span,
+    ast::{Call, Expr, Expression, PathMember},
+};
+
+use crate::{
+    LintLevel,
+    ast::call::CallExt,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+/// What replaced a removed built-in
+#[derive(Clone, Copy)]
+enum Successor {
+    /// Same arguments under a new name
+    Rename(&'static str),
+    /// Needs a different construct, described by the help text
+    Rewrite(&'static str),
+}
+
+/// Removed built-ins by their old name. Add new deprecations here.
+const DEPRECATIONS: &[(&str, Successor)] = &[
+    ("str collect", Successor::Rename("str join")),
+    ("empty?", Successor::Rename("is-empty")),
+    (
+        "build-string",
+        Successor::Rewrite("use string interpolation, like `$\"($a)-($b)\"`"),
+    ),
+    (
+        "let-env",
+        Successor::Rewrite("assign to the environment with `$env.NAME = value`"),
+    ),
+    (
+        "$nu.scope",
+        Successor::Rewrite("use the `scope` commands, like `scope commands` or `scope variables`"),
+    ),
+];
+
+static REGISTRY: LazyLock<HashMap<&'static str, Successor>> =
+    LazyLock::new(|| DEPRECATIONS.iter().copied().collect());
+
+struct FixData {
+    /// The written command name, without its arguments
+    name_span: Span,
+    replacement: &'static str,
+}
+
+/// Name of the command as written, with the span covering it. A removed
+/// subcommand like `str collect` parses as a call to its parent `str` with
+/// the subcommand word as first argument.
+fn written_call_name(call: &Call, context: &LintContext) -> (String, Span) {
+    let name = call.get_call_name(context);
+    call.get_first_positional_arg()
+        .map(|arg| (format!("{name} {}", context.span_text(arg.span)), arg.span))
+        .filter(|(full, _)| REGISTRY.contains_key(full.as_str()))
+        .map_or((name, call.head), |(full, arg_span)| {
+            (full, Span::new(call.head.start, arg_span.end))
+        })
+}
+
+/// The deprecated name used by an expression, with the span to report
+fn deprecated_use(expr: &Expression, context: &LintContext) -> Option<(String, Span)> {
+    match &expr.expr {
+        Expr::Call(call) => Some(written_call_name(call, context)),
+        // Removed commands without a declaration parse as external calls
+        Expr::ExternalCall(head, _) => Some((context.span_text(head.span).to_string(), head.span)),
+        Expr::FullCellPath(cell_path) => {
+            let Expr::Var(var_id) = cell_path.head.expr else {
+                return None;
+            };
+            let Some(PathMember::String { val, span, .. }) = cell_path.tail.first() else {
+                return None;
+            };
+            (var_id == NU_VARIABLE_ID).then(|| {
+                (
+                    format!("$nu.{val}"),
+                    Span::new(cell_path.head.span.start, span.end),
+                )
+            })
+        }
+        _ => None,
+    }
+}
+
+fn check_expression(expr: &Expression, context: &LintContext) -> Vec<(Detection, Option<FixData>)> {
+    let Some((name, span)) = deprecated_use(expr, context) else {
+        return vec![];
+    };
+    let Some(successor) = REGISTRY.get(name.as_str()) else {
+        return vec![];
+    };
+
+    let (message, fix_data) = match *successor {
+        Successor::Rename(new) => (
+            format!("`{name}` was renamed to `{new}`"),
+            Some(FixData {
+                name_span: span,
+                replacement: new,
+            }),
+        ),
+        Successor::Rewrite(help) => (format!("`{name}` was removed; {help}"), None),
+    };
+    vec![(
+        Detection::from_global_span(message, span).with_primary_label("removed built-in"),
+        fix_data,
+    )]
+}
+
+struct DeprecatedBuiltin;
+
+impl DetectFix for DeprecatedBuiltin {
+    type FixInput<'a> = Option<FixData>;
+
+    fn id(&self) -> &'static str {
+        "deprecated_builtin"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Built-in that was renamed or removed in a later Nushell release"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Some built-in commands were renamed or replaced and the old names were later \
+             removed, so scripts written for older Nushell versions fail to parse. Renamed \
+             commands take the same arguments and are fixed automatically, for example `str \
+             collect ', '` becomes `str join ', '`. Others, like `let-env` and `build-string`, \
+             need a different construct and only come with a hint.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/blog/")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn diagnostic_tags(&self) -> &'static [DiagnosticTag] {
+        &[DiagnosticTag::DEPRECATED]
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.detect_with_fix_data(check_expression)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let fix_data = fix_data.as_ref()?;
+        Some(Fix {
+            explanation: format!("Rename to `{}`", fix_data.replacement).into(),
+            replacements: vec![Replacement::new(fix_data.name_span, fix_data.replacement)],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &DeprecatedBuiltin;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
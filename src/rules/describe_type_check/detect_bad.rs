@@ -0,0 +1,41 @@
+use super::RULE;
+
+#[test]
+fn detects_if_on_describe() {
+    RULE.assert_detects(
+        r#"def f [x] { if ($x | describe) == "string" { 'text' } else { 'other' } }"#,
+    );
+}
+
+#[test]
+fn detects_not_equal() {
+    RULE.assert_detects(
+        r"def f [x] { if ($x | describe) != 'int' { error make {msg: 'not an int'} } }",
+    );
+}
+
+#[test]
+fn detects_literal_on_left() {
+    RULE.assert_detects(r"def f [x] { 'list<any>' == ($x | describe) }");
+}
+
+#[test]
+fn detects_in_where_condition() {
+    RULE.assert_detects(r"[1 'a' 2] | where {|v| ($v | describe) == 'int' }");
+}
+
+#[test]
+fn counts_each_comparison() {
+    let bad_code = r"
+def kind [x] {
+    if ($x | describe) == 'string' {
+        'text'
+    } else if ($x | describe) == 'int' {
+        'number'
+    } else {
+        'other'
+    }
+}
+";
+    RULE.assert_count(bad_code, 2);
+}
@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn ignore_describe_for_display() {
+    RULE.assert_ignores(r#"def f [x] { print $"got a ($x | describe)" }"#);
+}
+
+#[test]
+fn ignore_describe_output_value() {
+    RULE.assert_ignores(r"[1 2 3] | describe");
+}
+
+#[test]
+fn ignore_detailed_type_field() {
+    RULE.assert_ignores(r"def f [x] { if ($x | describe --detailed).type == 'list' { 'many' } }");
+}
+
+#[test]
+fn ignore_comparison_with_other_command() {
+    RULE.assert_ignores(r"def f [x] { if ($x | str trim) == 'yes' { true } }");
+}
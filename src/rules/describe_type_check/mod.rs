@@ -0,0 +1,106 @@
+use nu_protocol::{
+    Span,
+    ast::{Comparison, Expr, Expression, Operator},
+};
+
+use crate::{
+    LintLevel,
+    ast::{call::CallExt, expression::ExpressionExt, string::StringFormat},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Span of a plain `describe` call ending a subexpression like `($x |
+/// describe)`
+fn describe_call_span(expr: &Expression, context: &LintContext) -> Option<Span> {
+    let block = context.working_set.get_block(expr.extract_block_id()?);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return None;
+    };
+    let Expr::Call(call) = &pipeline.elements.last()?.expr.expr else {
+        return None;
+    };
+    (call.is_call_to_command("describe", context)
+        && !call.has_named_flag("detailed")
+        && !call.has_named_flag("d"))
+    .then_some(call.span())
+}
+
+fn check_comparison(expr: &Expression, context: &LintContext) -> Option<Detection> {
+    let Expr::BinaryOp(lhs, op, rhs) = &expr.expr else {
+        return None;
+    };
+    if !matches!(
+        op.expr,
+        Expr::Operator(Operator::Comparison(
+            Comparison::Equal | Comparison::NotEqual
+        ))
+    ) {
+        return None;
+    }
+
+    let (describe_span, literal) = describe_call_span(lhs, context)
+        .map(|span| (span, rhs))
+        .or_else(|| describe_call_span(rhs, context).map(|span| (span, lhs)))?;
+    let type_name = StringFormat::from_expression(literal, context)?
+        .content()
+        .to_string();
+
+    Some(
+        Detection::from_global_span(
+            format!(
+                "Comparing `describe` output to '{type_name}' breaks when the description \
+                 changes; compare `(... | describe --detailed).type` or match on the value instead"
+            ),
+            expr.span,
+        )
+        .with_primary_label("type check on a description string")
+        .with_extra_label("produces text like `list<string>`", describe_span),
+    )
+}
+
+struct DescribeTypeCheck;
+
+impl DetectFix for DescribeTypeCheck {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "describe_type_check"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Control flow depending on the exact text of `describe`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`describe` returns a human-readable description, not a type tag. Lists and records \
+             include their element types (`list<int>`, `record<a: int>`), tables read \
+             `table<...>`, and the wording can change between Nushell versions, so `($x | \
+             describe) == \"list\"` is never true for an actual list. `describe --detailed` \
+             returns a record whose `type` field is just the base type (`list`, `record`, \
+             `string`), which is stable to compare against. Often a typed parameter or a `match` \
+             on the value itself removes the need for the check altogether.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/describe.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.detect_single(check_comparison))
+    }
+}
+
+pub static RULE: &dyn Rule = &DescribeTypeCheck;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
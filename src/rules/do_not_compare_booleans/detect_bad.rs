@@ -79,3 +79,11 @@ if $a == true and $b == false { do_something }
 "#;
     RULE.assert_count(bad_code, 2);
 }
+
+#[test]
+fn test_detect_in_where_row_condition() {
+    let bad_code = r#"
+$rows | where enabled == false
+"#;
+    RULE.assert_detects(bad_code);
+}
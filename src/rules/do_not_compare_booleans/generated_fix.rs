@@ -62,3 +62,31 @@ fn test_fix_let_binding() {
     let expected = "let result = $check";
     RULE.assert_fixed_is(bad_code, expected);
 }
+
+#[test]
+fn test_fix_where_closure() {
+    let bad_code = "$items | where { $in.active == true }";
+    let expected = "$items | where { $in.active }";
+    RULE.assert_fixed_is(bad_code, expected);
+}
+
+#[test]
+fn test_fix_where_shorthand_column() {
+    let bad_code = "$items | where active == true";
+    let expected = "$items | where $it.active";
+    RULE.assert_fixed_is(bad_code, expected);
+}
+
+#[test]
+fn test_fix_where_shorthand_column_negated() {
+    let bad_code = "$items | where archived != true";
+    let expected = "$items | where (not $it.archived)";
+    RULE.assert_fixed_is(bad_code, expected);
+}
+
+#[test]
+fn test_fix_where_it_column() {
+    let bad_code = "$items | where $it.active == false";
+    let expected = "$items | where (not $it.active)";
+    RULE.assert_fixed_is(bad_code, expected);
+}
@@ -15,10 +15,8 @@ use crate::{
 pub struct FixData {
     /// The span of the entire comparison expression
     full_expr: Span,
-    /// The span of the non-boolean operand (the variable/expression to keep)
-    operand: Span,
-    /// Whether the fix needs negation (not $x)
-    needs_negation: bool,
+    /// The non-boolean operand, negated with `not` when needed
+    replacement: String,
 }
 
 /// Check if an expression is a boolean literal and return its value
@@ -30,11 +28,11 @@ const fn is_bool_literal(expr: &Expression) -> Option<bool> {
 }
 
 /// Analyze a binary comparison with a boolean literal.
-const fn analyze_bool_comparison(
-    left: &Expression,
-    right: &Expression,
+const fn analyze_bool_comparison<'a>(
+    left: &'a Expression,
+    right: &'a Expression,
     is_equal: bool,
-) -> Option<(Span, bool)> {
+) -> Option<(&'a Expression, bool)> {
     // Check if left is bool literal
     if let Some(bool_val) = is_bool_literal(left) {
         // `true == $x` or `false == $x` etc.
@@ -43,7 +41,7 @@ const fn analyze_bool_comparison(
         } else {
             bool_val // `true !=` needs negation, `false !=` keeps as-is
         };
-        return Some((right.span, needs_negation));
+        return Some((right, needs_negation));
     }
 
     // Check if right is bool literal
@@ -54,12 +52,36 @@ const fn analyze_bool_comparison(
         } else {
             bool_val // `!= true` needs negation, `!= false` keeps as-is
         };
-        return Some((left.span, needs_negation));
+        return Some((left, needs_negation));
     }
 
     None
 }
 
+/// Whether the operand is a column name written without `$it.`, which is only
+/// allowed on the left of a comparison in a `where` row condition
+fn is_row_condition_shorthand(operand: &Expression, context: &LintContext) -> bool {
+    let Expr::FullCellPath(cell_path) = &operand.expr else {
+        return false;
+    };
+    matches!(cell_path.head.expr, Expr::Var(_)) && !context.span_text(operand.span).starts_with('$')
+}
+
+/// Text that replaces the comparison: the operand itself, or `(not operand)`
+fn simplified_operand(operand: &Expression, needs_negation: bool, context: &LintContext) -> String {
+    let operand_text = context.span_text(operand.span);
+    let operand_text = if is_row_condition_shorthand(operand, context) {
+        format!("$it.{operand_text}")
+    } else {
+        operand_text.to_string()
+    };
+    if needs_negation {
+        format!("(not {operand_text})")
+    } else {
+        operand_text
+    }
+}
+
 fn detect_redundant_bool_comparison(
     expr: &Expression,
     context: &LintContext,
@@ -76,8 +98,7 @@ fn detect_redundant_bool_comparison(
     };
 
     // Analyze if one side is a boolean literal
-    let Some((operand_span, needs_negation)) = analyze_bool_comparison(left, right, is_equal)
-    else {
+    let Some((operand, needs_negation)) = analyze_bool_comparison(left, right, is_equal) else {
         return vec![];
     };
 
@@ -101,8 +122,7 @@ fn detect_redundant_bool_comparison(
 
     let fix_data = FixData {
         full_expr: expr.span,
-        operand: operand_span,
-        needs_negation,
+        replacement: simplified_operand(operand, needs_negation, context),
     };
 
     vec![(detection, fix_data)]
@@ -121,6 +141,16 @@ impl DetectFix for RedundantBooleanComparison {
         "Redundant comparison with boolean literal"
     }
 
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Conditions of `if` and `where`, and any other boolean expression, can use a boolean \
+             value directly: `if $flag` instead of `if $flag == true`, and `if (not $flag)` \
+             instead of `if $flag == false`. In a `where` row condition, a column compared to a \
+             literal is rewritten to `$it.column`, because a bare column name is only understood \
+             on the left of a comparison.",
+        )
+    }
+
     fn source_link(&self) -> Option<&'static str> {
         Some("https://www.nushell.sh/book/types_of_data.html#booleans")
     }
@@ -137,18 +167,13 @@ impl DetectFix for RedundantBooleanComparison {
         context.detect_with_fix_data(|expr, ctx| detect_redundant_bool_comparison(expr, ctx))
     }
 
-    fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
-        let operand_text = context.span_text(fix_data.operand);
-
-        let replacement_text = if fix_data.needs_negation {
-            format!("(not {operand_text})")
-        } else {
-            operand_text.to_string()
-        };
-
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
         Some(Fix {
             explanation: "simplify".into(),
-            replacements: vec![Replacement::new(fix_data.full_expr, replacement_text)],
+            replacements: vec![Replacement::new(
+                fix_data.full_expr,
+                fix_data.replacement.clone(),
+            )],
         })
     }
 }
@@ -53,3 +53,38 @@ export def my-command [] {
 "#;
     RULE.assert_count(source, 1);
 }
+
+#[test]
+fn exported_env_function_missing_documentation() {
+    let source = r"
+export def --env enter-project [] {
+    cd ~/project
+}
+";
+    RULE.assert_count(source, 1);
+}
+
+#[test]
+fn main_function_missing_documentation() {
+    let source = r#"
+def main [] {
+    print "hello"
+}
+"#;
+    RULE.assert_count(source, 1);
+}
+
+#[test]
+fn main_subcommand_missing_documentation() {
+    let source = r#"
+# Greet the user
+def main [] {
+    print "hello"
+}
+
+def "main build" [] {
+    print "building"
+}
+"#;
+    RULE.assert_count(source, 1);
+}
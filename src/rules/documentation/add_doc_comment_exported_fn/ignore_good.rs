@@ -70,3 +70,14 @@ export def my-command [] {
 "#;
     RULE.assert_ignores(source);
 }
+
+#[test]
+fn main_function_with_documentation() {
+    let source = r#"
+# Print a greeting
+def main [] {
+    print "hello"
+}
+"#;
+    RULE.assert_ignores(source);
+}
@@ -41,27 +41,28 @@ fn has_doc_comment_before(context: &LintContext, span: nu_protocol::Span) -> boo
 fn check_exported_function(call: &Call, context: &LintContext) -> Option<Detection> {
     let func_def = call.custom_command_def(context)?;
 
-    if !func_def.is_exported() {
+    if !func_def.is_exported() && !func_def.is_main() {
         return None;
     }
 
     let has_docs = has_doc_comment_before(context, call.head);
 
     if has_docs {
-        None
+        return None;
+    }
+
+    let kind = if func_def.is_main() {
+        "Entry point"
     } else {
-        Some(
-            Detection::from_global_span(
-                format!(
-                    "Exported function '{}' lacks documentation comment",
-                    func_def.name
-                ),
-                call.head,
-            )
-            .with_primary_label("missing doc comment")
-            .with_extra_label("exported function", func_def.name_span),
+        "Exported function"
+    };
+    Some(
+        Detection::from_global_span(
+            format!("{kind} '{}' lacks documentation comment", func_def.name),
+            func_def.name_span,
         )
-    }
+        .with_primary_label("missing doc comment"),
+    )
 }
 
 struct AddDocCommentExportedFn;
@@ -74,7 +75,16 @@ impl DetectFix for AddDocCommentExportedFn {
     }
 
     fn short_description(&self) -> &'static str {
-        "Exported functions should have documentation comments"
+        "Exported and `main` commands should have documentation comments"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "The comment lines directly above a `def` become the description that `help` shows \
+             for the command. Exported commands are the public interface of a module, and `main` \
+             commands are what users of a script see with `--help`, so both should explain what \
+             they do. No fix is offered, since the description has to be written by hand.",
+        )
     }
 
     fn source_link(&self) -> Option<&'static str> {
@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn detects_record_with_same_names() {
+    RULE.assert_detects(r"ls | each {|f| {name: $f.name, size: $f.size} }");
+}
+
+#[test]
+fn detects_renamed_fields() {
+    RULE.assert_detects(r"ls | each {|f| {file: $f.name, bytes: $f.size} }");
+}
+
+#[test]
+fn detects_list_of_fields() {
+    RULE.assert_detects(r"$rows | each {|x| [$x.a $x.b] }");
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r"
+def names [] {
+    ps | each {|p| {pid: $p.pid, name: $p.name} }
+}
+";
+    RULE.assert_detects(bad_code);
+}
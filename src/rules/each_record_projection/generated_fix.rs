@@ -0,0 +1,30 @@
+use super::RULE;
+
+#[test]
+fn fix_record_to_select() {
+    RULE.assert_fixed_is(
+        r"ls | each {|f| {name: $f.name, size: $f.size} }",
+        r"ls | select name size",
+    );
+}
+
+#[test]
+fn fix_single_field() {
+    RULE.assert_fixed_is(r"$rows | each {|r| {id: $r.id} }", r"$rows | select id");
+}
+
+#[test]
+fn fix_quotes_field_with_space() {
+    RULE.assert_fixed_is(
+        r#"$rows | each {|r| {"full name": $r."full name"} }"#,
+        r"$rows | select 'full name'",
+    );
+}
+
+#[test]
+fn renamed_fields_have_no_fix() {
+    // Renaming needs a `rename` stage, which is left to the user
+    let code = r"ls | each {|f| {file: $f.name} }";
+    RULE.assert_count(code, 1);
+    RULE.assert_no_fix(code);
+}
@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn ignore_select() {
+    RULE.assert_ignores(r"ls | select name size");
+}
+
+#[test]
+fn ignore_computed_field() {
+    RULE.assert_ignores(r"ls | each {|f| {name: $f.name, kb: ($f.size / 1kb)} }");
+}
+
+#[test]
+fn ignore_nested_field_access() {
+    RULE.assert_ignores(r"$rows | each {|r| {city: $r.address.city} }");
+}
+
+#[test]
+fn ignore_literal_value() {
+    RULE.assert_ignores(r"ls | each {|f| {name: $f.name, kind: 'file'} }");
+}
+
+#[test]
+fn ignore_multiple_statements() {
+    RULE.assert_ignores(r"ls | each {|f| print $f.name; {name: $f.name} }");
+}
+
+#[test]
+fn ignore_spread() {
+    RULE.assert_ignores(r"ls | each {|f| {...$f, name: $f.name} }");
+}
@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+
+use nu_protocol::{
+    Span, VarId,
+    ast::{Call, Expr, Expression, ListItem, PathMember, Pipeline, RecordItem},
+};
+
+use crate::{
+    LintLevel,
+    ast::{
+        block::BlockExt,
+        call::CallExt,
+        string::{StringFormat, cell_path_member_needs_quotes},
+    },
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+/// What the closure of `each` assembles from the fields of its parameter
+enum Projection {
+    /// `{|row| {a: $row.a, b: $row.b}}`, as (key, field) pairs
+    Record(Vec<(String, String)>),
+    /// `{|row| [$row.a $row.b]}`
+    List(Vec<String>),
+}
+
+impl Projection {
+    fn fields(&self) -> Vec<&str> {
+        match self {
+            Self::Record(pairs) => pairs.iter().map(|(_, field)| field.as_str()).collect(),
+            Self::List(fields) => fields.iter().map(String::as_str).collect(),
+        }
+    }
+
+    /// Whether every key repeats its field name, so the record equals the
+    /// output of `select`
+    fn keeps_names(&self) -> bool {
+        let Self::Record(pairs) = self else {
+            return false;
+        };
+        let mut seen = HashSet::new();
+        pairs
+            .iter()
+            .all(|(key, field)| key == field && seen.insert(key))
+    }
+}
+
+struct FixData {
+    each_span: Span,
+    replacement: String,
+}
+
+/// Field name of a `$param.field` access without further members
+fn param_field(expr: &Expression, param: VarId) -> Option<String> {
+    let Expr::FullCellPath(cell_path) = &expr.expr else {
+        return None;
+    };
+    if !matches!(cell_path.head.expr, Expr::Var(var_id) if var_id == param) {
+        return None;
+    }
+    match cell_path.tail.as_slice() {
+        [
+            PathMember::String {
+                val,
+                optional: false,
+                ..
+            },
+        ] => Some(val.clone()),
+        _ => None,
+    }
+}
+
+fn closure_projection(call: &Call, context: &LintContext) -> Option<Projection> {
+    if !call.is_call_to_command("each", context) || call.arguments.len() != 1 {
+        return None;
+    }
+    let Expr::Closure(block_id) = call.get_first_positional_arg()?.expr else {
+        return None;
+    };
+    let block = context.working_set.get_block(block_id);
+    let [param] = block.signature.required_positional.as_slice() else {
+        return None;
+    };
+    let param = param.var_id?;
+    let [pipeline] = block.pipelines.as_slice() else {
+        return None;
+    };
+    let [element] = pipeline.elements.as_slice() else {
+        return None;
+    };
+
+    let projection = match &element.expr.expr {
+        Expr::Record(items) => Projection::Record(
+            items
+                .iter()
+                .map(|item| match item {
+                    RecordItem::Pair(key, value) => Some((
+                        StringFormat::from_expression(key, context)?
+                            .content()
+                            .to_string(),
+                        param_field(value, param)?,
+                    )),
+                    RecordItem::Spread(..) => None,
+                })
+                .collect::<Option<_>>()?,
+        ),
+        Expr::List(items) => Projection::List(
+            items
+                .iter()
+                .map(|item| match item {
+                    ListItem::Item(value) => param_field(value, param),
+                    ListItem::Spread(..) => None,
+                })
+                .collect::<Option<_>>()?,
+        ),
+        _ => return None,
+    };
+    (!projection.fields().is_empty()).then_some(projection)
+}
+
+fn select_command(fields: &[&str]) -> String {
+    let columns: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            if cell_path_member_needs_quotes(field) {
+                format!("'{field}'")
+            } else {
+                (*field).to_string()
+            }
+        })
+        .collect();
+    format!("select {}", columns.join(" "))
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, Option<FixData>)> {
+    pipeline
+        .elements
+        .iter()
+        .skip(1)
+        .filter_map(|element| {
+            let Expr::Call(call) = &element.expr.expr else {
+                return None;
+            };
+            let projection = closure_projection(call, context)?;
+            let select = select_command(&projection.fields());
+
+            let (message, fix_data) = if projection.keeps_names() {
+                (
+                    format!("`each` only copies fields into a new record; use `{select}`"),
+                    Some(FixData {
+                        each_span: element.expr.span,
+                        replacement: select.clone(),
+                    }),
+                )
+            } else if matches!(projection, Projection::Record(_)) {
+                (
+                    format!(
+                        "`each` copies and renames fields; use `{select}` followed by `rename`"
+                    ),
+                    None,
+                )
+            } else {
+                (
+                    format!(
+                        "`each` collects fields of every row into a list; `{select}` keeps those \
+                         columns without a closure"
+                    ),
+                    None,
+                )
+            };
+
+            Some((
+                Detection::from_global_span(message, element.expr.span)
+                    .with_primary_label("field-by-field projection"),
+                fix_data,
+            ))
+        })
+        .collect()
+}
+
+struct EachRecordProjection;
+
+impl DetectFix for EachRecordProjection {
+    type FixInput<'a> = Option<FixData>;
+
+    fn id(&self) -> &'static str {
+        "each_record_projection"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`each` rebuilding rows from a few fields instead of `select`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "An `each` closure that only reads `$row.field` members and assembles them into a new \
+             record keeps a subset of the columns, which is what `select` does. When the record \
+             keeps the original field names the closure is replaced by `select`. Closures that \
+             rename fields or build lists are reported without a fix, since they also need \
+             `rename` or a different output shape.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/select.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.ast.detect_in_pipelines(context, check_pipeline)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let fix_data = fix_data.as_ref()?;
+        Some(Fix {
+            explanation: format!("Replace `each` with `{}`", fix_data.replacement).into(),
+            replacements: vec![Replacement::new(
+                fix_data.each_span,
+                fix_data.replacement.clone(),
+            )],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &EachRecordProjection;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
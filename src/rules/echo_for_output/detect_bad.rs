@@ -0,0 +1,28 @@
+use super::RULE;
+
+#[test]
+fn detects_echo_before_other_statements() {
+    let bad_code = r"
+def main [] {
+    echo 'starting'
+    ls | length
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_top_level_script_statement() {
+    RULE.assert_detects("echo 'building'\nlet x = 1\n$x");
+}
+
+#[test]
+fn detects_in_loop_body() {
+    let bad_code = r"
+for file in (ls | get name) {
+    echo $file
+    rm $file
+}
+";
+    RULE.assert_detects(bad_code);
+}
@@ -0,0 +1,18 @@
+use super::RULE;
+
+#[test]
+fn fix_echo_to_print() {
+    let bad_code = r"
+def main [] {
+    echo 'starting'
+    ls | length
+}
+";
+    let expected = r"
+def main [] {
+    print 'starting'
+    ls | length
+}
+";
+    RULE.assert_fixed_is(bad_code, expected);
+}
@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn ignore_echo_piped_further() {
+    RULE.assert_ignores("echo $env.PATH | length\nprint done");
+}
+
+#[test]
+fn ignore_echo_as_result() {
+    RULE.assert_ignores(r"def greet [] { let name = 'x'; echo $'hello ($name)' }");
+}
+
+#[test]
+fn ignore_external_echo() {
+    RULE.assert_ignores("^echo 'hi'\nprint done");
+}
+
+#[test]
+fn ignore_print() {
+    RULE.assert_ignores("print 'hi'\nprint done");
+}
+
+#[test]
+fn ignore_echo_in_let() {
+    RULE.assert_ignores("let x = echo 1\n$x");
+}
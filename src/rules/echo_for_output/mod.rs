@@ -0,0 +1,114 @@
+use nu_protocol::{
+    Span,
+    ast::{Block, Expr, Expression},
+};
+
+use crate::{
+    LintLevel,
+    ast::call::CallExt,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    echo_head: Span,
+}
+
+/// Statements of the block whose value is thrown away: every pipeline but the
+/// last that consists of a single internal `echo` call
+fn check_block(block: &Block, context: &LintContext) -> Vec<(Detection, FixData)> {
+    let Some((_, statements)) = block.pipelines.split_last() else {
+        return vec![];
+    };
+    statements
+        .iter()
+        .filter_map(|pipeline| {
+            let [element] = pipeline.elements.as_slice() else {
+                return None;
+            };
+            let Expr::Call(call) = &element.expr.expr else {
+                return None;
+            };
+            if !call.is_call_to_command("echo", context) || call.arguments.is_empty() {
+                return None;
+            }
+            let detection = Detection::from_global_span(
+                "`echo` returns its arguments instead of printing them, and this value is \
+                 discarded; use `print`",
+                element.expr.span,
+            )
+            .with_primary_label("output is discarded")
+            .with_extra_label("returns a value", call.head);
+            Some((
+                detection,
+                FixData {
+                    echo_head: call.head,
+                },
+            ))
+        })
+        .collect()
+}
+
+fn check_expression(expr: &Expression, context: &LintContext) -> Vec<(Detection, FixData)> {
+    match &expr.expr {
+        Expr::Block(block_id) | Expr::Closure(block_id) | Expr::Subexpression(block_id) => {
+            check_block(context.working_set.get_block(*block_id), context)
+        }
+        _ => vec![],
+    }
+}
+
+struct EchoForOutput;
+
+impl DetectFix for EchoForOutput {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "echo_for_output"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`echo` statement whose value is discarded instead of `print`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Unlike in other shells, `echo` in Nushell does not write to the terminal: it returns \
+             its arguments as a value. Only the last statement of a block becomes its result, so \
+             an `echo` earlier in a command or script shows nothing. `print` writes the text \
+             immediately. Uses of `echo` whose value flows into a pipeline or becomes the result \
+             are reported by `redundant_echo` instead.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/print.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let mut detections = check_block(context.ast, context);
+        detections.extend(context.detect_with_fix_data(check_expression));
+        detections
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: "Replace `echo` with `print`".into(),
+            replacements: vec![Replacement::new(fix_data.echo_head, "print")],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &EchoForOutput;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
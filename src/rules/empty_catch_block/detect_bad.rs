@@ -0,0 +1,29 @@
+use super::RULE;
+
+#[test]
+fn detects_empty_catch() {
+    RULE.assert_detects(r"try { open config.toml } catch { }");
+}
+
+#[test]
+fn detects_empty_catch_closure_with_parameter() {
+    RULE.assert_detects(r"try { open config.toml } catch {|err| }");
+}
+
+#[test]
+fn detects_catch_returning_null() {
+    RULE.assert_detects(r"try { rm tmp.txt } catch { null }");
+}
+
+#[test]
+fn detects_nested_empty_catch() {
+    RULE.assert_count(
+        r"
+def cleanup [] {
+    try { rm a.txt } catch { }
+    try { rm b.txt } catch { print --stderr 'b.txt not removed' }
+}
+",
+        1,
+    );
+}
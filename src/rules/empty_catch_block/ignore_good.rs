@@ -0,0 +1,16 @@
+use super::RULE;
+
+#[test]
+fn ignores_catch_with_handler() {
+    RULE.assert_ignores(r"try { open config.toml } catch {|err| print --stderr $err.msg }");
+}
+
+#[test]
+fn ignores_catch_with_fallback_value() {
+    RULE.assert_ignores(r"let config = try { open config.toml } catch { {} }");
+}
+
+#[test]
+fn ignores_try_without_catch() {
+    RULE.assert_ignores(r"try { rm tmp.txt }");
+}
@@ -0,0 +1,92 @@
+use nu_protocol::ast::{Block, Expr, Expression};
+
+use crate::{
+    LintLevel,
+    ast::{call::CallExt, expression::ExpressionExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Whether the handler does nothing: no pipelines or only a bare `null`
+fn is_empty_handler(block: &Block) -> bool {
+    let elements: Vec<_> = block
+        .pipelines
+        .iter()
+        .flat_map(|pipeline| &pipeline.elements)
+        .collect();
+    match elements.as_slice() {
+        [] => true,
+        [element] => matches!(element.expr.expr, Expr::Nothing),
+        _ => false,
+    }
+}
+
+fn check_try(expr: &Expression, context: &LintContext) -> Option<Detection> {
+    let Expr::Call(call) = &expr.expr else {
+        return None;
+    };
+    if !call.is_call_to_command("try", context) {
+        return None;
+    }
+    let catch_arg = call.get_positional_arg(1)?;
+    let handler = match &catch_arg.expr {
+        Expr::Keyword(keyword) => &keyword.expr,
+        _ => catch_arg,
+    };
+    let block = context.working_set.get_block(handler.extract_block_id()?);
+    if !is_empty_handler(block) {
+        return None;
+    }
+
+    Some(
+        Detection::from_global_span(
+            "Empty `catch` discards the error; log it or re-raise it with `error make`",
+            catch_arg.span,
+        )
+        .with_primary_label("error is swallowed")
+        .with_extra_label("failure is hidden here", call.head),
+    )
+}
+
+struct EmptyCatchBlock;
+
+impl DetectFix for EmptyCatchBlock {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "empty_catch_block"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`try` with an empty `catch` block"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A `catch {}` (or one that only returns `null`) turns every error in the `try` block \
+             into nothing, so failures go unnoticed. Handle the error in the closure, for example \
+             `catch {|err| print --stderr $err.msg }`. A `try` without any `catch` is not \
+             reported, since leaving it out is an explicit choice.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/try.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.detect_single(check_try))
+    }
+}
+
+pub static RULE: &dyn Rule = &EmptyCatchBlock;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
@@ -51,3 +51,15 @@ def helper2 [] {
         2,
     );
 }
+
+#[test]
+fn labels_enclosing_command() {
+    RULE.assert_labels_contain(
+        "
+def cleanup [] {
+  exit 1
+}
+",
+        "inside 'cleanup'",
+    );
+}
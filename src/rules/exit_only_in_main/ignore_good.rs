@@ -68,3 +68,14 @@ def main [] {
 ",
     );
 }
+
+#[test]
+fn exit_in_main_subcommand_is_allowed() {
+    RULE.assert_ignores(
+        "
+def 'main build' [] {
+  exit 2
+}
+",
+    );
+}
@@ -13,6 +13,15 @@ fn is_exit_call(call: &Call, ctx: &LintContext) -> bool {
     call.get_call_name(ctx) == "exit"
 }
 
+/// Suggested replacement: leaving successfully is a `return`, anything else
+/// reports a failure to the caller
+fn replacement_for(call: &Call) -> &'static str {
+    match call.get_first_positional_arg().map(|arg| &arg.expr) {
+        None | Some(Expr::Int(0)) => "return",
+        Some(_) => "error make",
+    }
+}
+
 struct ExitOnlyInMain;
 
 impl DetectFix for ExitOnlyInMain {
@@ -26,6 +35,15 @@ impl DetectFix for ExitOnlyInMain {
         "Avoid using 'exit' in functions other than 'main'"
     }
 
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`exit` ends the whole script or interactive shell, not just the command it is called \
+             in. A reusable command should hand control back to its caller: `return` to stop \
+             early, or `error make` to signal a failure the caller can `try`/`catch`. Only `main` \
+             and its subcommands decide the exit code of a script.",
+        )
+    }
+
     fn source_link(&self) -> Option<&'static str> {
         Some("https://www.nushell.sh/commands/docs/exit.html")
     }
@@ -56,13 +74,18 @@ impl DetectFix for ExitOnlyInMain {
                 return Some(
                     Detection::from_global_span(
                         format!(
-                            "Function '{}' uses 'exit' which terminates the entire script",
-                            function_def.name
+                            "Function '{}' uses 'exit' which terminates the entire script; use \
+                             `{}` instead",
+                            function_def.name,
+                            replacement_for(call)
                         ),
                         call.head,
                     )
                     .with_primary_label("exit call")
-                    .with_extra_label(format!("inside '{}'", function_def.name), expr.span),
+                    .with_extra_label(
+                        format!("inside '{}'", function_def.name),
+                        function_def.name_span,
+                    ),
                 );
             }
             None
@@ -137,6 +137,29 @@ fn select_function() {
     }
 }
 
+#[test]
+fn select_comparison() {
+    let cases = [
+        r#"^jq 'select(.status == "done")' tasks.json"#,
+        "$data | to json | ^jq 'select(.age > 30)'",
+        "^jq 'select(.meta.score <= -1)' scores.json",
+    ];
+    for code in cases {
+        RULE.assert_detects(code);
+    }
+}
+
+#[test]
+fn multiline_select_comparison() {
+    RULE.assert_detects(
+        r#"$data | to json | ^jq '
+        .users[]
+        | select(.role == "admin")
+        | .email
+    '"#,
+    );
+}
+
 #[test]
 fn group_by_function() {
     let cases = [
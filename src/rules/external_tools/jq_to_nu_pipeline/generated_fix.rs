@@ -114,3 +114,41 @@ fn fix_mixed_field_and_bracket() {
         r#"get config."db.host""#,
     );
 }
+
+#[test]
+fn fix_entry_functions() {
+    RULE.assert_fixed_contains(
+        "$config | to json | ^jq 'to_entries'",
+        "transpose key value",
+    );
+    RULE.assert_fixed_contains("$pairs | to json | ^jq 'from_entries'", "transpose -r -d");
+    RULE.assert_fixed_contains("^jq 'keys_unsorted' config.json", "columns");
+}
+
+#[test]
+fn fix_has_key() {
+    RULE.assert_fixed_contains(
+        r#"^jq 'has("name")' user.json"#,
+        r#""name" in ($in | columns)"#,
+    );
+}
+
+#[test]
+fn fix_select_string_comparison() {
+    RULE.assert_fixed_contains(
+        r#"^jq 'select(.status == "done")' tasks.json"#,
+        r#"where status == "done""#,
+    );
+}
+
+#[test]
+fn fix_select_numeric_comparison() {
+    RULE.assert_fixed_contains(
+        "$data | to json | ^jq 'select(.age > 30)'",
+        "where age > 30",
+    );
+    RULE.assert_fixed_contains(
+        "^jq 'select(.meta.score <= -1)' scores.json",
+        "where meta.score <= -1",
+    );
+}
@@ -38,17 +38,6 @@ fn complex_jq_string_operations() {
     }
 }
 
-#[test]
-fn complex_jq_multiline_select() {
-    RULE.assert_ignores(
-        r#"$data | to json | ^jq '
-        .users[]
-        | select(.role == "admin")
-        | .email
-    '"#,
-    );
-}
-
 #[test]
 fn native_nu_simple_functions() {
     let cases = [
@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn detects_ends_with_operator() {
+    RULE.assert_detects(r"ls | where name ends-with '.nu'");
+}
+
+#[test]
+fn detects_str_ends_with_in_subexpression() {
+    RULE.assert_detects(r#"ls | where ($it.name | str ends-with ".csv")"#);
+}
+
+#[test]
+fn detects_closure() {
+    RULE.assert_detects(r"ls | where {|f| $f.name | str ends-with '.md' }");
+}
+
+#[test]
+fn detects_ls_with_directory() {
+    RULE.assert_detects(r"ls src | where name ends-with '.rs'");
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r"
+def scripts [] {
+    ls | where name ends-with '.nu' | get name
+}
+";
+    RULE.assert_detects(bad_code);
+}
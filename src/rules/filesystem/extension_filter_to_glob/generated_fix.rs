@@ -0,0 +1,30 @@
+use super::RULE;
+
+#[test]
+fn fix_ends_with_operator() {
+    RULE.assert_fixed_is(r"ls | where name ends-with '.nu'", r"ls *.nu");
+}
+
+#[test]
+fn fix_keeps_flags_and_later_stages() {
+    RULE.assert_fixed_is(
+        r#"ls -a | where ($it.name | str ends-with ".toml") | get name"#,
+        r"ls -a *.toml | get name",
+    );
+}
+
+#[test]
+fn fix_non_extension_suffix() {
+    RULE.assert_fixed_is(
+        r"ls | where {|f| $f.name | str ends-with '_test.nu' }",
+        r"ls *_test.nu",
+    );
+}
+
+#[test]
+fn directory_argument_has_no_fix() {
+    // `ls src` would need the pattern joined to the directory
+    let code = r"ls src | where name ends-with '.rs'";
+    RULE.assert_count(code, 1);
+    RULE.assert_no_fix(code);
+}
@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn ignore_glob_pattern() {
+    RULE.assert_ignores(r"ls *.nu");
+}
+
+#[test]
+fn ignore_type_filter() {
+    RULE.assert_ignores(r"ls | where type == dir");
+}
+
+#[test]
+fn ignore_other_column() {
+    RULE.assert_ignores(r"ls | where type ends-with 'link'");
+}
+
+#[test]
+fn ignore_dynamic_suffix() {
+    RULE.assert_ignores(r"let ext = '.nu'; ls | where name ends-with $ext");
+}
+
+#[test]
+fn ignore_case_insensitive_check() {
+    RULE.assert_ignores(r"ls | where {|f| $f.name | str ends-with --ignore-case '.NU' }");
+}
+
+#[test]
+fn ignore_where_on_other_source() {
+    RULE.assert_ignores(r"$files | where name ends-with '.nu'");
+}
@@ -0,0 +1,176 @@
+use nu_protocol::{
+    Span,
+    ast::{Call, Comparison, Expr, Expression, Operator, PathMember, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, expression::ExpressionExt, pipeline::PipelineExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    /// From `ls` to the end of the `where` stage
+    replace_span: Span,
+    replacement: String,
+}
+
+/// Whether the expression reads the `name` column of the row, like
+/// `$it.name` or `$file.name`
+fn is_name_access(expr: &Expression) -> bool {
+    let Expr::FullCellPath(cell_path) = &expr.expr else {
+        return false;
+    };
+    matches!(cell_path.head.expr, Expr::Var(_))
+        && matches!(
+            cell_path.tail.as_slice(),
+            [PathMember::String { val, .. }] if val == "name"
+        )
+}
+
+const fn string_literal(expr: &Expression) -> Option<&String> {
+    match &expr.expr {
+        Expr::String(s) | Expr::RawString(s) => Some(s),
+        _ => None,
+    }
+}
+
+/// Suffix tested by `name ends-with '.nu'` or `$it.name | str ends-with '.nu'`
+fn ends_with_suffix<'a>(condition: &'a Expression, context: &'a LintContext) -> Option<&'a str> {
+    let block = context.working_set.get_block(condition.extract_block_id()?);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return None;
+    };
+    match pipeline.elements.as_slice() {
+        [element] => {
+            if element.expr.extract_block_id().is_some() {
+                // Parenthesized: `where ($it.name | str ends-with '.nu')`
+                return ends_with_suffix(&element.expr, context);
+            }
+            let Expr::BinaryOp(lhs, op, rhs) = &element.expr.expr else {
+                return None;
+            };
+            (is_name_access(lhs)
+                && matches!(
+                    op.expr,
+                    Expr::Operator(Operator::Comparison(Comparison::EndsWith))
+                ))
+            .then(|| string_literal(rhs))
+            .flatten()
+            .map(String::as_str)
+        }
+        [subject, check] => {
+            let Expr::Call(call) = &check.expr.expr else {
+                return None;
+            };
+            (is_name_access(&subject.expr)
+                && call.is_call_to_command("str ends-with", context)
+                && call.arguments.len() == 1)
+                .then(|| call.get_first_positional_arg().and_then(string_literal))
+                .flatten()
+                .map(String::as_str)
+        }
+        _ => None,
+    }
+}
+
+fn is_ls(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("ls", context)
+}
+
+fn is_where(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("where", context)
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, Option<FixData>)> {
+    pipeline
+        .find_command_pairs(context, is_ls, is_where)
+        .into_iter()
+        .filter_map(|pair| {
+            let suffix = ends_with_suffix(pair.second.get_first_positional_arg()?, context)?;
+            let glob = format!("*{suffix}");
+
+            // A pattern is only equivalent when `ls` lists the current directory
+            // and the suffix contains no glob syntax of its own
+            let fixable = pair.first.get_first_positional_arg().is_none()
+                && !suffix.is_empty()
+                && !suffix.contains(['*', '?', '[', ']', '{', '}', ' ', '/', '\\']);
+            let fix_data = fixable.then(|| {
+                let ls_span = pipeline.elements[pair.first_index].expr.span;
+                FixData {
+                    replace_span: pair.span,
+                    replacement: format!("{} {glob}", context.span_text(ls_span)),
+                }
+            });
+
+            let detection = Detection::from_global_span(
+                format!("Filtering `ls` output by suffix '{suffix}'; use a glob like `ls {glob}`"),
+                pair.span,
+            )
+            .with_primary_label("extension filter")
+            .with_extra_label(
+                "could be a glob pattern",
+                pipeline.elements[pair.second_index].expr.span,
+            );
+            Some((detection, fix_data))
+        })
+        .collect()
+}
+
+struct ExtensionFilterToGlob;
+
+impl DetectFix for ExtensionFilterToGlob {
+    type FixInput<'a> = Option<FixData>;
+
+    fn id(&self) -> &'static str {
+        "extension_filter_to_glob"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`ls | where name ends-with` instead of a glob"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Listing every entry and then keeping those whose name ends with an extension can be \
+             done by `ls` itself: `ls *.nu` or `glob *.nu`. The pattern is matched while reading \
+             the directory, so the unwanted entries are never turned into rows. Note that `ls` \
+             reports an error when a pattern matches nothing. A fix is offered when `ls` lists \
+             the current directory and the suffix is a plain literal.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/ls.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.ast.detect_in_pipelines(context, check_pipeline)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let fix_data = fix_data.as_ref()?;
+        Some(Fix {
+            explanation: format!("Replace the filter with `{}`", fix_data.replacement).into(),
+            replacements: vec![Replacement::new(
+                fix_data.replace_span,
+                fix_data.replacement.clone(),
+            )],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &ExtensionFilterToGlob;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
@@ -59,3 +59,8 @@ fn detect_double_quoted_filename() {
 fn detect_single_quoted_filename() {
     RULE.assert_detects("open 'my file.json' | from json");
 }
+
+#[test]
+fn detect_filename_with_r_in_it() {
+    RULE.assert_detects("open 'report-r 2024.json' | from json");
+}
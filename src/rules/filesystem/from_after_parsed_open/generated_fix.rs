@@ -27,3 +27,9 @@ fn fix_preserves_single_quotes() {
     RULE.assert_count(source, 1);
     RULE.assert_fixed_is(source, "open 'my file.json'");
 }
+
+#[test]
+fn fix_keeps_following_stages() {
+    let source = "open data.json | from json | get field";
+    RULE.assert_fixed_is(source, "open data.json | get field");
+}
@@ -38,3 +38,8 @@ fn ignore_mismatched_format() {
 fn ignore_http_get_from() {
     RULE.assert_ignores("http get https://api.example.com/data | from json");
 }
+
+#[test]
+fn ignore_open_short_raw_flag_after_filename() {
+    RULE.assert_ignores("open data.json -r | from json");
+}
@@ -1,6 +1,6 @@
 use nu_protocol::{Span, ast::Pipeline};
 
-use super::{find_open_from_patterns, open_from_span};
+use super::find_open_from_patterns;
 use crate::{
     LintLevel,
     ast::block::BlockExt,
@@ -10,9 +10,9 @@ use crate::{
 };
 
 pub struct FixData {
-    full_span: Span,
+    /// From the end of `open` to the end of `from`
+    removal_span: Span,
     format: String,
-    filename: String,
 }
 
 fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, FixData)> {
@@ -20,7 +20,6 @@ fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection,
         .into_iter()
         .filter(|pattern| !pattern.has_raw_flag)
         .map(|pattern| {
-            let full_span = open_from_span(&pattern);
             let format = &pattern.format;
             let filename = &pattern.filename;
 
@@ -38,9 +37,8 @@ fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection,
             );
 
             let fix_data = FixData {
-                full_span,
+                removal_span: Span::new(pattern.open_expr.span.end, pattern.from_expr.span.end),
                 format: format.clone(),
-                filename: filename.clone(),
             };
 
             (detected, fix_data)
@@ -64,6 +62,15 @@ impl DetectFix for FromAfterParsedOpen {
         "`open` already parses known formats into structured data"
     }
 
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`open` picks a parser from the file extension, so `open data.json` already returns a \
+             record or table. Piping that into `from json` fails, because `from` expects text. \
+             Removing the `from` stage is enough. With `open --raw` the file is read as text and \
+             `from` is needed; that form is reported by `open_raw_from_to_open` instead.",
+        )
+    }
+
     fn source_link(&self) -> Option<&'static str> {
         Some("https://www.nushell.sh/commands/docs/open.html")
     }
@@ -83,10 +90,7 @@ impl DetectFix for FromAfterParsedOpen {
                 fix_data.format
             )
             .into(),
-            replacements: vec![Replacement::new(
-                fix_data.full_span,
-                format!("open {}", fix_data.filename),
-            )],
+            replacements: vec![Replacement::new(fix_data.removal_span, String::new())],
         })
     }
 }
@@ -1,6 +1,6 @@
 use nu_protocol::{
-    Span,
-    ast::{Expr, Expression, Pipeline},
+    ENV_VARIABLE_ID, Span,
+    ast::{Expr, Expression, PathMember, Pipeline},
 };
 
 use crate::{
@@ -8,9 +8,67 @@ use crate::{
     context::LintContext,
 };
 
+pub mod extension_filter_to_glob;
 pub mod from_after_parsed_open;
+pub mod open_raw_decode_to_open;
 pub mod open_raw_from_to_open;
+pub mod open_text_str_length;
+pub mod repeated_file_open;
+pub mod string_ops_on_path;
 pub mod string_param_as_path;
+pub mod unfiltered_recursive_glob;
+
+/// Extensions of plain text files that `open` returns as a string. Formats
+/// with a `from` command are excluded separately, because `open` parses them.
+const TEXT_EXTENSIONS: &[&str] = &[
+    "txt", "log", "md", "rst", "adoc", "nu", "sh", "bash", "zsh", "fish", "py", "rs", "c", "h",
+    "js", "ts", "css", "conf", "cfg", "env",
+];
+
+/// Whether `open` returns the file as plain text, judged by its extension
+pub fn is_text_file(filename: &str, context: &LintContext) -> bool {
+    let lower = filename.to_lowercase();
+    let Some((_, extension)) = lower.rsplit_once('.') else {
+        return false;
+    };
+    TEXT_EXTENSIONS.contains(&extension) && context.format_for_extension(&lower).is_none()
+}
+
+/// Content of a literal file name argument, quoted or bare
+pub fn literal_filename(expr: &Expression) -> Option<&str> {
+    match &expr.expr {
+        Expr::String(s) | Expr::RawString(s) | Expr::Filepath(s, _) | Expr::GlobPattern(s, _) => {
+            Some(s.as_str())
+        }
+        _ => None,
+    }
+}
+
+/// Environment variables that always hold a path
+const PATH_ENV_VARS: &[&str] = &["PWD", "HOME", "FILE_PWD", "CURRENT_FILE", "TMPDIR"];
+
+/// Commands whose output is a single path
+const PATH_COMMANDS: &[&str] = &["pwd", "path expand", "path join", "path dirname"];
+
+/// Whether the expression is known to produce a file system path: a path
+/// literal, a path-valued environment variable such as `$env.PWD`, or a call
+/// to a command like `path expand`
+pub fn is_path_source(expr: &Expression, context: &LintContext) -> bool {
+    match &expr.expr {
+        Expr::Filepath(..) | Expr::Directory(..) => true,
+        Expr::FullCellPath(cell_path) => {
+            matches!(&cell_path.head.expr, Expr::Var(var_id) if *var_id == ENV_VARIABLE_ID)
+                && matches!(
+                    cell_path.tail.as_slice(),
+                    [PathMember::String { val, .. }] if PATH_ENV_VARS.contains(&val.as_str())
+                )
+        }
+        Expr::Call(call) => PATH_COMMANDS
+            .iter()
+            .any(|name| call.is_call_to_command(name, context)),
+        _ => false,
+    }
+}
 
 /// Data extracted from an `open FILE | from FORMAT` pipeline pattern
 pub struct OpenFromPattern<'a> {
@@ -46,10 +104,7 @@ pub fn find_open_from_patterns<'a>(
             let filename_arg = pair.first.get_first_positional_arg()?;
             let filename = context.expr_text(filename_arg);
 
-            let filename_content = match &filename_arg.expr {
-                Expr::String(s) | Expr::RawString(s) | Expr::GlobPattern(s, _) => s.as_str(),
-                _ => filename,
-            };
+            let filename_content = literal_filename(filename_arg).unwrap_or(filename);
 
             let file_format = context.format_for_extension(filename_content)?;
 
@@ -57,8 +112,7 @@ pub fn find_open_from_patterns<'a>(
                 return None;
             }
 
-            let open_text = context.span_text(pair.span);
-            let has_raw_flag = open_text.contains("--raw") || open_text.contains("-r ");
+            let has_raw_flag = pair.first.has_named_flag("raw") || pair.first.has_named_flag("r");
 
             Some(OpenFromPattern {
                 open_expr: &pipeline.elements[pair.first_index].expr,
@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn detects_open_raw_decode_lines() {
+    RULE.assert_detects("open --raw notes.txt | decode utf-8 | lines");
+}
+
+#[test]
+fn detects_short_raw_flag() {
+    RULE.assert_detects("open -r notes.txt | decode utf-8");
+}
+
+#[test]
+fn detects_decode_without_encoding() {
+    RULE.assert_detects("open --raw server.log | decode | lines");
+}
+
+#[test]
+fn detects_quoted_filename() {
+    RULE.assert_detects(r#"open --raw "README.md" | decode utf8 | lines"#);
+}
+
+#[test]
+fn detects_inside_function() {
+    let bad_code = r#"
+def count-lines [] {
+    open --raw script.nu | decode utf-8 | lines | length
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
@@ -0,0 +1,17 @@
+use super::RULE;
+
+#[test]
+fn removes_raw_and_decode() {
+    RULE.assert_fixed_is(
+        "open --raw notes.txt | decode utf-8 | lines",
+        "open notes.txt | lines",
+    );
+}
+
+#[test]
+fn keeps_quoted_filename() {
+    RULE.assert_fixed_is(
+        r#"open --raw "my notes.txt" | decode utf-8 | lines | length"#,
+        r#"open "my notes.txt" | lines | length"#,
+    );
+}
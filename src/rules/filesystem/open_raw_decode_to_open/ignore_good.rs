@@ -0,0 +1,37 @@
+use super::RULE;
+
+#[test]
+fn ignores_plain_open() {
+    RULE.assert_ignores("open notes.txt | lines");
+}
+
+#[test]
+fn ignores_binary_file() {
+    RULE.assert_ignores("open --raw image.png | decode utf-8");
+}
+
+#[test]
+fn ignores_unknown_extension() {
+    RULE.assert_ignores("open --raw data.bin | decode utf-8 | lines");
+}
+
+#[test]
+fn ignores_structured_format() {
+    // `open data.csv` would parse the file into a table
+    RULE.assert_ignores("open --raw data.csv | decode utf-8 | lines");
+}
+
+#[test]
+fn ignores_other_encoding() {
+    RULE.assert_ignores("open --raw legacy.txt | decode shift-jis | lines");
+}
+
+#[test]
+fn ignores_variable_filename() {
+    let good_code = r#"
+def read-file [path: string] {
+    open --raw $path | decode utf-8 | lines
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
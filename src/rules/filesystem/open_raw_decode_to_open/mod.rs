@@ -0,0 +1,123 @@
+use nu_protocol::{
+    Span,
+    ast::{Call, Pipeline},
+};
+
+use super::{is_text_file, literal_filename};
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, pipeline::PipelineExt, string::StringFormat},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    /// Span from `open` up to and including `decode`
+    span: Span,
+    filename: String,
+}
+
+fn is_open_raw(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("open", context)
+        && (call.has_named_flag("raw") || call.has_named_flag("r"))
+}
+
+fn is_decode_utf8(call: &Call, context: &LintContext) -> bool {
+    if !call.is_call_to_command("decode", context) {
+        return false;
+    }
+    let Some(encoding) = call.get_first_positional_arg() else {
+        // `decode` defaults to UTF-8
+        return true;
+    };
+    StringFormat::from_expression(encoding, context).is_some_and(|encoding| {
+        matches!(encoding.content().to_lowercase().as_str(), "utf-8" | "utf8")
+    })
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, FixData)> {
+    pipeline
+        .find_command_pairs(context, is_open_raw, is_decode_utf8)
+        .into_iter()
+        .filter_map(|pair| {
+            let filename_arg = pair.first.get_first_positional_arg()?;
+            if !is_text_file(literal_filename(filename_arg)?, context) {
+                return None;
+            }
+            let filename = context.expr_text(filename_arg).to_string();
+
+            let detection = Detection::from_global_span(
+                format!("Redundant `open --raw | decode utf-8`, use `open {filename}` instead"),
+                pair.span,
+            )
+            .with_primary_label("text file decoded by hand")
+            .with_extra_label(
+                "`open` already returns text files as a string",
+                pipeline.elements[pair.second_index].expr.span,
+            );
+
+            Some((
+                detection,
+                FixData {
+                    span: pair.span,
+                    filename,
+                },
+            ))
+        })
+        .collect()
+}
+
+struct OpenRawDecodeToOpen;
+
+impl DetectFix for OpenRawDecodeToOpen {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "open_raw_decode_to_open"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Simplify `open --raw | decode utf-8` to `open` for text files"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "For plain text files `open` already returns the content as a string, so `open --raw \
+             notes.txt | decode utf-8 | lines` can be written as `open notes.txt | lines`. Binary \
+             files and files with an unknown extension still need `--raw`, so only well-known \
+             text extensions are flagged.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/open.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.ast.detect_in_pipelines(context, check_pipeline)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: format!("Simplify to `open {}`", fix_data.filename).into(),
+            replacements: vec![Replacement::new(
+                fix_data.span,
+                format!("open {}", fix_data.filename),
+            )],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &OpenRawDecodeToOpen;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn detects_str_length_of_log_file() {
+    RULE.assert_detects(r"let count = open server.log | str length");
+}
+
+#[test]
+fn detects_quoted_text_file() {
+    RULE.assert_detects(r#"let count = open "notes.txt" | str length"#);
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r"
+def count-lines [] {
+    open README.md | str length
+}
+";
+    RULE.assert_labels_contain(bad_code, "counts characters, not lines");
+}
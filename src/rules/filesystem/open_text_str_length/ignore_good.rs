@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn ignores_line_count() {
+    RULE.assert_ignores(r"let count = open server.log | lines | length");
+}
+
+#[test]
+fn ignores_structured_file() {
+    RULE.assert_ignores(r"let count = open data.json | get name | str length");
+}
+
+#[test]
+fn ignores_str_length_of_variable() {
+    RULE.assert_ignores(r#"let count = "hello" | str length"#);
+}
+
+#[test]
+fn ignores_dynamic_filename() {
+    let good_code = r"
+def size [file: path] {
+    open $file | str length
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_grapheme_count() {
+    RULE.assert_ignores(r"let count = open notes.txt | str length --grapheme-clusters");
+}
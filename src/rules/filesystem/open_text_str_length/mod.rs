@@ -0,0 +1,89 @@
+use nu_protocol::ast::{Call, Pipeline};
+
+use super::{is_text_file, literal_filename};
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, pipeline::PipelineExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+fn is_open_text(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("open", context)
+        && !call.has_named_flag("raw")
+        && !call.has_named_flag("r")
+        && call
+            .get_first_positional_arg()
+            .and_then(literal_filename)
+            .is_some_and(|filename| is_text_file(filename, context))
+}
+
+fn is_str_length(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("str length", context) && call.arguments.is_empty()
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    pipeline
+        .find_command_pairs(context, is_open_text, is_str_length)
+        .into_iter()
+        .filter_map(|pair| {
+            let filename = context.expr_text(pair.first.get_first_positional_arg()?);
+            Some(
+                Detection::from_global_span(
+                    format!(
+                        "`str length` counts the characters of `{filename}`; use `lines | length` \
+                         to count its lines"
+                    ),
+                    pair.span,
+                )
+                .with_primary_label("character count of a text file")
+                .with_extra_label(
+                    "counts characters, not lines",
+                    pipeline.elements[pair.second_index].expr.span,
+                ),
+            )
+        })
+        .collect()
+}
+
+struct OpenTextStrLength;
+
+impl DetectFix for OpenTextStrLength {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "open_text_str_length"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`open file | str length` counts characters, not lines"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`open` returns a plain text file as one string, so `str length` yields the number of \
+             characters in the whole file. Counting lines, the way `wc -l` does, needs `open file \
+             | lines | length`. Keep `str length` when the character count is really intended.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/lines.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.ast.detect_in_pipelines(context, check_pipeline))
+    }
+}
+
+pub static RULE: &dyn Rule = &OpenTextStrLength;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
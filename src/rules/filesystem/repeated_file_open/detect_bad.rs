@@ -0,0 +1,49 @@
+use super::RULE;
+
+#[test]
+fn detects_two_opens_in_command() {
+    let bad_code = r"
+def summary [] {
+    let name = (open package.json | get name)
+    let version = (open package.json | get version)
+    $'($name)@($version)'
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_quoted_and_bare_path() {
+    RULE.assert_detects(r#"let a = open config.toml; let b = open "config.toml""#);
+}
+
+#[test]
+fn detects_inside_one_branch() {
+    let bad_code = r"
+if true {
+    print (open data.json | get a)
+    print (open data.json | get b)
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn reports_each_file_once() {
+    let bad_code = r"
+let a = open a.json | get x
+let b = open a.json | get y
+let c = open a.json | get z
+let d = open b.json
+let e = open b.json
+";
+    RULE.assert_count(bad_code, 2);
+}
+
+#[test]
+fn labels_every_repeated_open() {
+    RULE.assert_labels_contain(
+        r"let a = open a.json; let b = open a.json",
+        "opened again here",
+    );
+}
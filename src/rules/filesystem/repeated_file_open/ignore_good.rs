@@ -0,0 +1,35 @@
+use super::RULE;
+
+#[test]
+fn ignore_single_open() {
+    RULE.assert_ignores(r"let data = open data.json; $data.a + $data.b");
+}
+
+#[test]
+fn ignore_different_files() {
+    RULE.assert_ignores(r"let a = open a.json; let b = open b.json");
+}
+
+#[test]
+fn ignore_opens_in_different_branches() {
+    let good_code = r"
+def load [verbose: bool] {
+    if $verbose {
+        open data.json | table --expand
+    } else {
+        open data.json | get summary
+    }
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignore_raw_and_parsed_open() {
+    RULE.assert_ignores(r"let text = open --raw data.json; let data = open data.json");
+}
+
+#[test]
+fn ignore_dynamic_path() {
+    RULE.assert_ignores(r"def f [file: path] { let a = open $file; let b = open $file }");
+}
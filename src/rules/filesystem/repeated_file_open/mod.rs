@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+
+use nu_protocol::{
+    Span,
+    ast::{Expr, Expression, Traverse},
+};
+
+use super::literal_filename;
+use crate::{
+    LintLevel,
+    ast::call::CallExt,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+struct OpenSite {
+    span: Span,
+    path: String,
+    raw: bool,
+}
+
+fn open_site(expr: &Expression, context: &LintContext) -> Option<OpenSite> {
+    let Expr::Call(call) = &expr.expr else {
+        return None;
+    };
+    if !call.is_call_to_command("open", context) {
+        return None;
+    }
+    let path = literal_filename(call.get_first_positional_arg()?)?;
+    Some(OpenSite {
+        span: expr.span,
+        path: path.to_string(),
+        raw: call.has_named_flag("raw") || call.has_named_flag("r"),
+    })
+}
+
+/// Blocks and closures that may run on their own, like the branches of an
+/// `if`. Code in two different ones of these is not on the same path.
+fn branch_span(expr: &Expression) -> Option<Span> {
+    matches!(
+        expr.expr,
+        Expr::Block(_) | Expr::Closure(_) | Expr::RowCondition(_)
+    )
+    .then_some(expr.span)
+}
+
+/// Value blocks of `let x = ...`, which run exactly where they are written
+fn binding_value_span(expr: &Expression, context: &LintContext) -> Option<Span> {
+    let Expr::Call(call) = &expr.expr else {
+        return None;
+    };
+    call.extract_variable_declaration(context)?;
+    call.get_positional_arg(1).map(|value| value.span)
+}
+
+fn collect<T>(context: &LintContext, f: impl Fn(&Expression) -> Option<T>) -> Vec<T> {
+    let mut results = Vec::new();
+    context.ast.flat_map(
+        context.working_set,
+        &|expr| f(expr).into_iter().collect(),
+        &mut results,
+    );
+    results
+}
+
+/// Innermost branch containing the span, `None` at the top level of the file
+fn enclosing_branch(span: Span, branches: &[Span]) -> Option<(usize, usize)> {
+    branches
+        .iter()
+        .filter(|branch| branch.contains_span(span))
+        .min_by_key(|branch| branch.end - branch.start)
+        .map(|branch| (branch.start, branch.end))
+}
+
+struct RepeatedFileOpen;
+
+impl DetectFix for RepeatedFileOpen {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "repeated_file_open"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Same file opened more than once on one code path"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Every `open` reads the file from disk and parses it again. When the same file is \
+             opened several times in one block, open it once, bind the result with `let` and \
+             reuse the variable. Opens in different branches of an `if` or `match`, or in \
+             separate closures, are not reported because only one of them may run.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/open.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let bindings = collect(context, |expr| binding_value_span(expr, context));
+        let branches: Vec<Span> = collect(context, branch_span)
+            .into_iter()
+            .filter(|span| !bindings.contains(span))
+            .collect();
+
+        let mut groups: BTreeMap<_, Vec<OpenSite>> = BTreeMap::new();
+        for site in collect(context, |expr| open_site(expr, context)) {
+            let key = (
+                enclosing_branch(site.span, &branches),
+                site.path.clone(),
+                site.raw,
+            );
+            groups.entry(key).or_default().push(site);
+        }
+
+        let mut repeated: Vec<Vec<OpenSite>> = groups
+            .into_values()
+            .filter(|sites| sites.len() > 1)
+            .map(|mut sites| {
+                sites.sort_by_key(|site| site.span.start);
+                sites
+            })
+            .collect();
+        repeated.sort_by_key(|sites| sites[0].span.start);
+
+        let detections = repeated.into_iter().filter_map(|sites| {
+            let (first, rest) = sites.split_first()?;
+            let detection = Detection::from_global_span(
+                format!(
+                    "'{}' is opened {} times; open it once and bind the result with `let`",
+                    first.path,
+                    sites.len()
+                ),
+                first.span,
+            )
+            .with_primary_label("first open");
+            Some(rest.iter().fold(detection, |detection, site| {
+                detection.with_extra_label("opened again here", site.span)
+            }))
+        });
+        Self::no_fix(detections.collect())
+    }
+}
+
+pub static RULE: &dyn Rule = &RepeatedFileOpen;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
@@ -0,0 +1,39 @@
+use super::RULE;
+
+#[test]
+fn detects_split_row_on_pwd() {
+    RULE.assert_detects(r"let parts = $env.PWD | split row '/'");
+}
+
+#[test]
+fn detects_separator_replace_on_expanded_path() {
+    RULE.assert_detects(r"let win = '~/notes' | path expand | str replace --all '/' '\'");
+}
+
+#[test]
+fn detects_split_and_join_round_trip() {
+    RULE.assert_count(
+        r"let parent = $env.HOME | split row '/' | drop | str join '/'",
+        2,
+    );
+}
+
+#[test]
+fn detects_on_pwd_command() {
+    RULE.assert_detects(r"let parts = pwd | split row '/'");
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r"
+def project-name [] {
+    $env.PWD | split row '/' | last
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn labels_path_source() {
+    RULE.assert_labels_contain(r"$env.PWD | split row '/'", "path produced here");
+}
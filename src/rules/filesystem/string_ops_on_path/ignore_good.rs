@@ -0,0 +1,36 @@
+use super::RULE;
+
+#[test]
+fn ignore_path_split() {
+    RULE.assert_ignores(r"let parts = $env.PWD | path split");
+}
+
+#[test]
+fn ignore_path_join() {
+    RULE.assert_ignores(r"let parent = $env.HOME | path split | drop | path join");
+}
+
+#[test]
+fn ignore_plain_string_split() {
+    RULE.assert_ignores(r"let fields = 'a/b/c' | split row '/'");
+}
+
+#[test]
+fn ignore_non_separator_replace_on_path() {
+    RULE.assert_ignores(r"let name = $env.PWD | str replace 'old' 'new'");
+}
+
+#[test]
+fn ignore_regex_with_backslash_class() {
+    RULE.assert_ignores(r"let clean = $env.PWD | str replace -r '\d+' ''");
+}
+
+#[test]
+fn ignore_non_path_env_var() {
+    RULE.assert_ignores(r"let items = $env.MY_LIST | split row '/'");
+}
+
+#[test]
+fn ignore_string_join_of_words() {
+    RULE.assert_ignores(r"let line = [a b c] | str join ','");
+}
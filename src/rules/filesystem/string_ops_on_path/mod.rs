@@ -0,0 +1,115 @@
+use nu_protocol::ast::{Call, Expr, Expression, Pipeline};
+
+use super::is_path_source;
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Whether the literal consists only of path separators, like `'/'` or `'\'`
+fn is_separator_literal(expr: &Expression) -> bool {
+    let (Expr::String(s) | Expr::RawString(s)) = &expr.expr else {
+        return false;
+    };
+    !s.is_empty() && s.chars().all(|c| c == '/' || c == '\\')
+}
+
+/// The `path` subcommand replacing a separator-based string operation
+fn path_alternative(call: &Call, context: &LintContext) -> Option<&'static str> {
+    if call.is_call_to_command("split row", context) {
+        call.get_first_positional_arg()
+            .is_some_and(is_separator_literal)
+            .then_some("path split")
+    } else if call.is_call_to_command("str join", context) {
+        call.get_first_positional_arg()
+            .is_some_and(is_separator_literal)
+            .then_some("path join")
+    } else if call.is_call_to_command("str replace", context) {
+        [call.get_positional_arg(0), call.get_positional_arg(1)]
+            .into_iter()
+            .flatten()
+            .any(is_separator_literal)
+            .then_some("path split` and `path join")
+    } else {
+        None
+    }
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    let Some(source_index) = pipeline
+        .elements
+        .iter()
+        .position(|element| is_path_source(&element.expr, context))
+    else {
+        return vec![];
+    };
+    let source = &pipeline.elements[source_index].expr;
+
+    pipeline.elements[source_index + 1..]
+        .iter()
+        .filter_map(|element| {
+            let Expr::Call(call) = &element.expr.expr else {
+                return None;
+            };
+            let alternative = path_alternative(call, context)?;
+            let command = call.get_call_name(context);
+            Some(
+                Detection::from_global_span(
+                    format!(
+                        "`{command}` on a path depends on the platform's separator; use \
+                         `{alternative}` instead"
+                    ),
+                    element.expr.span,
+                )
+                .with_primary_label("string operation on a path")
+                .with_extra_label("path produced here", source.span),
+            )
+        })
+        .collect()
+}
+
+struct StringOpsOnPath;
+
+impl DetectFix for StringOpsOnPath {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "string_ops_on_path"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Path separators handled with string commands instead of `path` commands"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Splitting, joining or rewriting a path on a hard-coded `/` or `\\` breaks on \
+             platforms that use the other separator. Values known to be paths, such as \
+             `$env.PWD`, the output of `path expand` or path literals, can be taken apart with \
+             `path split` and `path parse` and put back together with `path join`. String \
+             operations that do not involve separators are not reported.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/categories/path.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.ast.detect_in_pipelines(context, check_pipeline))
+    }
+}
+
+pub static RULE: &dyn Rule = &StringOpsOnPath;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn detects_ls_recursive_star() {
+    RULE.assert_detects(r"ls **/* | get name");
+}
+
+#[test]
+fn detects_glob_recursive_star() {
+    RULE.assert_detects(r"glob **/* | length");
+}
+
+#[test]
+fn detects_quoted_glob_pattern() {
+    RULE.assert_detects(r#"let files = glob "src/**/*""#);
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r"
+def all-files [] {
+    ls **/*
+}
+";
+    RULE.assert_count(bad_code, 1);
+}
@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn ignores_narrowed_extension() {
+    RULE.assert_ignores(r"ls **/*.nu | get name");
+}
+
+#[test]
+fn ignores_narrowed_glob() {
+    RULE.assert_ignores(r#"glob "src/**/*.rs""#);
+}
+
+#[test]
+fn ignores_where_filter() {
+    RULE.assert_ignores(r"ls **/* | where type == file");
+}
+
+#[test]
+fn ignores_glob_with_exclude() {
+    RULE.assert_ignores(r"glob **/* --exclude [**/target/**]");
+}
+
+#[test]
+fn ignores_non_recursive_listing() {
+    RULE.assert_ignores(r"ls *");
+}
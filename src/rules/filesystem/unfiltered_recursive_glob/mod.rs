@@ -0,0 +1,107 @@
+use nu_protocol::ast::{Call, Expr, Pipeline};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Commands that narrow down a file listing right after it is produced
+const FILTERS: &[&str] = &["where", "filter", "find"];
+
+/// A pattern whose last component matches every entry below a `**`, such as
+/// `**/*` or `src/**/*`
+fn is_unrestricted_pattern(pattern: &str) -> bool {
+    pattern == "**" || pattern.ends_with("**/*") || pattern.ends_with("**/**")
+}
+
+fn broad_pattern<'a>(call: &'a Call, context: &LintContext) -> Option<&'a str> {
+    if !call.is_call_to_command("ls", context) && !call.is_call_to_command("glob", context) {
+        return None;
+    }
+    if call.has_named_flag("exclude") || call.has_named_flag("e") {
+        return None;
+    }
+    let pattern = match &call.get_first_positional_arg()?.expr {
+        Expr::GlobPattern(s, _) | Expr::String(s) | Expr::RawString(s) => s.as_str(),
+        _ => return None,
+    };
+    is_unrestricted_pattern(pattern).then_some(pattern)
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    pipeline
+        .elements
+        .iter()
+        .enumerate()
+        .filter_map(|(index, element)| {
+            let Expr::Call(call) = &element.expr.expr else {
+                return None;
+            };
+            let pattern = broad_pattern(call, context)?;
+            let filtered = pipeline.elements.get(index + 1).is_some_and(|next| {
+                matches!(
+                    &next.expr.expr,
+                    Expr::Call(next_call)
+                        if FILTERS.contains(&next_call.get_call_name(context).as_str())
+                )
+            });
+            (!filtered).then(|| {
+                Detection::from_global_span(
+                    format!(
+                        "`{}` walks the whole tree below `{pattern}`; narrow the pattern, for \
+                         example to `**/*.nu`",
+                        call.get_call_name(context)
+                    ),
+                    element.expr.span,
+                )
+                .with_primary_label("unfiltered recursive pattern")
+            })
+        })
+        .collect()
+}
+
+struct UnfilteredRecursiveGlob;
+
+impl DetectFix for UnfilteredRecursiveGlob {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "unfiltered_recursive_glob"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`ls` or `glob` over `**/*` without any filter"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`ls **/*` and `glob **/*` list every file and directory below the starting point, \
+             which can take a long time in large trees such as `node_modules` or `target`. Put \
+             the extension or a directory into the pattern, exclude directories with `glob \
+             --exclude`, or filter directly with `where`. Opt-in, enable it as a hint in the \
+             configuration.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/glob.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Off
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.ast.detect_in_pipelines(context, check_pipeline))
+    }
+}
+
+pub static RULE: &dyn Rule = &UnfilteredRecursiveGlob;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
@@ -5,6 +5,7 @@ use crate::{ast::call::CallExt, context::LintContext};
 pub mod each_if_to_where;
 pub mod for_filter_to_where;
 pub mod omit_it_in_row_condition;
+pub mod prefer_empty_filter;
 pub mod slice_to_drop;
 pub mod slice_to_last;
 pub mod slice_to_skip;
@@ -0,0 +1,33 @@
+use super::RULE;
+
+#[test]
+fn detects_str_length_greater_than_zero() {
+    RULE.assert_detects(r"open notes.txt | lines | where ($it | str length) > 0");
+}
+
+#[test]
+fn detects_str_length_not_zero() {
+    RULE.assert_detects(r"open notes.txt | lines | where ($it | str length) != 0");
+}
+
+#[test]
+fn detects_negated_is_empty() {
+    RULE.assert_detects(r#""a,,b" | split row "," | where not ($it | is-empty)"#);
+}
+
+#[test]
+fn detects_inverted_checks() {
+    RULE.assert_detects(r"open notes.txt | lines | where ($it | str length) == 0");
+    RULE.assert_detects(r"open notes.txt | lines | where ($it | is-empty)");
+}
+
+#[test]
+fn detects_inside_function() {
+    RULE.assert_detects(
+        r"
+def non-empty-lines [file: path] {
+    open $file | lines | where ($it | str length) > 0
+}
+",
+    );
+}
@@ -0,0 +1,29 @@
+use super::RULE;
+
+#[test]
+fn fixes_str_length_check() {
+    RULE.assert_fixed_is(
+        r"open notes.txt | lines | where ($it | str length) > 0",
+        r#"open notes.txt | lines | where $it != """#,
+    );
+}
+
+#[test]
+fn fixes_negated_is_empty() {
+    RULE.assert_fixed_is(
+        r#""a,,b" | split row "," | where not ($it | is-empty)"#,
+        r#""a,,b" | split row "," | where $it != """#,
+    );
+}
+
+#[test]
+fn fixes_inverted_check_to_equality() {
+    RULE.assert_fixed_is(
+        r"open notes.txt | lines | where ($it | is-empty)",
+        r#"open notes.txt | lines | where $it == """#,
+    );
+    RULE.assert_fixed_is(
+        r"open notes.txt | lines | where ($it | str length) == 0",
+        r#"open notes.txt | lines | where $it == """#,
+    );
+}
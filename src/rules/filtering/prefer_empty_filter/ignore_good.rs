@@ -0,0 +1,23 @@
+use super::RULE;
+
+#[test]
+fn ignores_direct_comparison() {
+    RULE.assert_ignores(r#"open notes.txt | lines | where $it != """#);
+}
+
+#[test]
+fn ignores_other_length_threshold() {
+    RULE.assert_ignores(r"open notes.txt | lines | where ($it | str length) > 3");
+}
+
+#[test]
+fn ignores_unknown_input() {
+    RULE.assert_ignores(r"ls | get name | where ($it | str length) > 0");
+}
+
+#[test]
+fn ignores_grapheme_length() {
+    RULE.assert_ignores(
+        r"open notes.txt | lines | where ($it | str length --grapheme-clusters) > 0",
+    );
+}
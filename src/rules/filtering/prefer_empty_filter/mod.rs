@@ -0,0 +1,177 @@
+use nu_protocol::{
+    Span,
+    ast::{Comparison, Expr, Expression, Operator, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::{call::CallExt, expression::ExpressionExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+/// Commands producing a list of strings that commonly contains empty entries
+const STRING_LIST_SOURCES: &[&str] = &["lines", "split row", "split words", "split chars"];
+
+struct FixData {
+    predicate_span: Span,
+    keeps_empty: bool,
+}
+
+impl FixData {
+    const fn replacement(&self) -> &'static str {
+        if self.keeps_empty {
+            r#"$it == """#
+        } else {
+            r#"$it != """#
+        }
+    }
+}
+
+/// Whether the expression is `($it | COMMAND)` with a flagless `COMMAND`
+fn is_it_piped_into(expr: &Expression, command: &str, context: &LintContext) -> bool {
+    let Some(block_id) = expr.extract_block_id() else {
+        return false;
+    };
+    let block = context.working_set.get_block(block_id);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return false;
+    };
+    let [value, stage] = pipeline.elements.as_slice() else {
+        return false;
+    };
+    let Expr::Call(call) = &stage.expr.expr else {
+        return false;
+    };
+    context.span_text(value.expr.span) == "$it"
+        && call.is_call_to_command(command, context)
+        && call.arguments.is_empty()
+}
+
+/// Whether the predicate keeps empty strings (`true`) or drops them (`false`),
+/// or `None` when it is not an emptiness check on `$it`
+fn classify_predicate(predicate: &Expression, context: &LintContext) -> Option<bool> {
+    match &predicate.expr {
+        Expr::BinaryOp(lhs, op, rhs) => {
+            if !is_it_piped_into(lhs, "str length", context) || !matches!(rhs.expr, Expr::Int(0)) {
+                return None;
+            }
+            match op.expr {
+                Expr::Operator(Operator::Comparison(Comparison::Equal)) => Some(true),
+                Expr::Operator(Operator::Comparison(
+                    Comparison::NotEqual | Comparison::GreaterThan,
+                )) => Some(false),
+                _ => None,
+            }
+        }
+        Expr::UnaryNot(inner) => is_it_piped_into(inner, "is-empty", context).then_some(false),
+        _ => is_it_piped_into(predicate, "is-empty", context).then_some(true),
+    }
+}
+
+fn check_where(
+    source: &Expression,
+    filter: &Expression,
+    context: &LintContext,
+) -> Option<(Detection, FixData)> {
+    let (Expr::Call(source_call), Expr::Call(filter_call)) = (&source.expr, &filter.expr) else {
+        return None;
+    };
+    let source_name = source_call.get_call_name(context);
+    if !STRING_LIST_SOURCES.contains(&source_name.as_str())
+        || !filter_call.is_call_to_command("where", context)
+    {
+        return None;
+    }
+
+    let Expr::RowCondition(block_id) = &filter_call.get_first_positional_arg()?.expr else {
+        return None;
+    };
+    let block = context.working_set.get_block(*block_id);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return None;
+    };
+    let [element] = pipeline.elements.as_slice() else {
+        return None;
+    };
+    let predicate = &element.expr;
+    let keeps_empty = classify_predicate(predicate, context)?;
+
+    let fix_data = FixData {
+        predicate_span: predicate.span,
+        keeps_empty,
+    };
+    let detection = Detection::from_global_span(
+        format!(
+            "Emptiness check after `{source_name}` can be written as `where {}`",
+            fix_data.replacement()
+        ),
+        predicate.span,
+    )
+    .with_primary_label("verbose empty string check");
+
+    Some((detection, fix_data))
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, FixData)> {
+    pipeline
+        .elements
+        .windows(2)
+        .filter_map(|pair| check_where(&pair[0].expr, &pair[1].expr, context))
+        .collect()
+}
+
+struct PreferEmptyFilter;
+
+impl DetectFix for PreferEmptyFilter {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "prefer_empty_filter"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Compare with an empty string to filter out empty lines"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "After `lines` or `split row`, every row is a string, so checks like `($it | str \
+             length) > 0` or `not ($it | is-empty)` only test for the empty string. Comparing \
+             with `\"\"` directly says the same thing in fewer steps. The inverted checks (`== \
+             0`, `is-empty`) become `$it == \"\"`.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/where.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.ast.detect_in_pipelines(context, check_pipeline)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: format!("Replace with `{}`", fix_data.replacement()).into(),
+            replacements: vec![Replacement::new(
+                fix_data.predicate_span,
+                fix_data.replacement(),
+            )],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &PreferEmptyFilter;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
@@ -0,0 +1,44 @@
+use super::RULE;
+
+#[test]
+fn detects_get_index_on_pwd() {
+    let bad_code = "(pwd) | get 0";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_get_on_string_literal() {
+    let bad_code = r#""hello" | get name"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_get_on_int_literal() {
+    let bad_code = "42 | get value";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_get_on_typed_string_parameter() {
+    let bad_code = r#"
+def show [name: string] {
+    $name | get first
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_get_on_string_variable() {
+    let bad_code = r#"
+let version = "1.2.3"
+$version | get major
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_inside_closure() {
+    let bad_code = "[1 2 3] | each {|n| pwd | get 0 }";
+    RULE.assert_detects(bad_code);
+}
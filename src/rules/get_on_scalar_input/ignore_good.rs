@@ -0,0 +1,57 @@
+use super::RULE;
+
+#[test]
+fn ignores_get_on_date() {
+    let good_code = "(date now) | get year";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_get_on_record() {
+    let good_code = "{name: 'nu'} | get name";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_get_on_list() {
+    let good_code = "[1 2 3] | get 0";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_get_on_table_command() {
+    let good_code = "ls | get name";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_get_on_untyped_parameter() {
+    let good_code = r#"
+def show [data] {
+    $data | get name
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_get_after_polymorphic_command() {
+    let good_code = r#"
+def clean [items: list<string>] {
+    $items | str trim | get 0
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_optional_get() {
+    let good_code = r#""hello" | get --optional name"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_get_on_sys_host() {
+    let good_code = "sys host | get hostname";
+    RULE.assert_ignores(good_code);
+}
@@ -0,0 +1,94 @@
+use nu_protocol::{
+    Type,
+    ast::{Expr, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, expression::ExpressionExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+const fn is_scalar(ty: &Type) -> bool {
+    matches!(ty, Type::String | Type::Int | Type::Float | Type::Bool)
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    let [head, next, ..] = pipeline.elements.as_slice() else {
+        return vec![];
+    };
+
+    let Expr::Call(call) = &next.expr.expr else {
+        return vec![];
+    };
+
+    if !call.is_call_to_command("get", context) || call.is_get_optional(context) {
+        return vec![];
+    }
+
+    let Some(accessor) = call.get_first_positional_arg() else {
+        return vec![];
+    };
+
+    let Some(input_type) = head.expr.infer_output_type(context).filter(is_scalar) else {
+        return vec![];
+    };
+
+    vec![
+        Detection::from_global_span(
+            format!(
+                "`get {}` on a {input_type} value always fails: `get` only works on records, \
+                 lists and tables",
+                context.expr_text(accessor)
+            ),
+            call.head,
+        )
+        .with_primary_label(format!("input is {input_type}"))
+        .with_extra_label(format!("produces {input_type}"), head.expr.span),
+    ]
+}
+
+struct GetOnScalarInput;
+
+impl DetectFix for GetOnScalarInput {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "get_on_scalar_input"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`get` applied to a string, number or boolean value"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "The `get` command extracts cells from records, lists and tables. Piping a string, \
+             number or boolean into `get` is a runtime error. This rule only fires when the \
+             scalar type of the input is known from the same type inference other rules use \
+             (literals, typed variables and command signatures), so `any`-typed input is never \
+             flagged.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/get.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.ast.detect_in_pipelines(context, check_pipeline))
+    }
+}
+
+pub static RULE: &dyn Rule = &GetOnScalarInput;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
@@ -19,21 +19,33 @@ const ERROR_HANDLING: Group = Group {
         super::documentation::descriptive_error_messages::RULE,
         super::unescaped_interpolation::RULE,
         super::exit_only_in_main::RULE,
+        super::infinite_loop::RULE,
         super::check_typed_flag_before_use::RULE,
         super::non_final_failure_check::RULE,
         super::error_make::error_make_for_non_fatal::RULE,
         super::try_instead_of_do::RULE,
+        super::silent_ignore_errors::RULE,
         super::unsafe_dynamic_record_access::RULE,
         super::missing_stdin_in_shebang::RULE,
         super::dynamic_script_import::RULE,
         super::catch_builtin_error_try::RULE,
         super::unchecked_cell_path_index::RULE,
         super::unchecked_get_index::RULE,
+        super::unchecked_env_access::RULE,
+        super::get_on_scalar_input::RULE,
+        super::missing_literal_column::RULE,
         super::unhandled_external_error::RULE,
         super::source_to_use::RULE,
         super::spread_list_to_external::RULE,
         super::glob_may_drop_quotes::RULE,
         super::require_main_with_stdin::RULE,
+        super::trim_char_strips_repeats::RULE,
+        super::interpolated_regex_replacement::RULE,
+        super::unconditional_recursion::RULE,
+        super::path_append_duplicates::RULE,
+        super::shadowed_variable::RULE,
+        super::empty_catch_block::RULE,
+        super::transpose_round_trip::RULE,
     ],
 };
 
@@ -47,8 +59,13 @@ const TYPE_SAFETY: Group = Group {
         super::filesystem::string_param_as_path::RULE,
         super::typing::missing_output_type::RULE,
         super::typing::missing_in_type::RULE,
+        super::typing::scalar_in_type::RULE,
+        super::typing::let_type_mismatch::RULE,
+        super::typing::untyped_main_params::RULE,
         super::redundant_nu_subprocess::RULE,
         super::dynamic_script_import::RULE,
+        super::mismatched_branch_types::RULE,
+        super::describe_type_check::RULE,
     ],
 };
 
@@ -64,6 +81,9 @@ const IDIOMATIC: Group = Group {
         super::get_optional_to_not_has::RULE,
         super::hardcoded_math_constants::RULE,
         super::transpose_items::RULE,
+        super::manual_case_conversion::RULE,
+        super::manual_filesize_formatting::RULE,
+        super::manual_math_avg::RULE,
         super::merge_get_cell_path::RULE,
         super::merge_multiline_print::RULE,
         super::positional_to_pipeline::RULE,
@@ -78,6 +98,12 @@ const IDIOMATIC: Group = Group {
         super::record_assignments::USE_LOAD_ENV,
         super::remove_hat_not_builtin::RULE,
         super::division_to_format_duration::RULE,
+        super::bare_sys_command::RULE,
+        super::unnecessary_mut::RULE,
+        super::prefer_first_last_over_get_index::RULE,
+        super::echo_for_output::RULE,
+        super::prefer_is_empty_over_null_compare::RULE,
+        super::prefer_string_interpolation::RULE,
     ],
 };
 
@@ -93,6 +119,7 @@ const PARSING: Group = Group {
         super::parsing::split_first_to_parse::RULE,
         super::parsing::split_row_get_inline::RULE,
         super::parsing::split_row_space_to_split_words::RULE,
+        super::to_json_flag_conflict::RULE,
     ],
 };
 
@@ -100,9 +127,15 @@ const FILESYSTEM: Group = Group {
     name: "filesystem",
     description: "Simplify file and path operations.",
     rules: &[
+        super::filesystem::extension_filter_to_glob::RULE,
         super::filesystem::from_after_parsed_open::RULE,
+        super::filesystem::open_raw_decode_to_open::RULE,
         super::filesystem::open_raw_from_to_open::RULE,
+        super::filesystem::open_text_str_length::RULE,
+        super::filesystem::repeated_file_open::RULE,
+        super::filesystem::string_ops_on_path::RULE,
         super::filesystem::string_param_as_path::RULE,
+        super::filesystem::unfiltered_recursive_glob::RULE,
     ],
 };
 
@@ -113,6 +146,7 @@ const FILTERING: Group = Group {
         super::filtering::each_if_to_where::RULE,
         super::filtering::for_filter_to_where::RULE,
         super::filtering::omit_it_in_row_condition::RULE,
+        super::filtering::prefer_empty_filter::RULE,
         super::filtering::slice_to_drop::RULE,
         super::filtering::slice_to_last::RULE,
         super::filtering::slice_to_skip::RULE,
@@ -128,6 +162,9 @@ const ITERATION: Group = Group {
     rules: &[
         super::range_for_iteration::loop_counter::RULE,
         super::range_for_iteration::while_counter::RULE,
+        super::each_record_projection::RULE,
+        super::prefer_get_over_each_field::RULE,
+        super::prefer_each_over_for::RULE,
     ],
 };
 
@@ -140,15 +177,20 @@ const DEAD_CODE: Group = Group {
         super::assign_then_return::RULE,
         super::do_not_compare_booleans::RULE,
         super::if_null_to_default::RULE,
+        super::redundant_identity_each::RULE,
         super::redundant_ignore::RULE,
-        super::unnecessary_mut::RULE,
+        super::redundant_table_render::RULE,
+        super::redundant_into_string::RULE,
         super::unused_helper_functions::RULE,
         super::unused_parameter::RULE,
+        super::unused_closure_parameter::RULE,
         super::unused_variable::RULE,
         super::script_export_main::RULE,
         super::string_may_be_bare::RULE,
         super::single_call_command::RULE,
         super::append_to_concat_assign::RULE,
+        super::redundant_format_roundtrip::RULE,
+        super::unreachable_after_terminator::RULE,
     ],
 };
 
@@ -165,6 +207,12 @@ const PERFORMANCE: Group = Group {
         super::chained_str_transform::RULE,
         super::streaming_hidden_by_complete::RULE,
         super::chained_append::RULE,
+        super::chained_column_update::RULE,
+        super::concat_list_literals::RULE,
+        super::where_after_sort::RULE,
+        super::filesystem::repeated_file_open::RULE,
+        super::redundant_format_roundtrip::RULE,
+        super::consider_par_each::RULE,
     ],
 };
 
@@ -174,6 +222,7 @@ const POSIX_TOOLS: Group = Group {
     rules: &[
         super::ignore_over_dev_null::RULE,
         super::posix_tools::awk_to_pipeline::RULE,
+        super::posix_tools::basename_dirname_to_path::RULE,
         super::posix_tools::bat_to_open::RULE,
         super::posix_tools::cat_to_open::RULE,
         super::posix_tools::date_to_date_now::RULE,
@@ -182,6 +231,7 @@ const POSIX_TOOLS: Group = Group {
         super::posix_tools::find_to_glob::RULE,
         super::posix_tools::free_to_sys_mem::RULE,
         super::posix_tools::grep_to_find_or_where::RULE,
+        super::posix_tools::hashsum_to_hash::RULE,
         super::posix_tools::head_to_first::RULE,
         super::posix_tools::hostname_to_sys_host::RULE,
         super::posix_tools::external_cd_to_builtin::RULE,
@@ -189,6 +239,7 @@ const POSIX_TOOLS: Group = Group {
         super::posix_tools::pager_to_explore::RULE,
         super::posix_tools::read_to_input::RULE,
         super::posix_tools::sed_to_str_transform::RULE,
+        super::posix_tools::seq_to_range::RULE,
         super::posix_tools::external_sort_to_builtin::RULE,
         super::posix_tools::tac_to_reverse::RULE,
         super::posix_tools::tail_to_last::RULE,
@@ -199,6 +250,7 @@ const POSIX_TOOLS: Group = Group {
         super::posix_tools::w_to_sys_users::RULE,
         super::posix_tools::wc_to_length::RULE,
         super::posix_tools::who_to_sys_users::RULE,
+        super::posix_tools::xargs_to_each::RULE,
     ],
 };
 
@@ -217,6 +269,7 @@ const DOCUMENTATION: Group = Group {
         super::max_positional_params::RULE,
         super::explicit_long_flags::RULE,
         super::list_param_to_variadic::RULE,
+        super::magic_number::RULE,
     ],
 };
 
@@ -242,10 +295,12 @@ const FORMATTING: Group = Group {
         super::collapsible_if::RULE,
         super::forbid_excessive_nesting::RULE,
         super::max_function_body_length::RULE,
+        super::pipeline_too_long::RULE,
         super::if_else_chain_to_match::RULE,
         super::spacing::block_brace_spacing::RULE,
         super::spacing::closure_brace_pipe_spacing::RULE,
         super::spacing::closure_pipe_body_spacing::RULE,
+        super::spacing::multiline_trailing_comma::RULE,
         super::spacing::no_trailing_spaces::RULE,
         super::spacing::omit_list_commas::RULE,
         super::spacing::pipe_spacing::RULE,
@@ -276,6 +331,7 @@ const SIDE_EFFECTS: Group = Group {
         super::side_effects::dont_mix_different_effects::RULE,
         super::side_effects::print_and_return_data::RULE,
         super::side_effects::each_nothing_to_for_loop::RULE,
+        super::side_effects::echoed_command_output::RULE,
         super::side_effects::silence_stderr_data::RULE,
     ],
 };
@@ -285,6 +341,7 @@ const UPSTREAM: Group = Group {
     description: "Forward warnings and errors of the Nu parser.",
     rules: &[
         super::dynamic_script_import::RULE,
+        super::deprecated_builtin::RULE,
         super::upstream::nu_deprecated::RULE,
         super::upstream::nu_parse_error::RULE,
     ],
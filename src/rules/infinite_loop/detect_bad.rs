@@ -0,0 +1,41 @@
+use super::RULE;
+
+#[test]
+fn detects_loop_without_break() {
+    RULE.assert_detects(r"loop { print 'tick'; sleep 1sec }");
+}
+
+#[test]
+fn detects_while_true_without_break() {
+    let bad_code = r"
+mut n = 0
+while true {
+    $n += 1
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_break_only_in_inner_loop() {
+    let bad_code = r"
+loop {
+    for x in [1 2 3] {
+        if $x == 2 { break }
+    }
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r"
+def watch [] {
+    loop {
+        ls | length | print
+    }
+}
+";
+    RULE.assert_detects(bad_code);
+}
@@ -0,0 +1,40 @@
+use super::RULE;
+
+#[test]
+fn ignore_loop_with_break() {
+    let good_code = r"
+loop {
+    let line = input
+    if $line == 'quit' { break }
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignore_while_true_with_return() {
+    let good_code = r"
+def wait-ready [] {
+    while true {
+        if (ready) { return }
+        sleep 1sec
+    }
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignore_loop_with_exit() {
+    RULE.assert_ignores(r"loop { if (ls | is-empty) { exit 0 } }");
+}
+
+#[test]
+fn ignore_loop_with_error_make() {
+    RULE.assert_ignores(r"loop { error make {msg: 'stop'} }");
+}
+
+#[test]
+fn ignore_while_with_condition() {
+    RULE.assert_ignores(r"mut n = 0; while $n < 3 { $n += 1 }");
+}
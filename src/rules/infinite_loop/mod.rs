@@ -0,0 +1,110 @@
+use nu_protocol::ast::{Block, Call, Expr, Expression};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, expression::ExpressionExt, span::SpanExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Commands that leave the loop from any nesting depth
+const TERMINATORS: &[&str] = &["return", "exit", "error make"];
+
+const LOOPS: &[&str] = &["loop", "while", "for"];
+
+fn is_call_to_any(expr: &Expression, names: &[&str], context: &LintContext) -> bool {
+    matches!(&expr.expr, Expr::Call(call) if names.iter().any(|name| call.is_call_to_command(name, context)))
+}
+
+/// Body of a `loop { }` or a `while true { }`
+fn unbounded_loop_body<'a>(call: &'a Call, context: &LintContext) -> Option<&'a Expression> {
+    if call.is_call_to_command("loop", context) {
+        call.get_first_positional_arg()
+    } else if call.is_call_to_command("while", context)
+        && matches!(call.get_first_positional_arg()?.expr, Expr::Bool(true))
+    {
+        call.get_positional_arg(1)
+    } else {
+        None
+    }
+}
+
+/// Whether the loop body can end the loop. A `break` only counts when it is
+/// not nested in an inner loop, which it would leave instead.
+fn can_exit(body: &Block, context: &LintContext) -> bool {
+    if !body
+        .find_expr_spans(context, |expr, ctx| is_call_to_any(expr, TERMINATORS, ctx))
+        .is_empty()
+    {
+        return true;
+    }
+
+    let inner_loops = body.find_expr_spans(context, |expr, ctx| is_call_to_any(expr, LOOPS, ctx));
+    body.find_expr_spans(context, |expr, ctx| is_call_to_any(expr, &["break"], ctx))
+        .iter()
+        .any(|span| !span.is_inside_any(&inner_loops))
+}
+
+fn check_expression(expr: &Expression, context: &LintContext) -> Option<Detection> {
+    let Expr::Call(call) = &expr.expr else {
+        return None;
+    };
+    let body_expr = unbounded_loop_body(call, context)?;
+    let body = context.working_set.get_block(body_expr.extract_block_id()?);
+    if can_exit(body, context) {
+        return None;
+    }
+
+    let keyword = call.get_call_name(context);
+    Some(
+        Detection::from_global_span(
+            format!("`{keyword}` without `break`, `return` or `exit` never terminates"),
+            call.head,
+        )
+        .with_primary_label("infinite loop")
+        .with_extra_label("no exit from this body", body_expr.span),
+    )
+}
+
+struct InfiniteLoop;
+
+impl DetectFix for InfiniteLoop {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "infinite_loop"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`loop` or `while true` without any way out"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A `loop` or `while true` whose body contains no `break`, `return`, `exit` or `error \
+             make` can only be stopped from outside the script. A `break` inside a nested loop \
+             does not count, because it only leaves the inner loop. Long-running daemon loops \
+             that are meant to run forever can be marked with `# nu-lint-ignore: infinite_loop`.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/loop.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.detect_single(check_expression))
+    }
+}
+
+pub static RULE: &dyn Rule = &InfiniteLoop;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
@@ -0,0 +1,33 @@
+use super::RULE;
+
+#[test]
+fn detects_dollar_in_in_replacement() {
+    RULE.assert_detects(r#"'hello world' | str replace --regex '(\w+)' $"<($in)>""#);
+}
+
+#[test]
+fn detects_short_regex_flag() {
+    RULE.assert_detects(r#"'a-b' | str replace -r '(\w)-(\w)' $"($in)!""#);
+}
+
+#[test]
+fn detects_piped_variable_in_replacement() {
+    let bad_code = r#"
+let name = 'foo_bar'
+$name | str replace --all --regex '_(\w)' $"-($name)"
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_inside_closure() {
+    RULE.assert_detects(r#"['a1' 'b2'] | each {|s| $s | str replace --regex '(\d)' $"[($s)]" }"#);
+}
+
+#[test]
+fn labels_input_reference() {
+    RULE.assert_labels_contain(
+        r#"'hello' | str replace --regex '(\w+)' $"<($in)>""#,
+        "entire input string",
+    );
+}
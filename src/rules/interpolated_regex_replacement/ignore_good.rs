@@ -0,0 +1,25 @@
+use super::RULE;
+
+#[test]
+fn ignore_capture_group_syntax() {
+    RULE.assert_ignores(r"'hello world' | str replace --regex '(\w+)' '<$1>'");
+}
+
+#[test]
+fn ignore_named_capture_group() {
+    RULE.assert_ignores(r"'2024-01' | str replace --regex '(?<y>\d+)-(?<m>\d+)' '${m}/${y}'");
+}
+
+#[test]
+fn ignore_plain_replace_with_interpolation() {
+    RULE.assert_ignores(r#"'hello' | str replace 'l' $"($in | str length)""#);
+}
+
+#[test]
+fn ignore_interpolation_of_other_variable() {
+    let good_code = r#"
+let sep = '-'
+'a_b' | str replace --all --regex '_' $"($sep)"
+"#;
+    RULE.assert_ignores(good_code);
+}
@@ -0,0 +1,124 @@
+use nu_protocol::{
+    Span, VarId,
+    ast::{Call, Expr, Expression, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, expression::ExpressionExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+fn regex_replace_call<'a>(expr: &'a Expression, context: &LintContext) -> Option<&'a Call> {
+    match &expr.expr {
+        Expr::Call(call)
+            if call.is_call_to_command("str replace", context)
+                && (call.has_named_flag("regex") || call.has_named_flag("r")) =>
+        {
+            Some(call)
+        }
+        // `$in` in an argument wraps the whole element in a collect
+        Expr::Collect(_, inner) => regex_replace_call(inner, context),
+        _ => None,
+    }
+}
+
+/// Span of the first part of the interpolation that reads the replaced
+/// string, either through `$in` or through the variable piped into the
+/// replace
+fn input_reference(
+    replacement: &Expression,
+    subject: Option<VarId>,
+    context: &LintContext,
+) -> Option<Span> {
+    let Expr::StringInterpolation(items) = &replacement.expr else {
+        return None;
+    };
+    items.iter().find_map(|item| {
+        item.find_pipeline_input(context)
+            .map(|(_, span)| span)
+            .or_else(|| {
+                let var_id = subject?;
+                match item.extract_block_id() {
+                    Some(block_id) => context
+                        .working_set
+                        .get_block(block_id)
+                        .var_usages(var_id, context)
+                        .first()
+                        .copied(),
+                    None => item.matches_var(var_id).then_some(item.span),
+                }
+            })
+    })
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    pipeline
+        .elements
+        .iter()
+        .enumerate()
+        .filter_map(|(index, element)| {
+            let call = regex_replace_call(&element.expr, context)?;
+            let replacement = call.get_positional_arg(1)?;
+            let subject = index
+                .checked_sub(1)
+                .and_then(|prev| pipeline.elements[prev].expr.extract_direct_var());
+            let reference = input_reference(replacement, subject, context)?;
+
+            Some(
+                Detection::from_global_span(
+                    "Regex replacement interpolates the whole input instead of the match; use \
+                     capture groups like `$1` or `${name}`",
+                    replacement.span,
+                )
+                .with_primary_label("interpolated replacement")
+                .with_extra_label("this is the entire input string", reference),
+            )
+        })
+        .collect()
+}
+
+struct InterpolatedRegexReplacement;
+
+impl DetectFix for InterpolatedRegexReplacement {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "interpolated_regex_replacement"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`str replace --regex` replacement interpolating the input instead of captures"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "In `$s | str replace --regex '(\\w+)' $\"<($in)>\"` the interpolation is evaluated \
+             once, before any matching happens, so `$in` is the whole input string and not the \
+             text matched by the pattern. Refer to capture groups in a plain string instead: `str \
+             replace --regex '(\\w+)' '<$1>'`, or `'${name}'` for a named group `(?<name>...)`. \
+             Use single quotes so Nushell does not interpret the `$`.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/str_replace.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.ast.detect_in_pipelines(context, check_pipeline))
+    }
+}
+
+pub static RULE: &dyn Rule = &InterpolatedRegexReplacement;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
@@ -0,0 +1,34 @@
+use super::RULE;
+use crate::{Config, config::MagicNumberExceptions};
+
+#[test]
+fn detects_take_count() {
+    RULE.assert_detects(r"ls | take 100");
+}
+
+#[test]
+fn detects_duration() {
+    RULE.assert_detects(r"sleep 3sec");
+}
+
+#[test]
+fn detects_float() {
+    RULE.assert_detects(r"[1.5 2.5] | math round --precision 2 | append 0.25");
+}
+
+#[test]
+fn detects_each_literal_argument() {
+    RULE.assert_count(r"seq 5 50", 2);
+}
+
+#[test]
+fn detects_value_no_longer_allowed() {
+    let config = Config {
+        magic_number: MagicNumberExceptions {
+            allowed_values: vec![0],
+            ignored_commands: vec![],
+        },
+        ..Config::default()
+    };
+    RULE.assert_detects_with_config(r"ls | skip 1", &config);
+}
@@ -0,0 +1,46 @@
+use super::RULE;
+use crate::{Config, config::MagicNumberExceptions};
+
+#[test]
+fn ignores_zero_and_one() {
+    RULE.assert_ignores(r"ls | skip 1 | get 0");
+}
+
+#[test]
+fn ignores_named_constant() {
+    RULE.assert_ignores("const PAGE_SIZE = 100\nls | take $PAGE_SIZE");
+}
+
+#[test]
+fn ignores_let_binding() {
+    RULE.assert_ignores(r"let retries = 5");
+}
+
+#[test]
+fn ignores_configured_commands() {
+    let config = Config {
+        magic_number: MagicNumberExceptions {
+            ignored_commands: vec!["take".to_string(), "skip".to_string()],
+            ..MagicNumberExceptions::default()
+        },
+        ..Config::default()
+    };
+    RULE.assert_ignores_with_config(r"ls | skip 5 | take 10", &config);
+}
+
+#[test]
+fn ignores_configured_values() {
+    let config = Config {
+        magic_number: MagicNumberExceptions {
+            allowed_values: vec![0, 1, 10],
+            ..MagicNumberExceptions::default()
+        },
+        ..Config::default()
+    };
+    RULE.assert_ignores_with_config(r"ls | take 10", &config);
+}
+
+#[test]
+fn ignores_flag_values() {
+    RULE.assert_ignores(r"[1.5] | math round --precision 2");
+}
@@ -0,0 +1,101 @@
+use nu_protocol::ast::{Argument, Call, Expr, Expression};
+
+use crate::{
+    LintLevel,
+    ast::call::CallExt,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Commands whose arguments are the named constant itself
+const DECLARATIONS: &[&str] = &["let", "mut", "const"];
+
+/// Integer value of a number literal, `None` for floats that have no exact
+/// integer value
+fn literal_number(expr: &Expression) -> Option<Option<i64>> {
+    match &expr.expr {
+        Expr::Int(value) => Some(Some(*value)),
+        Expr::Float(_) => Some(None),
+        Expr::ValueWithUnit(value) => literal_number(&value.expr),
+        _ => None,
+    }
+}
+
+fn check_call(call: &Call, context: &LintContext) -> Vec<Detection> {
+    let command = call.get_call_name(context);
+    let exceptions = &context.config.magic_number;
+    if DECLARATIONS.contains(&command.as_str()) || exceptions.ignored_commands.contains(&command) {
+        return vec![];
+    }
+
+    call.arguments
+        .iter()
+        .filter_map(|arg| match arg {
+            Argument::Positional(expr) => Some(expr),
+            _ => None,
+        })
+        .filter(|expr| {
+            literal_number(expr).is_some_and(|value| {
+                value.is_none_or(|value| !exceptions.allowed_values.contains(&value))
+            })
+        })
+        .map(|expr| {
+            let literal = context.span_text(expr.span);
+            Detection::from_global_span(
+                format!(
+                    "Magic number `{literal}` passed to `{command}`; extract it into a named \
+                     `const` or `let`"
+                ),
+                expr.span,
+            )
+            .with_primary_label("unnamed literal")
+        })
+        .collect()
+}
+
+struct MagicNumber;
+
+impl DetectFix for MagicNumber {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "magic_number"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Number literal passed directly to a command"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A literal like `take 100` or `sleep 3sec` doesn't say why that value was chosen, and \
+             the same value repeated in several places has to be changed everywhere at once. A \
+             `const` or `let` with a descriptive name documents the intent. Values listed in \
+             `magic_number.allowed_values` (by default 0 and 1) and arguments of commands listed \
+             in `magic_number.ignored_commands` are not reported.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/variables.html#constant-variables")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Off
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.detect(|expr, ctx| match &expr.expr {
+            Expr::Call(call) => check_call(call, ctx),
+            _ => vec![],
+        }))
+    }
+}
+
+pub static RULE: &dyn Rule = &MagicNumber;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
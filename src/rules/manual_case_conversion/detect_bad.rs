@@ -0,0 +1,19 @@
+use super::RULE;
+
+#[test]
+fn detects_snake_case_by_hand() {
+    let bad_code = r#"let key = "My Title-Text" | str replace -a ' ' '_' | str replace -a '-' '_' | str downcase"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_kebab_case_with_downcase_first() {
+    let bad_code = r#"let slug = "My Title_Text" | str downcase | str replace --all ' ' '-' | str replace --all '_' '-'"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_screaming_snake_case() {
+    let bad_code = r#"let name = "some-env name" | str replace -a '-' '_' | str replace -a ' ' '_' | str upcase"#;
+    RULE.assert_labels_contain(bad_code, "str upcase");
+}
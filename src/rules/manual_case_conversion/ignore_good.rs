@@ -0,0 +1,60 @@
+use super::RULE;
+
+#[test]
+fn ignores_case_command() {
+    let good_code = r#"let key = "My Title" | str snake-case"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_single_replace_with_downcase() {
+    let good_code = r#"let key = "My Title" | str replace -a ' ' '_' | str downcase"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_separator_swaps_without_case_change() {
+    let good_code = r#"let path = "a b-c" | str replace -a ' ' '_' | str replace -a '-' '_'"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_different_targets() {
+    let good_code =
+        r#"let text = "a b_c" | str replace -a ' ' '-' | str replace -a '_' ' ' | str downcase"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_non_separator_replacements() {
+    let good_code =
+        r#"let text = "a.b,c" | str replace -a '.' '_' | str replace -a ',' '_' | str downcase"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_first_occurrence_only() {
+    let good_code =
+        r#"let text = "a b-c" | str replace ' ' '_' | str replace '-' '_' | str downcase"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_regex_replacements() {
+    let good_code =
+        r#"let text = "a b-c" | str replace -ar ' ' '_' | str replace -ar '-' '_' | str downcase"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_separators_to_spaces() {
+    let good_code =
+        r#"let text = "a_b-c" | str replace -a '_' ' ' | str replace -a '-' ' ' | str downcase"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_case_change_elsewhere_in_pipeline() {
+    let good_code = r#"let text = "a b-c" | str replace -a ' ' '_' | str replace -a '-' '_' | str trim | str downcase"#;
+    RULE.assert_ignores(good_code);
+}
@@ -0,0 +1,149 @@
+use nu_protocol::ast::{Call, Expr, Expression, Pipeline};
+
+use crate::{
+    LintLevel,
+    ast::{
+        block::BlockExt,
+        call::CallExt,
+        pipeline::{ClusterConfig, PipelineExt},
+    },
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Word separators that case commands insert or remove
+const SEPARATORS: &[&str] = &[" ", "-", "_"];
+
+fn string_literal(expr: &Expression) -> Option<&str> {
+    match &expr.expr {
+        Expr::String(s) | Expr::RawString(s) => Some(s),
+        _ => None,
+    }
+}
+
+/// The separator a `str replace --all` swaps in, if it swaps one separator for
+/// another
+fn separator_target(call: &Call) -> Option<&str> {
+    let is_all = call.has_named_flag("all") || call.has_named_flag("a");
+    let is_regex = call.has_named_flag("regex") || call.has_named_flag("r");
+    if !is_all || is_regex {
+        return None;
+    }
+    let find = string_literal(call.get_positional_arg(0)?)?;
+    let replacement = string_literal(call.get_positional_arg(1)?)?;
+    (find != replacement && SEPARATORS.contains(&find) && SEPARATORS.contains(&replacement))
+        .then_some(replacement)
+}
+
+fn case_command_at(
+    pipeline: &Pipeline,
+    index: Option<usize>,
+    context: &LintContext,
+) -> Option<&'static str> {
+    let Expr::Call(call) = &pipeline.elements.get(index?)?.expr.expr else {
+        return None;
+    };
+    if !call.arguments.is_empty() {
+        return None;
+    }
+    if call.is_call_to_command("str downcase", context) {
+        Some("downcase")
+    } else if call.is_call_to_command("str upcase", context) {
+        Some("upcase")
+    } else {
+        None
+    }
+}
+
+fn suggested_command(separator: &str, case: &str) -> Option<&'static str> {
+    match (separator, case) {
+        ("_", "downcase") => Some("str snake-case"),
+        ("_", "upcase") => Some("str screaming-snake-case"),
+        ("-", "downcase") => Some("str kebab-case"),
+        _ => None,
+    }
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    pipeline
+        .find_command_clusters("str replace", context, &ClusterConfig::min_consecutive(2))
+        .into_iter()
+        .filter_map(|cluster| {
+            let targets: Vec<&str> = cluster
+                .calls
+                .iter()
+                .map(|call| separator_target(call))
+                .collect::<Option<_>>()?;
+            let separator = *targets.first()?;
+            if targets.iter().any(|target| *target != separator) {
+                return None;
+            }
+
+            let before = cluster.first_index()?.checked_sub(1);
+            let after = cluster.last_index().map(|index| index + 1);
+            let (case, case_index) = [before, after]
+                .into_iter()
+                .find_map(|index| Some((case_command_at(pipeline, index, context)?, index?)))?;
+            let command = suggested_command(separator, case)?;
+
+            Some(
+                Detection::from_global_span(
+                    format!(
+                        "{} `str replace` calls and `str {case}` convert case by hand; \
+                         `{command}` does this in one step",
+                        cluster.len()
+                    ),
+                    cluster.span,
+                )
+                .with_primary_label("manual separator conversion")
+                .with_extra_label(
+                    format!("`str {case}`"),
+                    pipeline.elements[case_index].expr.span,
+                ),
+            )
+        })
+        .collect()
+}
+
+struct ManualCaseConversion;
+
+impl DetectFix for ManualCaseConversion {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "manual_case_conversion"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Chained `str replace` used to convert between case styles"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Replacing spaces, dashes and underscores one by one and then changing the case, as \
+             in `str replace -a ' ' '_' | str replace -a '-' '_' | str downcase`, reimplements \
+             the case commands. `str snake-case`, `str kebab-case` and `str screaming-snake-case` \
+             also split words on case changes, so check the result before switching.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/categories/strings.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.ast.detect_in_pipelines(context, check_pipeline))
+    }
+}
+
+pub static RULE: &dyn Rule = &ManualCaseConversion;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
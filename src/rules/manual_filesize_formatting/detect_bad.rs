@@ -0,0 +1,53 @@
+use super::RULE;
+
+#[test]
+fn detects_kb_mb_chain() {
+    let bad_code = r#"
+def human-size [bytes: int] {
+    if $bytes >= 1048576 {
+        $"($bytes / 1048576) MB"
+    } else if $bytes >= 1024 {
+        $"($bytes / 1024) KB"
+    } else {
+        $"($bytes) B"
+    }
+}
+"#;
+    RULE.assert_count(bad_code, 1);
+}
+
+#[test]
+fn detects_single_threshold() {
+    let bad_code = r#"
+let size = 5000
+let text = if $size > 1024 { $"($size / 1024) KiB" } else { $"($size) bytes" }
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_product_of_literals() {
+    let bad_code = r#"
+let n = 123456789
+if $n >= (1024 * 1024 * 1024) { print $"($n / (1024 * 1024 * 1024)) GB" } else { print $n }
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_decimal_units() {
+    let bad_code = r#"
+let n = 2500000
+if $n >= 1000000 { $"($n / 1000000)MB" } else { $"($n)B" }
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn labels_each_division() {
+    let bad_code = r#"
+let b = 4096
+if $b >= 1048576 { $"($b / 1048576) MB" } else if $b >= 1024 { $"($b / 1024) KB" } else { $"($b) B" }
+"#;
+    RULE.assert_labels_contain(bad_code, "scaled by hand");
+}
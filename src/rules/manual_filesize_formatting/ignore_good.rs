@@ -0,0 +1,43 @@
+use super::RULE;
+
+#[test]
+fn ignore_into_filesize() {
+    RULE.assert_ignores(r"let size = 5000 | into filesize");
+}
+
+#[test]
+fn ignore_milliseconds_to_seconds() {
+    let good_code = r#"
+let ms = 4200
+if $ms >= 1000 { $"($ms / 1000) s" } else { $"($ms) ms" }
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignore_without_else() {
+    let good_code = r#"
+let b = 4096
+if $b >= 1024 { print $"($b / 1024) KB" }
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignore_non_unit_threshold() {
+    let good_code = r#"
+let count = 1500
+if $count > 1500 { $"($count / 1024) KB" } else { $"($count) B" }
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignore_division_of_other_variable() {
+    let good_code = r#"
+let b = 4096
+let total = 10
+if $b >= 1024 { $"($total / 1024) KB" } else { $"($b) B" }
+"#;
+    RULE.assert_ignores(good_code);
+}
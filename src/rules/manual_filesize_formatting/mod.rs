@@ -0,0 +1,206 @@
+use nu_protocol::{
+    Span, VarId,
+    ast::{Call, Comparison, Expr, Expression, FindMapResult, Math, Operator, Traverse},
+};
+
+use crate::{
+    LintLevel,
+    ast::{call::CallExt, expression::ExpressionExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Unit labels that mark a branch as producing a human-readable size
+const SIZE_LABELS: &[&str] = &["kb", "mb", "gb", "tb", "kib", "mib", "gib", "tib"];
+
+/// Value of an integer literal or a product of literals like `(1024 * 1024)`
+fn const_int(expr: &Expression, context: &LintContext) -> Option<i64> {
+    let expr = match &expr.expr {
+        Expr::FullCellPath(cell_path) if cell_path.tail.is_empty() => &cell_path.head,
+        _ => expr,
+    }
+    .unwrap_block_expr(context);
+
+    match &expr.expr {
+        Expr::Int(n) => Some(*n),
+        Expr::BinaryOp(lhs, op, rhs) => {
+            let (lhs, rhs) = (const_int(lhs, context)?, const_int(rhs, context)?);
+            match op.expr {
+                Expr::Operator(Operator::Math(Math::Multiply)) => lhs.checked_mul(rhs),
+                Expr::Operator(Operator::Math(Math::Pow)) => {
+                    lhs.checked_pow(u32::try_from(rhs).ok()?)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether the number is a power of 1000 or 1024, i.e. one kilobyte or more
+fn is_size_unit(expr: &Expression, context: &LintContext) -> bool {
+    const_int(expr, context)
+        .is_some_and(|n| (1..=6).any(|exp| 1000_i64.pow(exp) == n || 1024_i64.pow(exp) == n))
+}
+
+/// Variable compared in a `$bytes >= 1024` threshold condition
+fn threshold_var(condition: &Expression, context: &LintContext) -> Option<VarId> {
+    let Expr::BinaryOp(lhs, op, rhs) = &condition.unwrap_block_expr(context).expr else {
+        return None;
+    };
+    let Expr::Var(var_id) = lhs.expr else {
+        return None;
+    };
+    (matches!(
+        op.expr,
+        Expr::Operator(Operator::Comparison(
+            Comparison::GreaterThan | Comparison::GreaterThanOrEqual
+        ))
+    ) && is_size_unit(rhs, context))
+    .then_some(var_id)
+}
+
+/// Span of a `$bytes / 1024` division in the branch, if the branch also
+/// labels its result with a size unit like `KB`
+fn scaled_division(branch: &Expression, var_id: VarId, context: &LintContext) -> Option<Span> {
+    let block = context.working_set.get_block(branch.extract_block_id()?);
+
+    let has_size_label = block
+        .find_map(context.working_set, &|expr| match &expr.expr {
+            Expr::String(text)
+                if text
+                    .split(|c: char| !c.is_ascii_alphabetic())
+                    .any(|word| SIZE_LABELS.contains(&word.to_ascii_lowercase().as_str())) =>
+            {
+                FindMapResult::Found(())
+            }
+            _ => FindMapResult::Continue,
+        })
+        .is_some();
+    if !has_size_label {
+        return None;
+    }
+
+    block.find_map(context.working_set, &|expr| match &expr.expr {
+        Expr::BinaryOp(lhs, op, rhs)
+            if matches!(lhs.expr, Expr::Var(id) if id == var_id)
+                && matches!(op.expr, Expr::Operator(Operator::Math(Math::Divide)))
+                && is_size_unit(rhs, context) =>
+        {
+            FindMapResult::Found(expr.span)
+        }
+        _ => FindMapResult::Continue,
+    })
+}
+
+/// Divisions of the `if`/`else if` chain that scale a byte count to a unit
+fn size_branches(call: &Call, context: &LintContext) -> Vec<Span> {
+    let mut divisions = Vec::new();
+    let mut current = call;
+    loop {
+        if let (Some(condition), Some(then_branch)) =
+            (current.get_positional_arg(0), current.get_positional_arg(1))
+            && let Some(var_id) = threshold_var(condition, context)
+            && let Some(division) = scaled_division(then_branch, var_id, context)
+        {
+            divisions.push(division);
+        }
+
+        match current.get_else_branch() {
+            Some((true, else_if)) => {
+                let Expr::Call(next) = &else_if.expr else {
+                    break;
+                };
+                current = next;
+            }
+            Some((false, _)) => break,
+            None => return vec![],
+        }
+    }
+    divisions
+}
+
+fn check_expression(expr: &Expression, context: &LintContext) -> Vec<(Span, Detection)> {
+    let Expr::Call(call) = &expr.expr else {
+        return vec![];
+    };
+    if !call.is_call_to_command("if", context) {
+        return vec![];
+    }
+    let divisions = size_branches(call, context);
+    if divisions.is_empty() {
+        return vec![];
+    }
+
+    let detection = divisions.iter().fold(
+        Detection::from_global_span(
+            "Byte count formatted by hand; `into filesize` picks the unit automatically",
+            call.head,
+        )
+        .with_primary_label("manual size formatting"),
+        |detection, division| detection.with_extra_label("scaled by hand", *division),
+    );
+    vec![(expr.span, detection)]
+}
+
+struct ManualFilesizeFormatting;
+
+impl DetectFix for ManualFilesizeFormatting {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "manual_filesize_formatting"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`if`/`else` chain dividing by 1024 to format a size"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Scripts from other shells format sizes with a chain like `if $bytes >= 1048576 { \
+             $\"($bytes / 1048576) MB\" } else if ...`. Nushell has a filesize type: `$bytes | \
+             into filesize` displays with a fitting unit, and `format filesize` converts to a \
+             fixed one. Only chains that compare a variable against a power of 1000 or 1024, \
+             divide it by one and label the result with a unit like `KB` are reported.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/format_filesize.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let mut chains = Vec::new();
+        context.ast.flat_map(
+            context.working_set,
+            &|expr| check_expression(expr, context),
+            &mut chains,
+        );
+
+        // An `else if` is itself an `if` call; only report the outermost one
+        let spans: Vec<Span> = chains.iter().map(|(span, _)| *span).collect();
+        let detections = chains
+            .into_iter()
+            .filter(|(span, _)| {
+                !spans
+                    .iter()
+                    .any(|outer| outer != span && outer.contains_span(*span))
+            })
+            .map(|(_, detection)| detection)
+            .collect();
+        Self::no_fix(detections)
+    }
+}
+
+pub static RULE: &dyn Rule = &ManualFilesizeFormatting;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
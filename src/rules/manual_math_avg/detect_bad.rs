@@ -0,0 +1,25 @@
+use super::RULE;
+
+#[test]
+fn detects_sum_over_length() {
+    RULE.assert_detects(r"let xs = [1 2 3]; ($xs | math sum) / ($xs | length)");
+}
+
+#[test]
+fn detects_in_let() {
+    RULE.assert_detects(r"let xs = [1 2 3]; let mean = ($xs | math sum) / ($xs | length)");
+}
+
+#[test]
+fn detects_cell_path_source() {
+    RULE.assert_detects(
+        r"let data = {values: [1 2 3]}; ($data.values | math sum) / ($data.values | length)",
+    );
+}
+
+#[test]
+fn detects_reduce_sum() {
+    RULE.assert_detects(
+        r"let xs = [1 2 3]; ($xs | reduce {|it, acc| $acc + $it }) / ($xs | length)",
+    );
+}
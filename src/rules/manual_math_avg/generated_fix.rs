@@ -0,0 +1,25 @@
+use super::RULE;
+
+#[test]
+fn fix_to_math_avg() {
+    RULE.assert_fixed_is(
+        r"let xs = [1 2 3]; ($xs | math sum) / ($xs | length)",
+        r"let xs = [1 2 3]; ($xs | math avg)",
+    );
+}
+
+#[test]
+fn fix_keeps_multi_stage_source() {
+    RULE.assert_fixed_is(
+        r"let mean = (ls | get size | math sum) / (ls | get size | length)",
+        r"let mean = (ls | get size | math avg)",
+    );
+}
+
+#[test]
+fn reduce_sum_has_no_fix() {
+    // The closure may do more than add, so only the help is shown
+    let code = r"let xs = [1 2 3]; ($xs | reduce {|it, acc| $acc + $it }) / ($xs | length)";
+    RULE.assert_count(code, 1);
+    RULE.assert_no_fix(code);
+}
@@ -0,0 +1,28 @@
+use super::RULE;
+
+#[test]
+fn ignore_math_avg() {
+    RULE.assert_ignores(r"let xs = [1 2 3]; $xs | math avg");
+}
+
+#[test]
+fn ignore_different_sources() {
+    RULE.assert_ignores(r"let xs = [1 2 3]; let ys = [4 5]; ($xs | math sum) / ($ys | length)");
+}
+
+#[test]
+fn ignore_division_by_constant() {
+    RULE.assert_ignores(r"let xs = [1 2 3]; ($xs | math sum) / 3");
+}
+
+#[test]
+fn ignore_product_over_length() {
+    RULE.assert_ignores(r"let xs = [1 2 3]; ($xs | math product) / ($xs | length)");
+}
+
+#[test]
+fn ignore_reduce_with_other_operation() {
+    RULE.assert_ignores(
+        r"let xs = [1 2 3]; ($xs | reduce {|it, acc| $acc * $it }) / ($xs | length)",
+    );
+}
@@ -0,0 +1,160 @@
+use nu_protocol::{
+    Span,
+    ast::{Call, Expr, Expression, Math, Operator},
+};
+
+use crate::{
+    LintLevel,
+    ast::{call::CallExt, expression::ExpressionExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    /// The whole `(... | math sum) / (... | length)` division
+    division: Span,
+    source: String,
+}
+
+/// A closure that adds its two parameters, like `{|it, acc| $acc + $it }`
+fn is_sum_closure(expr: &Expression, context: &LintContext) -> bool {
+    let Expr::Closure(block_id) = &expr.expr else {
+        return false;
+    };
+    let block = context.working_set.get_block(*block_id);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return false;
+    };
+    let [element] = pipeline.elements.as_slice() else {
+        return false;
+    };
+    let Expr::BinaryOp(lhs, op, rhs) = &element.expr.expr else {
+        return false;
+    };
+    matches!(op.expr, Expr::Operator(Operator::Math(Math::Add)))
+        && lhs.extract_direct_var().is_some()
+        && rhs.extract_direct_var().is_some()
+}
+
+fn is_reduce_sum(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("reduce", context)
+        && call
+            .get_first_positional_arg()
+            .is_some_and(|closure| is_sum_closure(closure, context))
+}
+
+/// Splits `(<source> | <command>)` into the source text and the final call
+fn split_last_stage<'a>(
+    expr: &Expression,
+    context: &'a LintContext,
+) -> Option<(&'a str, &'a Call)> {
+    let block = context.working_set.get_block(expr.extract_block_id()?);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return None;
+    };
+    let (last, rest) = pipeline.elements.split_last()?;
+    let (first, _) = rest.split_first()?;
+    let Expr::Call(call) = &last.expr.expr else {
+        return None;
+    };
+    let source_end = rest.last()?.expr.span.end;
+    Some((
+        context.span_text(Span::new(first.expr.span.start, source_end)),
+        call,
+    ))
+}
+
+fn check_division(expr: &Expression, context: &LintContext) -> Vec<(Detection, Option<FixData>)> {
+    let Expr::BinaryOp(lhs, op, rhs) = &expr.expr else {
+        return vec![];
+    };
+    if !matches!(op.expr, Expr::Operator(Operator::Math(Math::Divide))) {
+        return vec![];
+    }
+    let (Some((sum_source, sum)), Some((length_source, length))) = (
+        split_last_stage(lhs, context),
+        split_last_stage(rhs, context),
+    ) else {
+        return vec![];
+    };
+    if sum_source != length_source
+        || !length.is_call_to_command("length", context)
+        || !length.arguments.is_empty()
+    {
+        return vec![];
+    }
+
+    let fix_data = if sum.is_call_to_command("math sum", context) && sum.arguments.is_empty() {
+        Some(FixData {
+            division: expr.span,
+            source: sum_source.to_string(),
+        })
+    } else if is_reduce_sum(sum, context) {
+        None
+    } else {
+        return vec![];
+    };
+
+    let detection = Detection::from_global_span(
+        format!("Sum divided by length is an average; use `{sum_source} | math avg`"),
+        expr.span,
+    )
+    .with_primary_label("manual average")
+    .with_extra_label("sum", lhs.span)
+    .with_extra_label("count of the same list", rhs.span);
+    vec![(detection, fix_data)]
+}
+
+struct ManualMathAvg;
+
+impl DetectFix for ManualMathAvg {
+    type FixInput<'a> = Option<FixData>;
+
+    fn id(&self) -> &'static str {
+        "manual_math_avg"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Sum divided by `length` instead of `math avg`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`($xs | math sum) / ($xs | length)` computes the mean of a list in two passes and \
+             evaluates `$xs` twice. `$xs | math avg` does the same in one command and also works \
+             on durations and filesizes. Sums written with `reduce` are reported as well, but \
+             only the `math sum` form is fixed automatically.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/math_avg.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.detect_with_fix_data(check_division)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let fix_data = fix_data.as_ref()?;
+        let replacement = format!("({} | math avg)", fix_data.source);
+        Some(Fix {
+            explanation: format!("Replace with `{replacement}`").into(),
+            replacements: vec![Replacement::new(fix_data.division, replacement)],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &ManualMathAvg;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
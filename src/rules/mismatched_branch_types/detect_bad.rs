@@ -0,0 +1,55 @@
+use super::RULE;
+
+#[test]
+fn detects_if_with_string_and_int() {
+    let bad_code = r#"
+def pick [c: bool] {
+    let x = if $c { "a" } else { 5 }
+    $x
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_parenthesized_if() {
+    let bad_code = r#"
+def pick [c: bool] {
+    let x = (if $c { "a" } else { 5 })
+    $x
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_else_if_chain() {
+    let bad_code = r#"
+def pick [n: int] {
+    let label = if $n > 10 { "big" } else if $n > 5 { "medium" } else { false }
+    $label
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_match_arms() {
+    let bad_code = r#"
+def pick [name: string] {
+    mut size = match $name {
+        "small" => 1
+        "large" => "huge"
+        _ => 0
+    }
+    $size
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn labels_each_branch_type() {
+    let bad_code = r#"let x = if true { "a" } else { [1 2] }"#;
+    RULE.assert_labels_contain(bad_code, "string");
+}
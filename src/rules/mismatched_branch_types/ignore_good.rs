@@ -0,0 +1,72 @@
+use super::RULE;
+
+#[test]
+fn ignores_matching_branches() {
+    let good_code = r#"
+def pick [c: bool] {
+    let x = if $c { "a" } else { "b" }
+    $x
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_int_and_float() {
+    let good_code = r#"
+def pick [c: bool] {
+    let x = if $c { 1 } else { 2.5 }
+    $x
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_if_without_else() {
+    let good_code = r#"
+def pick [c: bool] {
+    let x = if $c { "a" }
+    $x
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_null_branch() {
+    let good_code = r#"
+def pick [c: bool] {
+    let x = if $c { "a" } else { null }
+    $x
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_unknown_branch_type() {
+    let good_code = r#"
+def pick [c: bool, fallback] {
+    let x = if $c { "a" } else { $fallback }
+    $x
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_lists_with_different_elements() {
+    let good_code = r#"let xs = if true { [1 2] } else { ["a"] }"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_if_as_statement() {
+    let good_code = r#"
+def show [c: bool] {
+    if $c { print "a" } else { print 5 }
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
@@ -0,0 +1,138 @@
+use nu_protocol::{
+    Span, Type,
+    ast::{Call, Expr, Expression},
+};
+
+use crate::{
+    LintLevel,
+    ast::{call::CallExt, expression::ExpressionExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Coarse category of a concrete type. Types in the same category (`int` and
+/// `float`, lists with different element types) are not reported. Returns
+/// `None` for types that are not concrete enough to compare.
+const fn type_category(ty: &Type) -> Option<&'static str> {
+    match ty {
+        Type::Int | Type::Float | Type::Number => Some("number"),
+        Type::String | Type::Glob => Some("string"),
+        Type::Bool => Some("bool"),
+        Type::List(_) | Type::Table(_) => Some("list"),
+        Type::Record(_) => Some("record"),
+        Type::Date => Some("datetime"),
+        Type::Duration => Some("duration"),
+        Type::Filesize => Some("filesize"),
+        Type::Binary => Some("binary"),
+        Type::Range => Some("range"),
+        Type::Closure => Some("closure"),
+        _ => None,
+    }
+}
+
+/// Collect the value expression of every branch. Returns `None` for an `if`
+/// without a final `else`, since its missing branch yields `null` on purpose.
+fn collect_branches<'a>(call: &'a Call, context: &LintContext) -> Option<Vec<&'a Expression>> {
+    match call.get_call_name(context).as_str() {
+        "if" => {
+            let mut branches = vec![call.get_positional_arg(1)?];
+            match call.get_else_branch()? {
+                (true, else_if) => {
+                    let Expr::Call(else_if_call) = &else_if.expr else {
+                        return None;
+                    };
+                    branches.extend(collect_branches(else_if_call, context)?);
+                }
+                (false, else_block) => branches.push(else_block),
+            }
+            Some(branches)
+        }
+        "match" => {
+            let Expr::MatchBlock(arms) = &call.get_positional_arg(1)?.expr else {
+                return None;
+            };
+            Some(arms.iter().map(|(_, arm)| arm).collect())
+        }
+        _ => None,
+    }
+}
+
+fn check_declaration(expr: &Expression, context: &LintContext) -> Option<Detection> {
+    let Expr::Call(decl_call) = &expr.expr else {
+        return None;
+    };
+    let (_, var_name, _) = decl_call.extract_variable_declaration(context)?;
+    let value = decl_call.get_positional_arg(1)?.unwrap_block_expr(context);
+    let Expr::Call(call) = &value.expr else {
+        return None;
+    };
+
+    let typed_branches: Vec<(Type, &'static str, Span)> = collect_branches(call, context)?
+        .into_iter()
+        .filter_map(|branch| {
+            let ty = branch.infer_output_type(context)?;
+            let category = type_category(&ty)?;
+            Some((ty, category, branch.span))
+        })
+        .collect();
+
+    let (first_ty, first_category, _) = typed_branches.first()?;
+    let (other_ty, _, _) = typed_branches
+        .iter()
+        .find(|(_, category, _)| category != first_category)?;
+
+    let command = call.get_call_name(context);
+    typed_branches
+        .iter()
+        .fold(
+            Detection::from_global_span(
+                format!(
+                    "`{command}` assigned to `{var_name}` returns {first_ty} in one branch and \
+                     {other_ty} in another, so `{var_name}` has type `any`"
+                ),
+                call.head,
+            )
+            .with_primary_label("branches disagree on type"),
+            |detection, (ty, _, span)| detection.with_extra_label(format!("{ty}"), *span),
+        )
+        .into()
+}
+
+struct MismatchedBranchTypes;
+
+impl DetectFix for MismatchedBranchTypes {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "mismatched_branch_types"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`if` or `match` value with branches of different types"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "When `if` or `match` is used as a value, as in `let x = if $c { \"a\" } else { 5 }`, \
+             branches returning different types make the result `any`. That hides type errors \
+             until a later command receives the wrong kind of value. Only branches with a clearly \
+             inferred type are compared, and `int` and `float` count as the same kind.",
+        )
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.detect_single(check_declaration))
+    }
+}
+
+pub static RULE: &dyn Rule = &MismatchedBranchTypes;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
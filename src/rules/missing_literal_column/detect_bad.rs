@@ -0,0 +1,41 @@
+use super::RULE;
+
+#[test]
+fn detects_get_missing_record_key() {
+    RULE.assert_detects("{name: 'nu', version: 1} | get nmae");
+}
+
+#[test]
+fn detects_select_missing_table_column() {
+    RULE.assert_detects("[[name size]; [a 1] [b 2]] | select name type");
+}
+
+#[test]
+fn detects_each_missing_select_column() {
+    RULE.assert_count("{a: 1, b: 2} | select c d", 2);
+}
+
+#[test]
+fn detects_get_after_row_index() {
+    RULE.assert_detects("[[name size]; [a 1]] | get 0.nmae");
+}
+
+#[test]
+fn detects_list_of_records() {
+    RULE.assert_detects("[{a: 1} {a: 2, b: 3}] | get c");
+}
+
+#[test]
+fn detects_quoted_key() {
+    RULE.assert_detects(r#"{"first name": 'Ada'} | get "last name""#);
+}
+
+#[test]
+fn detects_inside_closure() {
+    RULE.assert_detects("def main [] { {x: 1} | get y | print }");
+}
+
+#[test]
+fn labels_literal() {
+    RULE.assert_labels_contain("{a: 1} | get b", "literal defined here");
+}
@@ -0,0 +1,47 @@
+use super::RULE;
+
+#[test]
+fn ignore_existing_record_key() {
+    RULE.assert_ignores("{name: 'nu', version: 1} | get name");
+}
+
+#[test]
+fn ignore_existing_table_columns() {
+    RULE.assert_ignores("[[name size]; [a 1]] | select name size");
+}
+
+#[test]
+fn ignore_optional_member() {
+    RULE.assert_ignores("{a: 1} | get b?");
+}
+
+#[test]
+fn ignore_optional_flag() {
+    RULE.assert_ignores("{a: 1} | get -o b");
+}
+
+#[test]
+fn ignore_record_with_spread() {
+    RULE.assert_ignores("{a: 1, ...$defaults} | get b");
+}
+
+#[test]
+fn ignore_variable_upstream() {
+    RULE.assert_ignores("let r = {a: 1}; $r | get b");
+}
+
+#[test]
+fn ignore_row_index_only() {
+    RULE.assert_ignores("[[name]; [a] [b]] | get 1");
+}
+
+#[test]
+fn ignore_key_present_in_some_records() {
+    // Rows may have differing shapes, so only keys absent from every row count
+    RULE.assert_ignores("[{a: 1} {b: 2}] | get b");
+}
+
+#[test]
+fn ignore_intermediate_stage() {
+    RULE.assert_ignores("{a: 1} | insert b 2 | get b");
+}
@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+
+use nu_protocol::ast::{Call, Expr, Expression, ListItem, PathMember, Pipeline, RecordItem};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, string::StringFormat},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Shape of an inline literal whose column names are statically known
+struct LiteralColumns {
+    names: HashSet<String>,
+    /// Tables and lists of records are indexed by row before column
+    has_rows: bool,
+}
+
+fn key_name(key: &Expression, context: &LintContext) -> Option<String> {
+    StringFormat::from_expression(key, context).map(|format| format.content().to_string())
+}
+
+fn record_keys(items: &[RecordItem], context: &LintContext) -> Option<HashSet<String>> {
+    items
+        .iter()
+        .map(|item| match item {
+            RecordItem::Pair(key, _) => key_name(key, context),
+            // A spread may contribute any column
+            RecordItem::Spread(..) => None,
+        })
+        .collect()
+}
+
+fn literal_columns(expr: &Expression, context: &LintContext) -> Option<LiteralColumns> {
+    match &expr.expr {
+        Expr::Record(items) => Some(LiteralColumns {
+            names: record_keys(items, context)?,
+            has_rows: false,
+        }),
+        Expr::Table(table) => Some(LiteralColumns {
+            names: table
+                .columns
+                .iter()
+                .map(|column| key_name(column, context))
+                .collect::<Option<_>>()?,
+            has_rows: true,
+        }),
+        Expr::List(items) if !items.is_empty() => {
+            let mut names = HashSet::new();
+            for item in items {
+                let ListItem::Item(Expression {
+                    expr: Expr::Record(fields),
+                    ..
+                }) = item
+                else {
+                    return None;
+                };
+                names.extend(record_keys(fields, context)?);
+            }
+            Some(LiteralColumns {
+                names,
+                has_rows: true,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Column name referenced by a `get`/`select` argument, skipping leading row
+/// indices on tables. Optional members (`name?`) are never reported.
+fn referenced_column(arg: &Expression, has_rows: bool) -> Option<&str> {
+    match &arg.expr {
+        Expr::String(name) => Some(name.as_str()),
+        Expr::CellPath(cell_path) => {
+            let mut members = cell_path.members.iter();
+            let member = if has_rows {
+                members.find(|m| !matches!(m, PathMember::Int { .. }))?
+            } else {
+                members.next()?
+            };
+            match member {
+                PathMember::String {
+                    val,
+                    optional: false,
+                    ..
+                } => Some(val.as_str()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_column_access(call: &Call, context: &LintContext) -> bool {
+    (call.is_call_to_command("get", context) || call.is_call_to_command("select", context))
+        && !call.has_named_flag("optional")
+        && !call.has_named_flag("o")
+        && !call.has_named_flag("ignore-errors")
+        && !call.has_named_flag("i")
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    let [source, access, ..] = pipeline.elements.as_slice() else {
+        return vec![];
+    };
+    let Expr::Call(call) = &access.expr.expr else {
+        return vec![];
+    };
+    if !is_column_access(call, context) {
+        return vec![];
+    }
+    let Some(columns) = literal_columns(&source.expr, context) else {
+        return vec![];
+    };
+
+    let command = call.get_call_name(context);
+    (0..)
+        .map_while(|index| call.get_positional_arg(index))
+        .filter_map(|arg| {
+            let column = referenced_column(arg, columns.has_rows)?;
+            (!columns.names.contains(column)).then(|| {
+                Detection::from_global_span(
+                    format!(
+                        "`{command}` refers to column '{column}' which the literal does not have"
+                    ),
+                    arg.span,
+                )
+                .with_primary_label("missing column")
+                .with_extra_label("literal defined here", source.expr.span)
+            })
+        })
+        .collect()
+}
+
+struct MissingLiteralColumn;
+
+impl DetectFix for MissingLiteralColumn {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "missing_literal_column"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`get`/`select` of a column absent from an inline record or table"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "When `get` or `select` is applied directly to a record or table literal, the \
+             available columns are known while linting. Referencing a column the literal does not \
+             define fails at runtime with 'cannot find column', which usually points to a typo. \
+             Optional members (`name?`) and the `--optional` flag are not reported.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/get.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.ast.detect_in_pipelines(context, check_pipeline))
+    }
+}
+
+pub static RULE: &dyn Rule = &MissingLiteralColumn;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
@@ -6,8 +6,10 @@ pub mod add_hat_external_commands;
 pub mod ansi_over_escape_codes;
 pub mod append_to_concat_assign;
 pub mod assign_then_return;
+pub mod bare_sys_command;
 pub mod catch_builtin_error_try;
 pub mod chained_append;
+pub mod chained_column_update;
 pub mod chained_str_transform;
 pub mod check_complete_exit_code;
 pub mod check_typed_flag_before_use;
@@ -15,14 +17,21 @@ pub mod collapsible_if;
 pub mod columns_in_to_has;
 pub mod columns_not_in_to_not_has;
 pub mod compound_assignment;
+pub mod concat_list_literals;
+pub mod consider_par_each;
 pub mod contains_to_regex_op;
 pub mod custom_log_command;
 pub mod dangerous_file_operations;
+pub mod deprecated_builtin;
+pub mod describe_type_check;
 pub mod dispatch_with_subcommands;
 pub mod division_to_format_duration;
 pub mod do_not_compare_booleans;
 pub mod documentation;
 pub mod dynamic_script_import;
+pub mod each_record_projection;
+pub mod echo_for_output;
+pub mod empty_catch_block;
 pub mod error_make;
 pub mod errors_to_stderr;
 pub mod exit_only_in_main;
@@ -33,6 +42,7 @@ pub mod filesystem;
 pub mod filtering;
 pub mod forbid_excessive_nesting;
 pub mod fragile_last_exit_code;
+pub mod get_on_scalar_input;
 pub mod get_optional_to_has;
 pub mod get_optional_to_not_has;
 pub mod glob_may_drop_quotes;
@@ -40,11 +50,19 @@ pub mod hardcoded_math_constants;
 pub mod if_else_chain_to_match;
 pub mod if_null_to_default;
 pub mod ignore_over_dev_null;
+pub mod infinite_loop;
+pub mod interpolated_regex_replacement;
 pub mod list_param_to_variadic;
+pub mod magic_number;
+pub mod manual_case_conversion;
+pub mod manual_filesize_formatting;
+pub mod manual_math_avg;
 pub mod max_function_body_length;
 pub mod max_positional_params;
 pub mod merge_get_cell_path;
 pub mod merge_multiline_print;
+pub mod mismatched_branch_types;
+pub mod missing_literal_column;
 pub mod missing_stdin_in_shebang;
 pub mod naming;
 pub mod never_space_split;
@@ -52,18 +70,31 @@ pub mod non_final_failure_check;
 pub mod not_is_empty_to_is_not_empty;
 pub mod nothing_outside_signature;
 pub mod parsing;
+pub mod path_append_duplicates;
+pub mod pipeline_too_long;
 pub mod positional_to_pipeline;
 pub mod posix_tools;
+pub mod prefer_each_over_for;
+pub mod prefer_first_last_over_get_index;
+pub mod prefer_get_over_each_field;
+pub mod prefer_is_empty_over_null_compare;
+pub mod prefer_string_interpolation;
 pub mod range_for_iteration;
 pub mod record_assignments;
+pub mod redundant_format_roundtrip;
+pub mod redundant_identity_each;
 pub mod redundant_ignore;
+pub mod redundant_into_string;
 pub mod redundant_nu_subprocess;
+pub mod redundant_table_render;
 pub mod remove_hat_not_builtin;
 pub mod remove_redundant_in;
 pub mod require_main_with_stdin;
 pub mod script_export_main;
 pub mod self_import;
+pub mod shadowed_variable;
 pub mod side_effects;
+pub mod silent_ignore_errors;
 pub mod single_call_command;
 pub mod source_to_use;
 pub mod spacing;
@@ -72,20 +103,28 @@ pub mod streaming_hidden_by_complete;
 pub mod string_may_be_bare;
 pub mod structured_data_to_csv_tool;
 pub mod structured_data_to_json_tool;
+pub mod to_json_flag_conflict;
 pub mod transpose_items;
+pub mod transpose_round_trip;
+pub mod trim_char_strips_repeats;
 pub mod try_instead_of_do;
 pub mod typing;
 pub mod unchecked_cell_path_index;
+pub mod unchecked_env_access;
 pub mod unchecked_get_index;
+pub mod unconditional_recursion;
 pub mod unescaped_interpolation;
 pub mod unhandled_external_error;
 pub mod unnecessary_accumulate;
 pub mod unnecessary_mut;
+pub mod unreachable_after_terminator;
 pub mod unsafe_dynamic_record_access;
+pub mod unused_closure_parameter;
 pub mod unused_helper_functions;
 pub mod unused_parameter;
 pub mod unused_variable;
 pub mod upstream;
+pub mod where_after_sort;
 
 /// All rules that are used by default when linting.
 pub const USED_RULES: &[&dyn Rule] = &[
@@ -93,8 +132,10 @@ pub const USED_RULES: &[&dyn Rule] = &[
     ansi_over_escape_codes::RULE,
     append_to_concat_assign::RULE,
     assign_then_return::RULE,
+    bare_sys_command::RULE,
     catch_builtin_error_try::RULE,
     chained_append::RULE,
+    chained_column_update::RULE,
     chained_str_transform::RULE,
     check_complete_exit_code::RULE,
     check_typed_flag_before_use::RULE,
@@ -102,9 +143,13 @@ pub const USED_RULES: &[&dyn Rule] = &[
     columns_in_to_has::RULE,
     columns_not_in_to_not_has::RULE,
     compound_assignment::RULE,
+    concat_list_literals::RULE,
+    consider_par_each::RULE,
     contains_to_regex_op::RULE,
     custom_log_command::RULE,
     dangerous_file_operations::RULE,
+    deprecated_builtin::RULE,
+    describe_type_check::RULE,
     dispatch_with_subcommands::RULE,
     do_not_compare_booleans::RULE,
     documentation::add_doc_comment_exported_fn::RULE,
@@ -113,6 +158,9 @@ pub const USED_RULES: &[&dyn Rule] = &[
     documentation::main_positional_args_docs::RULE,
     division_to_format_duration::RULE,
     dynamic_script_import::RULE,
+    each_record_projection::RULE,
+    echo_for_output::RULE,
+    empty_catch_block::RULE,
     error_make::add_help_to_error::RULE,
     error_make::add_label_to_error::RULE,
     error_make::add_span_to_label::RULE,
@@ -127,12 +175,19 @@ pub const USED_RULES: &[&dyn Rule] = &[
     external_tools::fd_to_glob::RULE,
     external_tools::jq_to_nu_pipeline::RULE,
     external_tools::wget_to_http_get::RULE,
+    filesystem::extension_filter_to_glob::RULE,
     filesystem::from_after_parsed_open::RULE,
+    filesystem::open_raw_decode_to_open::RULE,
     filesystem::open_raw_from_to_open::RULE,
+    filesystem::open_text_str_length::RULE,
+    filesystem::repeated_file_open::RULE,
+    filesystem::string_ops_on_path::RULE,
     filesystem::string_param_as_path::RULE,
+    filesystem::unfiltered_recursive_glob::RULE,
     filtering::each_if_to_where::RULE,
     filtering::for_filter_to_where::RULE,
     filtering::omit_it_in_row_condition::RULE,
+    filtering::prefer_empty_filter::RULE,
     filtering::slice_to_drop::RULE,
     filtering::slice_to_last::RULE,
     filtering::slice_to_skip::RULE,
@@ -140,6 +195,7 @@ pub const USED_RULES: &[&dyn Rule] = &[
     filtering::where_closure_drop_parameter::RULE,
     forbid_excessive_nesting::RULE,
     fragile_last_exit_code::RULE,
+    get_on_scalar_input::RULE,
     get_optional_to_has::RULE,
     get_optional_to_not_has::RULE,
     glob_may_drop_quotes::RULE,
@@ -147,13 +203,21 @@ pub const USED_RULES: &[&dyn Rule] = &[
     if_else_chain_to_match::RULE,
     if_null_to_default::RULE,
     ignore_over_dev_null::RULE,
+    infinite_loop::RULE,
+    interpolated_regex_replacement::RULE,
     list_param_to_variadic::RULE,
+    manual_case_conversion::RULE,
+    manual_filesize_formatting::RULE,
+    magic_number::RULE,
+    manual_math_avg::RULE,
     max_function_body_length::RULE,
     max_positional_params::RULE,
     merge_get_cell_path::RULE,
     record_assignments::USE_RECORD_SPREAD,
     record_assignments::USE_LOAD_ENV,
     merge_multiline_print::RULE,
+    mismatched_branch_types::RULE,
+    missing_literal_column::RULE,
     missing_stdin_in_shebang::RULE,
     naming::kebab_case_commands::RULE,
     naming::screaming_snake_constants::RULE,
@@ -169,8 +233,11 @@ pub const USED_RULES: &[&dyn Rule] = &[
     parsing::split_row_get_inline::RULE,
     parsing::split_row_get_multistatement::RULE,
     parsing::split_row_space_to_split_words::RULE,
+    path_append_duplicates::RULE,
+    pipeline_too_long::RULE,
     positional_to_pipeline::RULE,
     posix_tools::awk_to_pipeline::RULE,
+    posix_tools::basename_dirname_to_path::RULE,
     posix_tools::bat_to_open::RULE,
     posix_tools::cat_to_open::RULE,
     posix_tools::date_to_date_now::RULE,
@@ -182,12 +249,14 @@ pub const USED_RULES: &[&dyn Rule] = &[
     posix_tools::find_to_glob::RULE,
     posix_tools::free_to_sys_mem::RULE,
     posix_tools::grep_to_find_or_where::RULE,
+    posix_tools::hashsum_to_hash::RULE,
     posix_tools::head_to_first::RULE,
     posix_tools::hostname_to_sys_host::RULE,
     posix_tools::pager_to_explore::RULE,
     posix_tools::read_to_input::RULE,
     posix_tools::redundant_echo::RULE,
     posix_tools::sed_to_str_transform::RULE,
+    posix_tools::seq_to_range::RULE,
     posix_tools::tac_to_reverse::RULE,
     posix_tools::tail_to_last::RULE,
     posix_tools::uname_to_sys_host::RULE,
@@ -196,24 +265,38 @@ pub const USED_RULES: &[&dyn Rule] = &[
     posix_tools::w_to_sys_users::RULE,
     posix_tools::wc_to_length::RULE,
     posix_tools::who_to_sys_users::RULE,
+    posix_tools::xargs_to_each::RULE,
+    prefer_each_over_for::RULE,
+    prefer_get_over_each_field::RULE,
+    prefer_is_empty_over_null_compare::RULE,
+    prefer_first_last_over_get_index::RULE,
+    prefer_string_interpolation::RULE,
     range_for_iteration::loop_counter::RULE,
     range_for_iteration::while_counter::RULE,
+    redundant_format_roundtrip::RULE,
+    redundant_identity_each::RULE,
     redundant_ignore::RULE,
+    redundant_into_string::RULE,
     redundant_nu_subprocess::RULE,
+    redundant_table_render::RULE,
     remove_hat_not_builtin::RULE,
     remove_redundant_in::RULE,
     require_main_with_stdin::RULE,
     script_export_main::RULE,
     self_import::RULE,
+    shadowed_variable::RULE,
     side_effects::dont_mix_different_effects::RULE,
     side_effects::each_nothing_to_for_loop::RULE,
+    side_effects::echoed_command_output::RULE,
     side_effects::print_and_return_data::RULE,
     side_effects::silence_stderr_data::RULE,
+    silent_ignore_errors::RULE,
     single_call_command::RULE,
     source_to_use::RULE,
     spacing::block_brace_spacing::RULE,
     spacing::closure_brace_pipe_spacing::RULE,
     spacing::closure_pipe_body_spacing::RULE,
+    spacing::multiline_trailing_comma::RULE,
     spacing::no_trailing_spaces::RULE,
     spacing::omit_list_commas::RULE,
     spacing::pipe_spacing::RULE,
@@ -226,21 +309,32 @@ pub const USED_RULES: &[&dyn Rule] = &[
     string_may_be_bare::RULE,
     structured_data_to_csv_tool::RULE,
     structured_data_to_json_tool::RULE,
+    to_json_flag_conflict::RULE,
     transpose_items::RULE,
+    transpose_round_trip::RULE,
+    trim_char_strips_repeats::RULE,
     try_instead_of_do::RULE,
     typing::add_type_hints_arguments::RULE,
+    typing::let_type_mismatch::RULE,
     typing::missing_in_type::RULE,
     typing::missing_output_type::RULE,
+    typing::scalar_in_type::RULE,
+    typing::untyped_main_params::RULE,
     unchecked_cell_path_index::RULE,
+    unchecked_env_access::RULE,
     unchecked_get_index::RULE,
+    unconditional_recursion::RULE,
     unescaped_interpolation::RULE,
     unnecessary_accumulate::RULE,
     unnecessary_mut::RULE,
+    unreachable_after_terminator::RULE,
     unsafe_dynamic_record_access::RULE,
+    unused_closure_parameter::RULE,
     unused_helper_functions::RULE,
     unused_parameter::RULE,
     unused_variable::RULE,
     upstream::nu_deprecated::RULE,
     upstream::nu_parse_error::RULE,
+    where_after_sort::RULE,
     unhandled_external_error::RULE,
 ];
@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn detects_append_to_path() {
+    RULE.assert_detects(r"$env.PATH = ($env.PATH | append '/opt/bin')");
+}
+
+#[test]
+fn detects_prepend_to_path() {
+    RULE.assert_detects(r"$env.PATH = ($env.PATH | prepend ($env.HOME | path join .local bin))");
+}
+
+#[test]
+fn detects_concatenate_assign() {
+    RULE.assert_detects(r"$env.PATH ++= ['/opt/bin']");
+}
+
+#[test]
+fn detects_split_before_append() {
+    RULE.assert_detects(r"$env.PATH = ($env.PATH | split row (char esep) | append '/opt/bin')");
+}
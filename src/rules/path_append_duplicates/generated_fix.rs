@@ -0,0 +1,25 @@
+use super::RULE;
+
+#[test]
+fn fix_append_to_path_add() {
+    RULE.assert_fixed_is(
+        r"$env.PATH = ($env.PATH | append '/opt/bin')",
+        r"path add --append '/opt/bin'",
+    );
+}
+
+#[test]
+fn fix_prepend_variable_to_path_add() {
+    RULE.assert_fixed_is(
+        r"let dir = '/opt/bin'; $env.PATH = ($env.PATH | prepend $dir)",
+        r"let dir = '/opt/bin'; path add $dir",
+    );
+}
+
+#[test]
+fn fix_windows_path() {
+    RULE.assert_fixed_is(
+        r"$env.Path = ($env.Path | prepend 'C:\tools')",
+        r"path add 'C:\tools'",
+    );
+}
@@ -0,0 +1,35 @@
+use super::RULE;
+
+#[test]
+fn ignores_uniq_after_append() {
+    RULE.assert_ignores(r"$env.PATH = ($env.PATH | append '/opt/bin' | uniq)");
+}
+
+#[test]
+fn ignores_membership_check() {
+    let good_code = r"
+if '/opt/bin' not-in $env.PATH {
+    $env.PATH = ($env.PATH | append '/opt/bin')
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_path_add() {
+    let good_code = r"
+use std/util 'path add'
+path add '/opt/bin'
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_other_env_variables() {
+    RULE.assert_ignores(r"$env.NU_LIB_DIRS = ($env.NU_LIB_DIRS | append '/opt/lib')");
+}
+
+#[test]
+fn ignores_full_replacement() {
+    RULE.assert_ignores(r"$env.PATH = ['/usr/bin' '/bin']");
+}
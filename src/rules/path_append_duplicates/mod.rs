@@ -0,0 +1,202 @@
+use nu_protocol::{
+    ENV_VARIABLE_ID, Span,
+    ast::{Argument, Assignment, Expr, Expression, Operator, PathMember},
+};
+
+use crate::{
+    LintLevel,
+    ast::{call::CallExt, expression::ExpressionExt, span::SpanExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    assignment_span: Span,
+    replacement: String,
+}
+
+/// Whether the expression is `$env.PATH` (or `$env.Path` on Windows)
+fn is_env_path(expr: &Expression) -> bool {
+    let Expr::FullCellPath(cell_path) = &expr.expr else {
+        return false;
+    };
+    matches!(&cell_path.head.expr, Expr::Var(var_id) if *var_id == ENV_VARIABLE_ID)
+        && matches!(
+            cell_path.tail.as_slice(),
+            [PathMember::String { val, .. }] if val == "PATH" || val == "Path"
+        )
+}
+
+/// Span of the `append`/`prepend` stage in `$env.PATH | append $dir`, unless
+/// the pipeline also deduplicates with `uniq`
+fn unguarded_extension(rhs: &Expression, context: &LintContext) -> Option<Span> {
+    let (Expr::Subexpression(block_id) | Expr::Block(block_id)) = &rhs.expr else {
+        return None;
+    };
+    let block = context.working_set.get_block(*block_id);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return None;
+    };
+    let (first, rest) = pipeline.elements.split_first()?;
+    if !is_env_path(&first.expr) {
+        return None;
+    }
+
+    let calls: Vec<_> = rest
+        .iter()
+        .filter_map(|element| match &element.expr.expr {
+            Expr::Call(call) => Some((call, element.expr.span)),
+            _ => None,
+        })
+        .collect();
+    if calls
+        .iter()
+        .any(|(call, _)| call.is_call_to_command("uniq", context))
+    {
+        return None;
+    }
+    calls.iter().find_map(|(call, span)| {
+        (call.is_call_to_command("append", context) || call.is_call_to_command("prepend", context))
+            .then_some(*span)
+    })
+}
+
+/// `path add` call equivalent to `$env.PATH | append X` or `prepend X`, when
+/// `X` is a single literal or variable
+fn path_add_replacement(rhs: &Expression, context: &LintContext) -> Option<String> {
+    let (Expr::Subexpression(block_id) | Expr::Block(block_id)) = &rhs.expr else {
+        return None;
+    };
+    let block = context.working_set.get_block(*block_id);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return None;
+    };
+    let [_, stage] = pipeline.elements.as_slice() else {
+        return None;
+    };
+    let Expr::Call(call) = &stage.expr.expr else {
+        return None;
+    };
+    let [Argument::Positional(dir)] = call.arguments.as_slice() else {
+        return None;
+    };
+    let is_simple = matches!(
+        dir.expr,
+        Expr::String(_) | Expr::RawString(_) | Expr::Filepath(..) | Expr::Directory(..)
+    ) || dir.extract_direct_var().is_some();
+    if !is_simple {
+        return None;
+    }
+
+    let dir = context.span_text(dir.span);
+    if call.is_call_to_command("append", context) {
+        Some(format!("path add --append {dir}"))
+    } else if call.is_call_to_command("prepend", context) {
+        Some(format!("path add {dir}"))
+    } else {
+        None
+    }
+}
+
+fn check_assignment(
+    expr: &Expression,
+    guarded_spans: &[Span],
+    context: &LintContext,
+) -> Option<(Detection, Option<FixData>)> {
+    let Expr::BinaryOp(lhs, op, rhs) = &expr.expr else {
+        return None;
+    };
+    if !is_env_path(lhs) || expr.span.is_inside_any(guarded_spans) {
+        return None;
+    }
+
+    let (extension_span, replacement) = match &op.expr {
+        Expr::Operator(Operator::Assignment(Assignment::Assign)) => (
+            unguarded_extension(rhs, context)?,
+            path_add_replacement(rhs, context),
+        ),
+        Expr::Operator(Operator::Assignment(Assignment::ConcatenateAssign)) => (rhs.span, None),
+        _ => return None,
+    };
+
+    let detection = Detection::from_global_span(
+        "Extending `$env.PATH` unconditionally adds a duplicate entry every time this runs; use \
+         `path add` from `std/util`, which skips existing entries",
+        expr.span,
+    )
+    .with_primary_label("may add duplicate entries")
+    .with_extra_label("not deduplicated", extension_span);
+    let fix_data = replacement.map(|replacement| FixData {
+        assignment_span: expr.span,
+        replacement,
+    });
+    Some((detection, fix_data))
+}
+
+struct PathAppendDuplicates;
+
+impl DetectFix for PathAppendDuplicates {
+    type FixInput<'a> = Option<FixData>;
+
+    fn id(&self) -> &'static str {
+        "path_append_duplicates"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`$env.PATH` extended without skipping existing entries"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`$env.PATH = ($env.PATH | append $dir)` adds `$dir` again whenever the config or \
+             script is sourced, so `PATH` slowly fills with duplicates. `path add` from \
+             `std/util` only adds missing directories. Pipelines that end in `uniq` and \
+             assignments inside an `if` (for example `if $dir not-in $env.PATH`) are not \
+             reported. When a single directory is appended or prepended, the fix rewrites the \
+             assignment to `path add`, which still has to be imported with `use std/util \"path \
+             add\"`.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/configuration.html#path-configuration")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let guarded_spans = context.collect_command_spans(&["if"]);
+        context.detect_with_fix_data(|expr, ctx| {
+            check_assignment(expr, &guarded_spans, ctx)
+                .into_iter()
+                .collect()
+        })
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let fix_data = fix_data.as_ref()?;
+        Some(Fix {
+            explanation: format!(
+                "Replace with `{}` (requires `use std/util \"path add\"`)",
+                fix_data.replacement
+            )
+            .into(),
+            replacements: vec![Replacement::new(
+                fix_data.assignment_span,
+                fix_data.replacement.clone(),
+            )],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &PathAppendDuplicates;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
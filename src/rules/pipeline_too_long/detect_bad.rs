@@ -0,0 +1,29 @@
+use super::RULE;
+use crate::{Config, config::PipelineLengthLimit};
+
+#[test]
+fn detects_pipeline_over_default_limit() {
+    RULE.assert_detects(
+        "ls | where size > 1kb | sort-by size | reverse | first 10 | get name | str upcase | str \
+         trim | uniq",
+    );
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r"
+def report [] {
+    open data.csv | where ok | select a b | sort-by a | uniq | first 5 | to md | str trim | lines
+}
+";
+    RULE.assert_count(bad_code, 1);
+}
+
+#[test]
+fn detects_with_lower_configured_limit() {
+    let config = Config {
+        pipeline_too_long: PipelineLengthLimit { max_elements: 2 },
+        ..Config::default()
+    };
+    RULE.assert_detects_with_config(r"ls | get name | first", &config);
+}
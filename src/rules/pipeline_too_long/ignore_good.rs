@@ -0,0 +1,27 @@
+use super::RULE;
+use crate::{Config, config::PipelineLengthLimit};
+
+#[test]
+fn ignores_short_pipeline() {
+    RULE.assert_ignores(r"ls | where size > 1kb | get name");
+}
+
+#[test]
+fn ignores_pipeline_at_limit() {
+    RULE.assert_ignores(
+        "ls | where size > 1kb | sort-by size | reverse | first 10 | get name | str upcase | uniq",
+    );
+}
+
+#[test]
+fn ignores_with_higher_configured_limit() {
+    let config = Config {
+        pipeline_too_long: PipelineLengthLimit { max_elements: 20 },
+        ..Config::default()
+    };
+    RULE.assert_ignores_with_config(
+        "ls | where size > 1kb | sort-by size | reverse | first 10 | get name | str upcase | str \
+         trim | uniq",
+        &config,
+    );
+}
@@ -0,0 +1,73 @@
+use nu_protocol::{Span, ast::Pipeline};
+
+use crate::{
+    LintLevel,
+    ast::block::BlockExt,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    let max_elements = context.config.pipeline_too_long.max_elements;
+    let count = pipeline.elements.len();
+    let (Some(first), Some(last)) = (pipeline.elements.first(), pipeline.elements.last()) else {
+        return vec![];
+    };
+    if count <= max_elements {
+        return vec![];
+    }
+
+    vec![
+        Detection::from_global_span(
+            format!(
+                "Pipeline has {count} elements, more than the maximum of {max_elements}; extract \
+                 part of it into a named command or an intermediate `let`"
+            ),
+            Span::new(first.expr.span.start, last.expr.span.end),
+        )
+        .with_primary_label(format!("{count} elements")),
+    ]
+}
+
+struct PipelineTooLong;
+
+impl DetectFix for PipelineTooLong {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "pipeline_too_long"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Pipeline with too many elements"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Long pipelines are hard to follow and to debug, because the data between the stages \
+             can't be inspected. Giving a group of stages a name, as a custom command or as a \
+             variable holding an intermediate result, documents what each part does. The limit is \
+             set with `pipeline_too_long.max_elements` in the configuration file.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/pipelines.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.ast.detect_in_pipelines(context, check_pipeline))
+    }
+}
+
+pub static RULE: &dyn Rule = &PipelineTooLong;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
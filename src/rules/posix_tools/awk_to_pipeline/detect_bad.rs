@@ -112,3 +112,24 @@ ls | each { |file|
 "#;
     RULE.assert_detects(bad_code);
 }
+
+#[test]
+fn detects_awk_with_condition_without_fix() {
+    let bad_code = r#"^awk '$3 > 100 {print $1}' sales.txt"#;
+    RULE.assert_detects(bad_code);
+    RULE.assert_no_fix(bad_code);
+}
+
+#[test]
+fn detects_awk_condition_only_without_fix() {
+    let bad_code = r#"^awk 'NR > 1' data.csv"#;
+    RULE.assert_detects(bad_code);
+    RULE.assert_no_fix(bad_code);
+}
+
+#[test]
+fn detects_awk_with_multiple_statements_without_fix() {
+    let bad_code = r#"^awk 'BEGIN {sum = 0} {sum += $2} END {print sum}' data.txt"#;
+    RULE.assert_detects(bad_code);
+    RULE.assert_no_fix(bad_code);
+}
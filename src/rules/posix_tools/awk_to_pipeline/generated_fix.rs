@@ -128,3 +128,18 @@ fn fix_combined_pattern_and_field() {
     RULE.assert_fixed_contains(source, r#"where $it =~ "error""#);
     RULE.assert_fixed_contains(source, "get column3");
 }
+
+#[test]
+fn fix_awk_print_whole_line() {
+    let source = r#"^awk '/error/ {print $0}' logs.txt"#;
+    RULE.assert_fixed_is(
+        source,
+        r#"open --raw logs.txt | lines | where $it =~ "error""#,
+    );
+}
+
+#[test]
+fn fix_awk_print_multiple_fields() {
+    let source = r#"^awk -F, '{print $1, $3}' data.csv"#;
+    RULE.assert_fixed_contains(source, "split column , | select column1 column3");
+}
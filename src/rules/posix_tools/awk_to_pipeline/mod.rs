@@ -10,6 +10,10 @@ const NOTE: &str = "Use 'where' for filtering rows, 'split column' for field ext
                     structured data pipelines replace awk's text-based approach with typed \
                     columns and native operations.";
 
+const NO_FIX_NOTE: &str = "This awk program uses conditions or several statements. Rewrite it \
+                           with 'lines', 'split column', 'where' and 'each', or use 'parse' to \
+                           extract fields by pattern.";
+
 #[derive(Default)]
 struct AwkOptions {
     field_separator: Option<String>,
@@ -18,6 +22,10 @@ struct AwkOptions {
     files: Vec<String>,
     nf_referenced: bool,
     nr_referenced: bool,
+    /// The program has no field print to convert, such as a condition or
+    /// several statements
+    unsupported: bool,
+    has_program: bool,
 }
 
 impl AwkOptions {
@@ -41,11 +49,11 @@ impl AwkOptions {
                 "-v" | "-f" => {
                     i += 1; // Skip next argument
                 }
-                // Patterns with / delimiters or braces are programs
-                s if s.starts_with('/') || s.contains('{') => {
+                // The first argument that's not a flag is the program, the rest are files
+                s if !s.starts_with('-') && !opts.has_program => {
+                    opts.has_program = true;
                     opts.parse_program(s);
                 }
-                // Everything else that's not a flag is a file
                 s if !s.starts_with('-') => {
                     opts.files.push(s.to_string());
                 }
@@ -60,39 +68,46 @@ impl AwkOptions {
     fn parse_program(&mut self, program: &str) {
         let p = program.trim();
 
-        if let Some((start, end)) = p
-            .find('/')
-            .and_then(|s| p[s + 1..].find('/').map(|er| (s, s + 1 + er)))
-        {
-            self.pattern = Some(p[start + 1..end].to_string());
-        }
-
-        let body = p
-            .trim_start_matches(|c: char| c == '{' || c.is_whitespace())
-            .trim_end_matches(|c: char| c == '}' || c.is_whitespace());
-
-        self.extract_print_fields(body);
+        // A regex pattern may contain braces itself, as in `/a{2}/`
+        let regex = p.strip_prefix('/').and_then(|rest| rest.split_once('/'));
+        let action = if let Some((pat, rest)) = regex {
+            self.pattern = Some(pat.to_string());
+            rest
+        } else {
+            let (condition, action) = p.split_once('{').unwrap_or((p, ""));
+            if !condition.trim().is_empty() {
+                self.unsupported = true;
+            }
+            action
+        };
 
-        if body.contains("NF") {
-            self.nf_referenced = true;
-        }
-        if body.contains("NR") {
-            self.nr_referenced = true;
+        let body = action
+            .trim()
+            .trim_start_matches('{')
+            .trim_end_matches('}')
+            .trim();
+        if !body.is_empty() {
+            self.parse_print(body);
         }
     }
 
-    fn extract_print_fields(&mut self, body: &str) {
-        let mut idx = 0;
-        while let Some(pos) = body[idx..].find("print $") {
-            let field_start = idx + pos + 7;
-            let digits: String = body[field_start..]
-                .chars()
-                .take_while(char::is_ascii_digit)
-                .collect();
-            if let Ok(n) = digits.parse::<usize>() {
-                self.add_field_if_valid(n);
+    /// Parse a single `print` statement of fields, `NR` and `NF`
+    fn parse_print(&mut self, body: &str) {
+        let Some(args) = body.strip_prefix("print") else {
+            self.unsupported = true;
+            return;
+        };
+
+        for arg in args.split([',', ' ']).filter(|arg| !arg.is_empty()) {
+            match arg {
+                "NR" => self.nr_referenced = true,
+                "NF" => self.nf_referenced = true,
+                "$0" => {}
+                _ => match arg.strip_prefix('$').and_then(|n| n.parse::<usize>().ok()) {
+                    Some(n) => self.add_field_if_valid(n),
+                    None => self.unsupported = true,
+                },
             }
-            idx = field_start + digits.len();
         }
     }
 
@@ -128,7 +143,8 @@ impl AwkOptions {
             examples.push("NF: use '($row | columns | length)' for field count".to_string());
         }
 
-        if parts.len() == 1 {
+        // Without a program, awk prints every line
+        if parts.len() == 1 && !self.has_program {
             parts.push("each {|line| $line}".to_string());
         }
 
@@ -192,6 +208,16 @@ impl DetectFix for UseBuiltinAwk {
         "`awk` replaceable with structured pipeline"
     }
 
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Programs that print fields, such as '{print $2}' or \"-F: '/root/ {print $1}'\", are \
+             converted to 'lines | split column SEP | get columnN', where '$0' is the whole line. \
+             Programs with conditions or several statements are reported without a fix. For \
+             extracting fields by pattern, 'parse' is often clearer than splitting, see the \
+             'lines_each_to_parse' rule.",
+        )
+    }
+
     fn source_link(&self) -> Option<&'static str> {
         Some("https://www.nushell.sh/book/coming_from_bash.html")
     }
@@ -202,22 +228,12 @@ impl DetectFix for UseBuiltinAwk {
 
     fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
         let validator = |_cmd: &str, fix_data: &ExternalCmdFixData, ctx: &LintContext| {
-            // Only detect simple awk patterns that we can reliably translate
-            // Don't detect if there are multiple statements, functions, or complex control
-            // flow
-            let has_complex_features = fix_data.arg_texts(ctx).any(|text| {
-                text.contains("BEGIN") ||
-                text.contains("END") ||
-                text.contains("function") ||
-                text.contains("for") ||
-                text.contains("while") ||
-                text.contains("if") && text.matches("if").count() > 1 || // Multiple conditionals
-                text.contains(';') && text.matches(';').count() > 1 ||    // Multiple statements
-                text.starts_with("-f") || text == "-f" ||                // External script file
-                text.starts_with("-v") && text.contains('=') // Complex variable assignments
-            });
-            if has_complex_features {
+            // Programs in a script file can't be inspected
+            let has_script_file = fix_data.arg_texts(ctx).any(|text| text.starts_with("-f"));
+            if has_script_file {
                 None
+            } else if AwkOptions::parse(fix_data.arg_texts(ctx)).unsupported {
+                Some(NO_FIX_NOTE)
             } else {
                 Some(NOTE)
             }
@@ -230,6 +246,9 @@ impl DetectFix for UseBuiltinAwk {
 
     fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
         let opts = AwkOptions::parse(fix_data.arg_texts(context));
+        if opts.unsupported {
+            return None;
+        }
         let (replacement, description) = opts.to_nushell();
 
         Some(Fix {
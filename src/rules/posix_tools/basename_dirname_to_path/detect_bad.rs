@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn detect_basename() {
+    RULE.assert_detects("^basename /tmp/report.txt");
+}
+
+#[test]
+fn detect_dirname() {
+    RULE.assert_detects("^dirname /tmp/report.txt");
+}
+
+#[test]
+fn detect_basename_with_suffix() {
+    RULE.assert_detects("^basename /tmp/report.txt .txt");
+}
+
+#[test]
+fn detect_both_in_function() {
+    let bad_code = r"
+def split-path [p: string] {
+    { dir: (^dirname $p), name: (^basename $p) }
+}
+";
+    RULE.assert_count(bad_code, 2);
+}
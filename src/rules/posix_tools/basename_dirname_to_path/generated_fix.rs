@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn fix_basename_literal() {
+    RULE.assert_fixed_is(
+        "^basename /tmp/report.txt",
+        "'/tmp/report.txt' | path basename",
+    );
+}
+
+#[test]
+fn fix_dirname_variable() {
+    RULE.assert_fixed_is(
+        "let p = 'a/b.txt'; ^dirname $p",
+        "let p = 'a/b.txt'; $p | path dirname",
+    );
+}
+
+#[test]
+fn fix_basename_quoted_path() {
+    RULE.assert_fixed_contains(
+        r#"^basename "my file.txt""#,
+        r#""my file.txt" | path basename"#,
+    );
+}
+
+#[test]
+fn fix_basename_strips_suffix() {
+    let source = "^basename $p .txt";
+    RULE.assert_fixed_contains(source, r"$p | path basename | str replace -r '\.txt$' ''");
+}
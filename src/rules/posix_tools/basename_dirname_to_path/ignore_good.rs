@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn ignore_builtin_path_basename() {
+    RULE.assert_ignores("'/tmp/report.txt' | path basename");
+}
+
+#[test]
+fn ignore_builtin_path_dirname() {
+    RULE.assert_ignores("$env.PWD | path dirname");
+}
+
+#[test]
+fn ignore_basename_multiple_flag() {
+    RULE.assert_ignores("^basename -a /tmp/a.txt /tmp/b.txt");
+}
+
+#[test]
+fn ignore_dirname_multiple_paths() {
+    RULE.assert_ignores("^dirname /tmp/a.txt /var/b.txt");
+}
@@ -0,0 +1,138 @@
+use nu_protocol::ast::{Expr, Expression};
+
+use crate::{
+    LintLevel,
+    context::{ExternalCmdFixData, LintContext},
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+const NOTE: &str = "Use Nu's 'path basename' or 'path dirname' on the path instead. 'path parse' \
+                    returns all components (parent, stem, extension) as a record.";
+
+/// Regex metacharacters that must be escaped in a suffix passed to
+/// `str replace -r`
+const REGEX_META: &[char] = &[
+    '.', '^', '$', '*', '+', '?', '(', ')', '[', ']', '{', '}', '|', '\\',
+];
+
+pub struct PathFixData<'a> {
+    /// The built-in replacement, `path basename` or `path dirname`
+    command: &'static str,
+    data: ExternalCmdFixData<'a>,
+}
+
+/// Only single-path calls without flags have a direct built-in equivalent.
+/// `basename` may take a suffix to strip as second argument.
+fn is_convertible(command: &str, fix_data: &ExternalCmdFixData, context: &LintContext) -> bool {
+    let max_args = if command == "basename" { 2 } else { 1 };
+    let arg_count = fix_data.args.len();
+
+    (1..=max_args).contains(&arg_count)
+        && !fix_data
+            .arg_texts(context)
+            .any(|text| text.starts_with('-'))
+        && fix_data.args.get(1).is_none_or(|suffix| {
+            matches!(
+                &suffix.expr,
+                Expr::String(_) | Expr::RawString(_) | Expr::GlobPattern(..)
+            )
+        })
+}
+
+/// Source text of the path, quoted when it was a bare word so it can start a
+/// pipeline
+fn path_input(expr: &Expression, context: &LintContext) -> String {
+    let text = context.expr_text(expr);
+    match &expr.expr {
+        Expr::String(s) | Expr::GlobPattern(s, _) | Expr::Filepath(s, _)
+            if !text.starts_with(['"', '\'', '`']) =>
+        {
+            format!("'{s}'")
+        }
+        _ => text.to_string(),
+    }
+}
+
+fn escape_regex(suffix: &str) -> String {
+    suffix
+        .chars()
+        .fold(String::with_capacity(suffix.len()), |mut escaped, ch| {
+            if REGEX_META.contains(&ch) {
+                escaped.push('\\');
+            }
+            escaped.push(ch);
+            escaped
+        })
+}
+
+struct UseBuiltinPathCommands;
+
+impl DetectFix for UseBuiltinPathCommands {
+    type FixInput<'a> = PathFixData<'a>;
+
+    fn id(&self) -> &'static str {
+        "basename_dirname_to_path"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`basename`/`dirname` replaceable with `path basename`/`path dirname`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "The external 'basename' and 'dirname' commands return text with a trailing newline. \
+             Nu's 'path basename' and 'path dirname' work on strings and lists of paths, and \
+             'path parse' splits a path into a record with parent, stem and extension.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/path_basename.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        [("basename", "path basename"), ("dirname", "path dirname")]
+            .into_iter()
+            .flat_map(|(external, command)| {
+                context
+                    .detect_external_with_validation(external, |cmd, fix_data, ctx| {
+                        is_convertible(cmd, fix_data, ctx).then_some(NOTE)
+                    })
+                    .into_iter()
+                    .map(move |(detection, data)| (detection, PathFixData { command, data }))
+            })
+            .collect()
+    }
+
+    fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let data = &fix_data.data;
+        let path = path_input(data.args.first()?, context);
+        let replacement = match data.arg_texts(context).nth(1) {
+            Some(suffix) => format!(
+                "{path} | {} | str replace -r '{}$' ''",
+                fix_data.command,
+                escape_regex(suffix)
+            ),
+            None => format!("{path} | {}", fix_data.command),
+        };
+
+        Some(Fix {
+            explanation: format!("Use '{}'", fix_data.command).into(),
+            replacements: vec![Replacement::new(data.expr_span, replacement)],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &UseBuiltinPathCommands;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
@@ -105,3 +105,15 @@ fn fix_preserves_filename() {
     RULE.assert_count(source, 1);
     RULE.assert_fixed_contains(source, "my-complex-filename.log");
 }
+
+#[test]
+fn fix_keeps_quotes_around_filename() {
+    let source = r#"^cat "my notes.txt""#;
+    RULE.assert_fixed_is(source, r#"open --raw "my notes.txt""#);
+}
+
+#[test]
+fn fix_only_replaces_cat_before_lines() {
+    let source = "^cat app.log | lines | last 10";
+    RULE.assert_fixed_is(source, "open --raw app.log | lines | last 10");
+}
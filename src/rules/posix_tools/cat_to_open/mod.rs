@@ -1,5 +1,6 @@
 use crate::{
     LintLevel,
+    ast::string::StringFormat,
     context::{ExternalCmdFixData, LintContext},
     rule::{DetectFix, Rule},
     violation::{Detection, Fix, Replacement},
@@ -20,24 +21,27 @@ struct CatOptions {
 }
 
 impl CatOptions {
-    fn parse<'a>(args: impl IntoIterator<Item = &'a str>) -> Self {
+    fn parse<'a>(args: impl IntoIterator<Item = (&'a str, Option<StringFormat>)>) -> Self {
         let mut opts = Self::default();
 
-        for arg in args {
-            Self::parse_arg(&mut opts, arg);
+        for (arg, format) in args {
+            Self::parse_arg(&mut opts, arg, format);
         }
 
         opts
     }
 
-    fn parse_arg(opts: &mut Self, arg: &str) {
+    fn parse_arg(opts: &mut Self, arg: &str, format: Option<StringFormat>) {
         match arg {
             "-n" | "--number" => opts.number_lines = true,
             "-b" | "--number-nonblank" => opts.number_nonblank = true,
             "-E" | "--show-ends" => opts.show_ends = true,
             "-T" | "--show-tabs" => opts.show_tabs = true,
             "-A" | "--show-all" => opts.show_all = true,
-            s if !s.starts_with('-') => opts.files.push(s.to_string()),
+            // Keep the original quotes, file names may contain spaces
+            s if !s.starts_with('-') => opts
+                .files
+                .push(format.map_or_else(|| s.to_string(), |fmt| fmt.reconstruct(fmt.content()))),
             _ => {}
         }
     }
@@ -178,7 +182,10 @@ impl DetectFix for UseBuiltinCat {
     }
 
     fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
-        let opts = CatOptions::parse(fix_data.arg_texts(context));
+        let args_with_formats = fix_data
+            .arg_texts(context)
+            .zip(fix_data.arg_formats(context));
+        let opts = CatOptions::parse(args_with_formats);
         let (replacement, description) = opts.to_nushell();
 
         Some(Fix {
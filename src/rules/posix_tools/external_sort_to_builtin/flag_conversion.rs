@@ -12,7 +12,7 @@ fn converts_numeric_flag() {
 
 #[test]
 fn converts_unique_flag() {
-    RULE.assert_fixed_contains("^sort -u", "sort");
+    RULE.assert_fixed_contains("^sort -u", "sort | uniq");
 }
 
 #[test]
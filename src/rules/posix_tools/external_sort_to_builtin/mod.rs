@@ -106,6 +106,11 @@ impl SortOptions {
             examples.push("reverse: use --reverse flag (same as Unix sort -r)".to_string());
         }
 
+        if self.unique {
+            parts.push("| uniq".to_string());
+            examples.push("unique: pipe to 'uniq' (same as Unix sort -u)".to_string());
+        }
+
         let replacement = parts.join(" ");
         let description = self.build_description(&examples);
 
@@ -119,14 +124,6 @@ impl SortOptions {
             parts.push(format!("Conversions: {}", examples.join("; ")));
         }
 
-        if self.unique {
-            parts.push(
-                "Note: -u flag for unique values should be handled separately with 'uniq' after \
-                 sorting."
-                    .to_string(),
-            );
-        }
-
         if self.ignore_case {
             parts.push(
                 "For case-insensitive sorting, pipe to 'str downcase' before sorting or use \
@@ -23,3 +23,10 @@ fn ignores_builtin_uniq_by() {
     let source = "ls | uniq-by name";
     RULE.assert_ignores(source);
 }
+
+#[test]
+fn replaces_uniq_after_external_sort() {
+    let source = "open names.txt | lines | ^sort | ^uniq";
+    RULE.assert_count(source, 1);
+    RULE.assert_fixed_contains(source, "^sort | uniq");
+}
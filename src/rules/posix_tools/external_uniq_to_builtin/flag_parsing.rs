@@ -9,19 +9,19 @@ fn converts_count_flag() {
 #[test]
 fn converts_repeated_flag() {
     let source = "^uniq -d";
-    RULE.assert_fixed_contains(source, "uniq");
+    RULE.assert_fixed_contains(source, "uniq --repeated");
 }
 
 #[test]
 fn converts_unique_flag() {
     let source = "^uniq -u";
-    RULE.assert_fixed_contains(source, "uniq");
+    RULE.assert_fixed_contains(source, "uniq --unique");
 }
 
 #[test]
 fn converts_ignore_case_flag() {
     let source = "^uniq -i";
-    RULE.assert_fixed_contains(source, "uniq");
+    RULE.assert_fixed_contains(source, "uniq --ignore-case");
 }
 
 #[test]
@@ -33,5 +33,5 @@ fn converts_skip_fields_flag() {
 #[test]
 fn combines_count_with_other_flags() {
     let source = "^uniq -ci";
-    RULE.assert_fixed_contains(source, "uniq --count");
+    RULE.assert_fixed_contains(source, "uniq --count --ignore-case");
 }
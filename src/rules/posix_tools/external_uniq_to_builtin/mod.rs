@@ -1,5 +1,13 @@
+use std::collections::HashSet;
+
+use nu_protocol::{
+    Span,
+    ast::{Expr, Expression, Pipeline},
+};
+
 use crate::{
     LintLevel,
+    ast::block::BlockExt,
     context::{ExternalCmdFixData, LintContext},
     rule::{DetectFix, Rule},
     violation::{Detection, Fix, Replacement},
@@ -9,6 +17,27 @@ const NOTE: &str = "Use Nu's 'uniq' for removing duplicates, 'uniq-by' for colum
                     deduplication. Nu's uniq works on structured data and provides --count flag \
                     for counting occurrences.";
 
+const AFTER_SORT_NOTE: &str = "Nu's 'uniq' removes all duplicates, not only adjacent ones, so the \
+                               'sort' in front of it is only needed when the output should be \
+                               sorted.";
+
+fn is_external(expr: &Expression, name: &str, context: &LintContext) -> bool {
+    matches!(&expr.expr, Expr::ExternalCall(head, _) if context.expr_text(head) == name)
+}
+
+/// External `uniq` calls directly following an external `sort`
+fn uniq_after_sort(pipeline: &Pipeline, context: &LintContext) -> Vec<Span> {
+    pipeline
+        .elements
+        .windows(2)
+        .filter(|pair| {
+            is_external(&pair[0].expr, "sort", context)
+                && is_external(&pair[1].expr, "uniq", context)
+        })
+        .map(|pair| pair[1].expr.span)
+        .collect()
+}
+
 /// Parse uniq command arguments to extract key options
 #[derive(Default)]
 
@@ -71,21 +100,18 @@ impl UniqOptions {
         }
 
         if self.repeated {
-            examples.push(
-                "repeated only: use 'uniq --count | where count > 1' to show only duplicates"
-                    .to_string(),
-            );
+            parts.push("--repeated".to_string());
+            examples.push("repeated only: use --repeated flag (same as Unix uniq -d)".to_string());
         }
 
         if self.unique {
-            examples.push(
-                "unique only: use 'uniq --count | where count == 1' to show only unique items"
-                    .to_string(),
-            );
+            parts.push("--unique".to_string());
+            examples.push("unique only: use --unique flag (same as Unix uniq -u)".to_string());
         }
 
         if self.ignore_case {
-            examples.push("case-insensitive: pipe to 'str downcase' before 'uniq'".to_string());
+            parts.push("--ignore-case".to_string());
+            examples.push("case-insensitive: use --ignore-case flag".to_string());
         }
 
         if self.skip_fields.is_some() {
@@ -114,13 +140,6 @@ impl UniqOptions {
             );
         }
 
-        if self.ignore_case {
-            parts.push(
-                "For case-insensitive uniqueness, pipe to 'str downcase' before 'uniq'."
-                    .to_string(),
-            );
-        }
-
         parts.push(
             "Nu's uniq integrates with structured data, enabling operations like 'uniq-by' for \
              specific columns."
@@ -153,6 +172,11 @@ impl DetectFix for UseBuiltinUniq {
     }
 
     fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let after_sort: HashSet<Span> = context
+            .ast
+            .detect_in_pipelines(context, uniq_after_sort)
+            .into_iter()
+            .collect();
         context.detect_external_with_validation("uniq", |_, fix_data, ctx| {
             // Only exclude very complex uniq options
             let has_very_complex = fix_data.arg_texts(ctx).any(|text| {
@@ -162,7 +186,13 @@ impl DetectFix for UseBuiltinUniq {
                     "--group" // Group adjacent duplicates
                 )
             });
-            if has_very_complex { None } else { Some(NOTE) }
+            if has_very_complex {
+                None
+            } else if after_sort.contains(&fix_data.expr_span) {
+                Some(AFTER_SORT_NOTE)
+            } else {
+                Some(NOTE)
+            }
         })
     }
 
@@ -62,7 +62,18 @@ fn fix_grep_fixed_strings() {
 fn fix_grep_recursive() {
     let source = r#"^grep -r "TODO" ."#;
     RULE.assert_count(source, 1);
-    RULE.assert_fixed_contains(source, r#"open . | lines | where $it =~ "TODO""#);
+    RULE.assert_fixed_contains(
+        source,
+        r#"glob "**/*" --no-dir | each {|file| open --raw $file"#,
+    );
+    RULE.assert_fixed_contains(source, r#"lines | where $it =~ "TODO" } | flatten"#);
+}
+
+#[test]
+fn fix_grep_recursive_in_directory() {
+    let source = r#"^grep -rn "TODO" src/"#;
+    RULE.assert_fixed_contains(source, r#"glob "src/**/*" --no-dir"#);
+    RULE.assert_fixed_contains(source, r#"lines | enumerate | where $it =~ "TODO""#);
 }
 
 #[test]
@@ -9,6 +9,10 @@ const NOTE: &str = "Use 'find' for simple text search (case-insensitive by defau
                     =~ pattern' for regex filtering, or 'lines | where' for line-based filtering \
                     with structured data operations.";
 
+const RECURSIVE_NOTE: &str = "Use 'glob' to list the files below a directory and filter the lines \
+                              of each one, for example 'glob **/* --no-dir | each {|file| open \
+                              --raw $file | lines | where $it =~ pattern } | flatten'.";
+
 #[derive(Default)]
 struct GrepFlags {
     case_insensitive: bool,
@@ -111,16 +115,33 @@ impl GrepOptions {
     fn build_where_replacement(&self, pattern: &str) -> (String, String) {
         let (filter_expr, examples) = self.build_where_filter(pattern);
 
-        let replacement = if self.files.is_empty() {
+        let replacement = if self.recursive {
+            format!(
+                "glob \"{}\" --no-dir | each {{|file| open --raw $file | lines | {filter_expr} }} \
+                 | flatten",
+                self.recursive_glob()
+            )
+        } else if self.files.is_empty() {
             format!("lines | {filter_expr}")
         } else {
             format!("open {} | lines | {filter_expr}", self.files.join(" "))
         };
 
-        let description = self.build_where_description(pattern, &examples);
+        let mut description = self.build_where_description(pattern, &examples);
+        if self.recursive {
+            description.push_str(" Recursive search becomes 'glob' over the directory.");
+        }
         (replacement, description)
     }
 
+    /// Glob matching every file below the directory searched by `grep -r`
+    fn recursive_glob(&self) -> String {
+        match self.files.first().map(|dir| dir.trim_end_matches('/')) {
+            None | Some("." | "") => "**/*".to_string(),
+            Some(dir) => format!("{dir}/**/*"),
+        }
+    }
+
     fn build_find_description(&self, pattern: &str) -> String {
         let mut parts = vec![format!(
             "Use 'find \"{}\"' for simple text search.",
@@ -242,7 +263,13 @@ impl DetectFix for UseBuiltinGrep {
                 ) || text.starts_with("--include")
                     || text.starts_with("--exclude")
             });
-            if has_very_complex { None } else { Some(NOTE) }
+            if has_very_complex {
+                None
+            } else if GrepOptions::parse(fix_data.arg_texts(ctx)).recursive {
+                Some(RECURSIVE_NOTE)
+            } else {
+                Some(NOTE)
+            }
         };
         let mut violations = context.detect_external_with_validation("grep", validator);
         violations.extend(context.detect_external_with_validation("rg", validator));
@@ -251,6 +278,10 @@ impl DetectFix for UseBuiltinGrep {
 
     fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
         let opts = GrepOptions::parse(fix_data.arg_texts(context));
+        // Several search roots would need one glob each
+        if opts.recursive && opts.files.len() > 1 {
+            return None;
+        }
         let (replacement, description) = opts.to_nushell();
 
         Some(Fix {
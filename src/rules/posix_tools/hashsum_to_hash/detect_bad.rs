@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn detect_md5sum_file() {
+    RULE.assert_detects("^md5sum archive.tar.gz");
+}
+
+#[test]
+fn detect_sha256sum_file() {
+    RULE.assert_detects("^sha256sum archive.tar.gz");
+}
+
+#[test]
+fn detect_sha256sum_piped() {
+    RULE.assert_detects("'hello' | ^sha256sum");
+}
+
+#[test]
+fn detect_binary_mode_flag() {
+    RULE.assert_detects("^sha256sum -b archive.tar.gz");
+}
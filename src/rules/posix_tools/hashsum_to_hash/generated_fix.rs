@@ -0,0 +1,27 @@
+use super::RULE;
+
+#[test]
+fn fix_md5sum_file() {
+    RULE.assert_fixed_is(
+        "^md5sum archive.tar.gz",
+        "open --raw archive.tar.gz | hash md5",
+    );
+}
+
+#[test]
+fn fix_sha256sum_quoted_file() {
+    RULE.assert_fixed_is(
+        r#"^sha256sum "my file.iso""#,
+        r#"open --raw "my file.iso" | hash sha256"#,
+    );
+}
+
+#[test]
+fn fix_sha256sum_piped() {
+    RULE.assert_fixed_is("'hello' | ^sha256sum", "'hello' | hash sha256");
+}
+
+#[test]
+fn fix_echo_n_piped() {
+    RULE.assert_fixed_is(r#"^echo -n "x" | ^sha256sum"#, r#""x" | hash sha256"#);
+}
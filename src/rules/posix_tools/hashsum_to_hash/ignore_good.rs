@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn ignore_builtin_hash() {
+    RULE.assert_ignores("open --raw archive.tar.gz | hash sha256");
+}
+
+#[test]
+fn ignore_sha512sum_without_builtin() {
+    RULE.assert_ignores("^sha512sum archive.tar.gz");
+}
+
+#[test]
+fn ignore_check_mode() {
+    RULE.assert_ignores("^sha256sum --check SHA256SUMS");
+}
+
+#[test]
+fn ignore_multiple_files() {
+    RULE.assert_ignores("^md5sum a.txt b.txt");
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use nu_protocol::{
+    Span,
+    ast::{Expr, ExternalArgument, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, string::StringFormat},
+    context::{ExternalCmdFixData, LintContext},
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+const NOTE: &str = "Use Nu's 'hash' commands. They return only the hex digest instead of \
+                    '<digest>  <file>' lines, so no parsing of the output is needed.";
+
+/// External checksum tools that have a built-in `hash` equivalent. Nu has no
+/// built-in for SHA-1 or SHA-512.
+const HASH_TOOLS: &[(&str, &str)] = &[("md5sum", "hash md5"), ("sha256sum", "hash sha256")];
+
+/// Flags that only select how the file is read, which `open --raw` makes
+/// irrelevant
+const MODE_FLAGS: &[&str] = &["-b", "--binary", "-t", "--text"];
+
+pub struct HashFixData<'a> {
+    /// The built-in replacement, such as `hash sha256`
+    command: &'static str,
+    data: ExternalCmdFixData<'a>,
+    /// `^echo -n TEXT` piped into the tool, replaced by `TEXT`
+    echo: Option<(Span, String)>,
+}
+
+/// Arguments that are not read mode flags
+fn file_args<'b>(
+    fix_data: &'b ExternalCmdFixData,
+    context: &'b LintContext,
+) -> impl Iterator<Item = (&'b str, Option<StringFormat>)> {
+    fix_data
+        .arg_texts(context)
+        .zip(fix_data.arg_formats(context))
+        .filter(|(text, _)| !MODE_FLAGS.contains(text))
+}
+
+/// At most one file and no flags that change the output, like `--check`
+fn is_convertible(fix_data: &ExternalCmdFixData, context: &LintContext) -> bool {
+    let mut files = file_args(fix_data, context);
+    files.next().is_none_or(|(text, _)| !text.starts_with('-')) && files.next().is_none()
+}
+
+/// `^echo -n TEXT | ^tool`: maps the span of the tool call to the span of
+/// the echo call and the text it prints
+fn echo_inputs(pipeline: &Pipeline, context: &LintContext) -> Vec<(Span, (Span, String))> {
+    pipeline
+        .elements
+        .windows(2)
+        .filter_map(|pair| {
+            let Expr::ExternalCall(head, args) = &pair[0].expr.expr else {
+                return None;
+            };
+            let [
+                ExternalArgument::Regular(flag),
+                ExternalArgument::Regular(text),
+            ] = args.as_ref()
+            else {
+                return None;
+            };
+            let is_echo_n = context.expr_text(head) == "echo" && context.expr_text(flag) == "-n";
+            let feeds_tool = matches!(
+                &pair[1].expr.expr,
+                Expr::ExternalCall(tool, _)
+                    if HASH_TOOLS.iter().any(|(name, _)| context.expr_text(tool) == *name)
+            );
+            (is_echo_n && feeds_tool).then(|| {
+                (
+                    pair[1].expr.span,
+                    (pair[0].expr.span, context.expr_text(text).to_string()),
+                )
+            })
+        })
+        .collect()
+}
+
+struct UseBuiltinHash;
+
+impl DetectFix for UseBuiltinHash {
+    type FixInput<'a> = HashFixData<'a>;
+
+    fn id(&self) -> &'static str {
+        "hashsum_to_hash"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`md5sum`/`sha256sum` replaceable with `hash md5`/`hash sha256`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Files are read with 'open --raw', so the '--binary' and '--text' read modes of the \
+             external tools do not matter. Note that '--binary' on Nu's 'hash' commands means \
+             something else: it returns the digest as binary instead of a hex string. 'sha1sum' \
+             and 'sha512sum' have no built-in equivalent and are not reported.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/hash.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let echoes: HashMap<Span, (Span, String)> = context
+            .ast
+            .detect_in_pipelines(context, echo_inputs)
+            .into_iter()
+            .collect();
+        let echoes = &echoes;
+
+        HASH_TOOLS
+            .iter()
+            .flat_map(|&(external, command)| {
+                context
+                    .detect_external_with_validation(external, |_, fix_data, ctx| {
+                        is_convertible(fix_data, ctx).then_some(NOTE)
+                    })
+                    .into_iter()
+                    .map(move |(detection, data)| {
+                        let echo = echoes.get(&data.expr_span).cloned();
+                        (
+                            detection,
+                            HashFixData {
+                                command,
+                                data,
+                                echo,
+                            },
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let data = &fix_data.data;
+        let file = file_args(data, context)
+            .next()
+            .map(|(text, format)| format.map_or_else(|| text.to_string(), |f| f.reconstruct(text)));
+
+        let mut replacements = vec![Replacement::new(
+            data.expr_span,
+            file.map_or_else(
+                || fix_data.command.to_string(),
+                |file| format!("open --raw {file} | {}", fix_data.command),
+            ),
+        )];
+        if let Some((span, text)) = &fix_data.echo {
+            replacements.push(Replacement::new(*span, text.clone()));
+        }
+
+        Some(Fix {
+            explanation: format!("Use '{}'", fix_data.command).into(),
+            replacements,
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &UseBuiltinHash;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
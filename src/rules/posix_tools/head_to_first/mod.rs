@@ -1,8 +1,9 @@
 use nu_protocol::{
     Span,
-    ast::{Expr, ExternalArgument, Pipeline},
+    ast::{Expr, Pipeline},
 };
 
+use super::HeadTailOptions;
 use crate::{
     LintLevel,
     ast::{block::BlockExt, call::CallExt},
@@ -12,48 +13,23 @@ use crate::{
 };
 
 struct HeadFixData {
-    count: Option<isize>,
-    filename_span: Option<Span>,
-    combined_span: Span,
+    replacement: String,
+    /// The `head` call, together with a `lines` stage that directly follows
+    replaced_span: Span,
 }
 
 struct UseBuiltinHead;
 
-fn is_lines_arg(a: &ExternalArgument, ctx: &LintContext) -> bool {
-    let t = ctx.expr_text(a.expr());
-    t == "--lines" || t == "-n"
-}
-
-fn is_file_arg(a: &ExternalArgument, ctx: &LintContext) -> bool {
-    let t = ctx.expr_text(a.expr());
-    t.chars().next().is_some_and(|f| f != '-')
-}
-
 fn is_piped_to_lines(pipeline: &Pipeline, idx: usize, context: &LintContext) -> bool {
     pipeline.elements.get(idx + 1).is_some_and(|next| {
         matches!(&next.expr.expr, Expr::Call(call) if call.is_call_to_command("lines", context))
     })
 }
 
-fn extract_count(args: &[ExternalArgument], context: &LintContext) -> Option<isize> {
-    let count_idx = args.iter().position(|e| is_lines_arg(e, context))?;
-    if count_idx + 1 >= args.len() {
-        return None;
-    }
-    context
-        .expr_text(args[count_idx + 1].expr())
-        .parse::<isize>()
-        .ok()
-}
-
-fn extract_filename_span(args: &[ExternalArgument], context: &LintContext) -> Option<Span> {
-    args.iter()
-        .zip(args.iter().skip(1))
-        .find(|(prev, curr)| !is_lines_arg(prev, context) && is_file_arg(curr, context))
-        .map(|(_, file_arg)| file_arg.expr().span)
-}
-
-fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, HeadFixData)> {
+fn check_pipeline(
+    pipeline: &Pipeline,
+    context: &LintContext,
+) -> Vec<(Detection, Option<HeadFixData>)> {
     pipeline
         .elements
         .iter()
@@ -68,23 +44,22 @@ fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection,
                 return None;
             }
 
-            // Must be followed by `lines`
-            if !is_piped_to_lines(pipeline, idx, context) {
-                return None;
-            }
-
-            let count = extract_count(args, context);
-            let filename_span = extract_filename_span(args, context);
-
-            // Compute combined span from head to lines element
-            let lines_span = pipeline.elements[idx + 1].expr.span;
-            let combined_span = Span::new(element.expr.span.start, lines_span.end);
-
-            let fix_data = HeadFixData {
-                count,
-                filename_span,
-                combined_span,
-            };
+            let opts = HeadTailOptions::parse(args.iter().map(|arg| context.expr_text(arg.expr())));
+            let fix_data = opts.map(|opts| {
+                // An explicit `lines` after `head` is already part of the replacement
+                let replaced_span = if !opts.bytes && is_piped_to_lines(pipeline, idx, context) {
+                    Span::new(
+                        element.expr.span.start,
+                        pipeline.elements[idx + 1].expr.span.end,
+                    )
+                } else {
+                    element.expr.span
+                };
+                HeadFixData {
+                    replacement: opts.to_nushell("first"),
+                    replaced_span,
+                }
+            });
 
             let detection =
                 Detection::from_global_span("Use 'first N' to get the first N items", head.span)
@@ -96,7 +71,7 @@ fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection,
 }
 
 impl DetectFix for UseBuiltinHead {
-    type FixInput<'a> = HeadFixData;
+    type FixInput<'a> = Option<HeadFixData>;
 
     fn id(&self) -> &'static str {
         "head_to_first"
@@ -118,23 +93,14 @@ impl DetectFix for UseBuiltinHead {
         context.ast.detect_in_pipelines(context, check_pipeline)
     }
 
-    fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
-        let count = fix_data.count.unwrap_or(10);
-
-        let replacement = fix_data.filename_span.map_or_else(
-            || format!("lines | first {count}"),
-            |span| {
-                let filename = context.span_text(span);
-                format!("open {filename} | lines | first {count}")
-            },
-        );
-
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let fix_data = fix_data.as_ref()?;
         Some(Fix {
             explanation: "Use 'first' with cleaner syntax".into(),
-            replacements: vec![Replacement {
-                span: fix_data.combined_span.into(),
-                replacement_text: replacement.into(),
-            }],
+            replacements: vec![Replacement::new(
+                fix_data.replaced_span,
+                fix_data.replacement.clone(),
+            )],
         })
     }
 }
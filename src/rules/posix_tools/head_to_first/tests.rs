@@ -7,15 +7,44 @@ fn converts_head_with_count_to_first() {
 }
 
 #[test]
-fn do_not_recommend_invalid_head() {
+fn converts_bsd_style_count() {
     let source = "head -20 somefile.txt";
-    RULE.assert_ignores(source);
+    RULE.assert_fixed_is(source, "open somefile.txt | lines | first 20");
 }
 
 #[test]
-fn do_not_recommend_without_lines() {
+fn converts_head_without_lines() {
     let source = "head -n 20 somefile.txt";
-    RULE.assert_ignores(source);
+    RULE.assert_fixed_is(source, "open somefile.txt | lines | first 20");
+}
+
+#[test]
+fn converts_long_lines_option() {
+    let source = "^head --lines=3 notes.txt";
+    RULE.assert_fixed_is(source, "open notes.txt | lines | first 3");
+}
+
+#[test]
+fn converts_byte_count() {
+    let source = "^head -c 16 image.png";
+    RULE.assert_fixed_is(source, "open --raw image.png | into binary | first 16");
+}
+
+#[test]
+fn defaults_to_ten_lines() {
+    let source = "^head notes.txt";
+    RULE.assert_fixed_is(source, "open notes.txt | lines | first 10");
+}
+
+#[test]
+fn keeps_quoted_filename() {
+    let source = r#"^head -n 2 "my notes.txt""#;
+    RULE.assert_fixed_is(source, r#"open "my notes.txt" | lines | first 2"#);
+}
+
+#[test]
+fn detects_unsupported_flags() {
+    RULE.assert_detects("^head -q -n 2 a.txt b.txt");
 }
 
 #[test]
@@ -2,6 +2,7 @@
 //!  Less common commands are grouped in the 'other' subrule.
 
 pub mod awk_to_pipeline;
+pub mod basename_dirname_to_path;
 pub mod bat_to_open;
 pub mod cat_to_open;
 pub mod date_to_date_now;
@@ -11,6 +12,7 @@ pub mod external_ls_to_builtin;
 pub mod find_to_glob;
 pub mod free_to_sys_mem;
 pub mod grep_to_find_or_where;
+pub mod hashsum_to_hash;
 pub mod head_to_first;
 pub mod hostname_to_sys_host;
 pub mod pager_to_explore;
@@ -20,6 +22,7 @@ pub mod external_sort_to_builtin;
 pub mod external_uniq_to_builtin;
 pub mod read_to_input;
 pub mod sed_to_str_transform;
+pub mod seq_to_range;
 pub mod tac_to_reverse;
 pub mod tail_to_last;
 pub mod uname_to_sys_host;
@@ -28,3 +31,66 @@ pub mod users_to_sys_users;
 pub mod w_to_sys_users;
 pub mod wc_to_length;
 pub mod who_to_sys_users;
+pub mod xargs_to_each;
+
+/// Arguments of an external `head` or `tail` call, as source text
+#[derive(Default)]
+struct HeadTailOptions<'a> {
+    count: Option<&'a str>,
+    /// Count bytes (`-c`) instead of lines
+    bytes: bool,
+    /// `tail -f`, which keeps waiting for new lines
+    follow: bool,
+    file: Option<&'a str>,
+}
+
+impl<'a> HeadTailOptions<'a> {
+    /// Parse `-n N`, `--lines=N`, `-c N`, `--bytes=N`, the BSD style `-N`
+    /// and `-f`. Returns `None` for flags or counts without a built-in
+    /// equivalent, and for more than one file.
+    fn parse(args: impl IntoIterator<Item = &'a str>) -> Option<Self> {
+        let mut opts = Self::default();
+        let mut iter = args.into_iter();
+
+        while let Some(arg) = iter.next() {
+            match arg {
+                "-n" | "--lines" => opts.count = Some(iter.next()?),
+                "-c" | "--bytes" => {
+                    opts.bytes = true;
+                    opts.count = Some(iter.next()?);
+                }
+                "-f" | "-F" | "--follow" => opts.follow = true,
+                _ if arg.starts_with("--lines=") => {
+                    opts.count = arg.split_once('=').map(|(_, count)| count);
+                }
+                _ if arg.starts_with("--bytes=") => {
+                    opts.bytes = true;
+                    opts.count = arg.split_once('=').map(|(_, count)| count);
+                }
+                _ if arg.starts_with("-n") => opts.count = Some(&arg[2..]),
+                _ if arg.starts_with('-') => opts.count = Some(&arg[1..]),
+                _ if opts.file.is_none() => opts.file = Some(arg),
+                _ => return None,
+            }
+        }
+
+        // `tail -n +N` starts at line N, which `last` can't express
+        opts.count
+            .is_none_or(|count| !count.is_empty() && count.chars().all(|c| c.is_ascii_digit()))
+            .then_some(opts)
+    }
+
+    /// Built-in pipeline for the call, using `first` or `last`
+    fn to_nushell(&self, command: &str) -> String {
+        let count = self.count.unwrap_or("10");
+        let (source, conversion) = if self.bytes {
+            ("open --raw", "into binary")
+        } else {
+            ("open", "lines")
+        };
+        self.file.map_or_else(
+            || format!("{conversion} | {command} {count}"),
+            |file| format!("{source} {file} | {conversion} | {command} {count}"),
+        )
+    }
+}
@@ -41,3 +41,44 @@ fn test_detect_gsed() {
     let bad_code = r"^gsed 's/foo/bar/'";
     RULE.assert_detects(bad_code);
 }
+
+#[test]
+fn test_detect_sed_alternate_delimiter() {
+    let bad_code = r"^sed 's|/usr/local|/opt|g' paths.txt";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn test_detect_sed_delete_command_without_fix() {
+    let bad_code = "^sed '/pattern/d'";
+    RULE.assert_detects(bad_code);
+    RULE.assert_no_fix(bad_code);
+}
+
+#[test]
+fn test_detect_sed_print_command_without_fix() {
+    let bad_code = "^sed '/pattern/p'";
+    RULE.assert_detects(bad_code);
+    RULE.assert_no_fix(bad_code);
+}
+
+#[test]
+fn test_detect_sed_address_range_without_fix() {
+    let bad_code = "^sed '1,10s/old/new/'";
+    RULE.assert_detects(bad_code);
+    RULE.assert_no_fix(bad_code);
+}
+
+#[test]
+fn test_detect_sed_multiple_commands_without_fix() {
+    let bad_code = "^sed 's/a/b/;s/c/d/'";
+    RULE.assert_detects(bad_code);
+    RULE.assert_no_fix(bad_code);
+}
+
+#[test]
+fn test_detect_sed_quiet_mode_without_fix() {
+    let bad_code = "^sed -n 's/pattern/replacement/p'";
+    RULE.assert_detects(bad_code);
+    RULE.assert_no_fix(bad_code);
+}
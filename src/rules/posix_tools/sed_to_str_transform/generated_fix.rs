@@ -76,3 +76,36 @@ fn handles_escaped_quotes_in_replacement() {
     let bad_code = r#"^sed "s/old/\"new\"/" file.txt"#;
     RULE.assert_count(bad_code, 1);
 }
+
+#[test]
+fn test_fix_sed_alternate_delimiter() {
+    let bad_code = r"^sed 's|/usr/local|/opt|g' paths.txt";
+    RULE.assert_fixed_contains(
+        bad_code,
+        "open paths.txt | str replace --all '/usr/local' '/opt'",
+    );
+}
+
+#[test]
+fn test_fix_sed_escaped_delimiter() {
+    let bad_code = r"^sed 's/\/tmp/\/var\/tmp/'";
+    RULE.assert_fixed_contains(bad_code, "str replace '/tmp' '/var/tmp'");
+}
+
+#[test]
+fn test_fix_sed_basic_regex_to_rust_regex() {
+    let bad_code = r"^sed 's/\(foo\)+/\1-&/g'";
+    RULE.assert_fixed_contains(bad_code, r"str replace --all --regex '(foo)\+' '${1}-$0'");
+}
+
+#[test]
+fn test_fix_sed_ignore_case_flag() {
+    let bad_code = r"^sed 's/error/ERROR/gi'";
+    RULE.assert_fixed_contains(bad_code, "str replace --all --regex '(?i)error' 'ERROR'");
+}
+
+#[test]
+fn test_fix_sed_single_quote_uses_raw_string() {
+    let bad_code = r#"^sed "s/don't/do not/""#;
+    RULE.assert_fixed_contains(bad_code, "str replace r#'don't'# 'do not'");
+}
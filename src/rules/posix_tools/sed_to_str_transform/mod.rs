@@ -7,6 +7,17 @@ use crate::{
 
 const NOTE: &str = "Use 'str replace' for text substitution";
 
+const NO_FIX_NOTE: &str = "This sed program is not a single substitution. Use 'lines | where' to \
+                           keep or drop lines, 'str replace' for substitutions, or 'lines | skip' \
+                           and 'lines | first' for line ranges.";
+
+/// Characters with a special meaning in basic regular expressions
+const BRE_META: &[char] = &['.', '[', ']', '*', '^', '$', '\\'];
+
+/// Characters that are literal in basic but special in extended and Rust
+/// regular expressions, unless escaped
+const ERE_ONLY_META: &[char] = &['(', ')', '{', '}', '+', '?', '|'];
+
 struct UseBuiltinSed;
 
 impl DetectFix for UseBuiltinSed {
@@ -28,33 +39,28 @@ impl DetectFix for UseBuiltinSed {
         LintLevel::Warning
     }
 
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A single 's/PATTERN/REPLACEMENT/FLAGS' substitution is converted to 'str replace', \
+             with any delimiter. The 'g' flag becomes '--all' and the 'i' flag a '(?i)' prefix on \
+             the regex. Basic regular expressions are translated to the Rust regex syntax used by \
+             'str replace --regex', and '&' and '\\1' in the replacement become '$0' and '${1}'. \
+             Other sed programs, such as 'd', 'p' or address ranges, are reported without a fix.",
+        )
+    }
+
     fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
         let validator = |_cmd: &str, fix_data: &ExternalCmdFixData, ctx: &LintContext| {
-            // Only detect simple substitution patterns that str replace can handle
-            let has_unsupported = fix_data.arg_texts(ctx).any(|text| {
-                // External script file
-                text == "-f" || (text.starts_with("-f") && text.len() > 2) ||
-                // Non-substitution sed commands (looking for /d, /p, etc. patterns)
-                (text.contains('/') && (
-                    text.ends_with("/d") || text.ends_with("/p") ||
-                    text.ends_with("/a") || text.ends_with("/i") ||
-                    text.ends_with("/c")
-                )) ||
-                // Multiple commands separated by semicolon
-                (text.contains(';') && text.contains('/')) ||
-                // Print mode (changes behavior significantly)
-                text == "-n" || text == "--quiet" || text == "--silent"
-            });
-
-            // Check if there's at least one substitution pattern
-            let has_substitution = fix_data
+            // Programs in a script file can't be inspected
+            let has_script_file = fix_data
                 .arg_texts(ctx)
-                .any(|text| text.starts_with("s/") || text.contains(" s/"));
-
-            if has_unsupported || !has_substitution {
+                .any(|text| text == "-f" || (text.starts_with("-f") && text.len() > 2));
+            if has_script_file || fix_data.args.is_empty() {
                 None
-            } else {
+            } else if parse_sed_args(fix_data.arg_texts(ctx)).is_some() {
                 Some(NOTE)
+            } else {
+                Some(NO_FIX_NOTE)
             }
         };
         let mut violations = context.detect_external_with_validation("sed", validator);
@@ -63,7 +69,7 @@ impl DetectFix for UseBuiltinSed {
     }
 
     fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
-        let replacement = parse_sed_args(fix_data.arg_texts(context));
+        let replacement = parse_sed_args(fix_data.arg_texts(context))?;
 
         Some(Fix {
             explanation: "Replace with str replace".into(),
@@ -77,7 +83,9 @@ impl DetectFix for UseBuiltinSed {
 
 pub static RULE: &dyn Rule = &UseBuiltinSed;
 
-fn parse_sed_args<'a>(args: impl IntoIterator<Item = &'a str>) -> String {
+/// Converts a sed call with a single substitution, or returns `None` for
+/// other programs
+fn parse_sed_args<'a>(args: impl IntoIterator<Item = &'a str>) -> Option<String> {
     let mut pattern = None;
     let mut file = None;
     let mut in_place = false;
@@ -108,6 +116,8 @@ fn parse_sed_args<'a>(args: impl IntoIterator<Item = &'a str>) -> String {
                 }
             }
             "-E" | "-r" | "--regexp-extended" => regex_mode = true,
+            // Print mode only outputs lines explicitly printed with `p`
+            "-n" | "--quiet" | "--silent" => return None,
             s if s.starts_with('-') && s.len() > 1 && !s.starts_with("--") => {
                 let flags = parse_combined_flags(s);
                 in_place = in_place || flags.in_place;
@@ -123,10 +133,14 @@ fn parse_sed_args<'a>(args: impl IntoIterator<Item = &'a str>) -> String {
         i += 1;
     }
 
-    let pattern_str = pattern.unwrap_or("s/pattern/replacement/");
-    let (find, replace, global) = parse_substitution(pattern_str);
+    let substitution = Substitution::parse(pattern?)?;
 
-    build_str_replace_command(find, replace, file, in_place, regex_mode, global)
+    Some(build_str_replace_command(
+        &substitution,
+        file,
+        in_place,
+        regex_mode,
+    ))
 }
 
 struct CombinedFlags {
@@ -154,60 +168,156 @@ fn parse_combined_flags(arg: &str) -> CombinedFlags {
     flags
 }
 
-fn parse_substitution(pattern: &str) -> (&str, &str, bool) {
-    if !pattern.starts_with('s') {
-        return ("pattern", "replacement", false);
-    }
+/// A sed `s` command such as `s/pattern/replacement/g`
+struct Substitution {
+    find: String,
+    replace: String,
+    global: bool,
+    ignore_case: bool,
+}
+
+impl Substitution {
+    /// Parse an `s` command with any delimiter, as in `s|a|b|`. Escaped
+    /// delimiters become literal. Returns `None` for addresses, other
+    /// commands and flags without a `str replace` equivalent.
+    fn parse(program: &str) -> Option<Self> {
+        let mut chars = program.strip_prefix('s')?.chars();
+        let delimiter = chars.next()?;
+        if delimiter.is_alphanumeric() || delimiter == '\\' || delimiter.is_whitespace() {
+            return None;
+        }
 
-    // Find delimiter (typically '/' but could be others)
-    let delimiter = pattern.chars().nth(1).unwrap_or('/');
+        let mut sections = vec![String::new()];
+        while let Some(ch) = chars.next() {
+            let current = sections.last_mut()?;
+            match ch {
+                '\\' => match chars.next()? {
+                    escaped if escaped == delimiter => current.push(delimiter),
+                    escaped => {
+                        current.push('\\');
+                        current.push(escaped);
+                    }
+                },
+                _ if ch == delimiter => sections.push(String::new()),
+                _ => current.push(ch),
+            }
+        }
 
-    // Split by delimiter, handling escaped delimiters
-    let parts: Vec<&str> = pattern[2..].split(delimiter).collect();
+        let [find, replace, flags] = <[String; 3]>::try_from(sections).ok()?;
+        if find.is_empty() || !flags.chars().all(|flag| matches!(flag, 'g' | 'i' | 'I')) {
+            return None;
+        }
 
-    if parts.len() < 2 {
-        return ("pattern", "replacement", false);
+        Some(Self {
+            find,
+            replace,
+            global: flags.contains('g'),
+            ignore_case: flags.contains(['i', 'I']),
+        })
     }
 
-    let find = parts[0];
-    let replace = parts.get(1).copied().unwrap_or("");
-    let flags = parts.get(2).copied().unwrap_or("");
-    let global = flags.contains('g');
+    /// Whether the pattern or replacement relies on regex features
+    fn needs_regex(&self, extended: bool) -> bool {
+        self.ignore_case
+            || self.find.contains(BRE_META)
+            || (extended && self.find.contains(ERE_ONLY_META))
+            || self.replace.contains(['&', '\\'])
+    }
+}
 
-    (find, replace, global)
+/// Translate a basic regular expression, where `\(` groups and `(` is
+/// literal, to Rust regex syntax
+fn bre_to_regex(pattern: &str) -> String {
+    let mut regex = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some(escaped) if ERE_ONLY_META.contains(&escaped) => regex.push(escaped),
+                Some(escaped) => {
+                    regex.push('\\');
+                    regex.push(escaped);
+                }
+                None => regex.push_str("\\\\"),
+            },
+            _ if ERE_ONLY_META.contains(&ch) => {
+                regex.push('\\');
+                regex.push(ch);
+            }
+            _ => regex.push(ch),
+        }
+    }
+    regex
+}
+
+/// Translate a sed replacement, where `&` is the whole match and `\1` a
+/// group, to the `$0` and `${1}` syntax of `str replace --regex`
+fn sed_replacement_to_regex(replacement: &str) -> String {
+    let mut result = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '&' => result.push_str("$0"),
+            '$' => result.push_str("$$"),
+            '\\' => match chars.next() {
+                Some(digit @ '0'..='9') => result.push_str(&format!("${{{digit}}}")),
+                Some('n') => result.push('\n'),
+                Some(escaped) => result.push(escaped),
+                None => {}
+            },
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
+/// Nushell string literal, using a raw string when the text contains a
+/// single quote
+fn quote(text: &str) -> String {
+    if text.contains('\'') {
+        format!("r#'{text}'#")
+    } else {
+        format!("'{text}'")
+    }
 }
 
 fn build_str_replace_command(
-    find: &str,
-    replace: &str,
+    substitution: &Substitution,
     file: Option<&str>,
     in_place: bool,
-    regex_mode: bool,
-    global: bool,
+    extended: bool,
 ) -> String {
+    let regex_mode = extended || substitution.needs_regex(extended);
     let mut flags = String::new();
 
-    if global {
+    if substitution.global {
         flags.push_str(" --all");
     }
     if regex_mode {
         flags.push_str(" --regex");
     }
 
-    // Escape single quotes in find and replace patterns
-    let find_escaped = find.replace('\'', "''");
-    let replace_escaped = replace.replace('\'', "''");
+    let (find, replace) = if regex_mode {
+        let find = if extended {
+            substitution.find.clone()
+        } else {
+            bre_to_regex(&substitution.find)
+        };
+        let find = if substitution.ignore_case {
+            format!("(?i){find}")
+        } else {
+            find
+        };
+        (find, sed_replacement_to_regex(&substitution.replace))
+    } else {
+        (substitution.find.clone(), substitution.replace.clone())
+    };
+    let replace_cmd = format!("str replace{flags} {} {}", quote(&find), quote(&replace));
 
     match (file, in_place) {
-        (Some(f), true) => {
-            format!(
-                "open {f} | str replace{flags} '{find_escaped}' '{replace_escaped}' | save -f {f}"
-            )
-        }
-        (Some(f), false) => {
-            format!("open {f} | str replace{flags} '{find_escaped}' '{replace_escaped}'")
-        }
-        _ => format!("str replace{flags} '{find_escaped}' '{replace_escaped}'"),
+        (Some(f), true) => format!("open {f} | {replace_cmd} | save -f {f}"),
+        (Some(f), false) => format!("open {f} | {replace_cmd}"),
+        _ => replace_cmd,
     }
 }
 
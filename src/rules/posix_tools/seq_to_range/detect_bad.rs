@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn detect_seq_last_only() {
+    RULE.assert_detects("^seq 10");
+}
+
+#[test]
+fn detect_seq_first_last() {
+    RULE.assert_detects("^seq 1 10 | each {|i| $i * 2 }");
+}
+
+#[test]
+fn detect_seq_with_increment() {
+    RULE.assert_detects("^seq 0 2 10");
+}
+
+#[test]
+fn detect_seq_with_variable() {
+    RULE.assert_detects("def count-to [n: int] { ^seq 1 $n }");
+}
+
+#[test]
+fn detect_empty_descending_seq() {
+    RULE.assert_detects("^seq 10 1");
+}
+
+#[test]
+fn detect_empty_seq_with_unit_increment() {
+    RULE.assert_detects("^seq 10 1 1");
+}
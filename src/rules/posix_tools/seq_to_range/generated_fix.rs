@@ -0,0 +1,36 @@
+use super::RULE;
+
+#[test]
+fn fix_last_only_to_range() {
+    RULE.assert_fixed_is("^seq 10", "1..10");
+}
+
+#[test]
+fn fix_first_last_to_range() {
+    RULE.assert_fixed_is("^seq 3 7", "3..7");
+}
+
+#[test]
+fn fix_unit_increment_to_range() {
+    RULE.assert_fixed_is("^seq 3 1 7", "3..7");
+}
+
+#[test]
+fn fix_variable_bound_to_range() {
+    RULE.assert_fixed_contains("def count-to [n: int] { ^seq 1 $n }", "1..$n");
+}
+
+#[test]
+fn fix_step_to_builtin_seq() {
+    RULE.assert_fixed_is("^seq 0 2 10", "seq 0 2 10");
+}
+
+#[test]
+fn fix_negative_step_to_builtin_seq() {
+    RULE.assert_fixed_is("^seq 10 -1 1", "seq 10 -1 1");
+}
+
+#[test]
+fn fix_float_to_builtin_seq() {
+    RULE.assert_fixed_is("^seq 0 0.5", "seq 0 1 0.5");
+}
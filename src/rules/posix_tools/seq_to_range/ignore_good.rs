@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn ignore_range_literal() {
+    RULE.assert_ignores("1..10 | each {|i| $i * 2 }");
+}
+
+#[test]
+fn ignore_builtin_seq() {
+    RULE.assert_ignores("seq 0 2 10");
+}
+
+#[test]
+fn ignore_equal_width_flag() {
+    RULE.assert_ignores("^seq -w 1 10");
+}
+
+#[test]
+fn ignore_separator_flag() {
+    RULE.assert_ignores("^seq -s , 1 10");
+}
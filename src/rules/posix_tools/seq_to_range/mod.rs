@@ -0,0 +1,163 @@
+use crate::{
+    LintLevel,
+    context::{ExternalCmdFixData, LintContext},
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+const NOTE: &str = "Use a range like '1..10' or Nu's built-in 'seq'. Both produce a list of \
+                    numbers instead of lines of text.";
+
+const DESCENDING_NOTE: &str = "External 'seq' prints nothing when the first number is larger than \
+                               the last, but Nu's ranges count down. Use a range like '10..1' if \
+                               a descending sequence was intended.";
+
+/// A number or variable argument of `seq`
+enum Bound {
+    Int(i64),
+    Float,
+    /// A variable or subexpression, only known at runtime
+    Dynamic,
+}
+
+impl Bound {
+    fn parse(text: &str) -> Option<Self> {
+        if let Ok(n) = text.parse::<i64>() {
+            Some(Self::Int(n))
+        } else if text.parse::<f64>().is_ok() {
+            Some(Self::Float)
+        } else if text.starts_with('$') || text.starts_with('(') {
+            Some(Self::Dynamic)
+        } else {
+            None
+        }
+    }
+}
+
+/// `seq LAST`, `seq FIRST LAST` or `seq FIRST INCREMENT LAST`, without
+/// formatting flags
+struct SeqArgs<'a> {
+    first: &'a str,
+    increment: Option<&'a str>,
+    last: &'a str,
+    bounds: Vec<Bound>,
+}
+
+impl<'a> SeqArgs<'a> {
+    fn parse(args: &[&'a str]) -> Option<Self> {
+        let bounds = args
+            .iter()
+            .map(|arg| Bound::parse(arg))
+            .collect::<Option<Vec<_>>>()?;
+
+        let (first, increment, last) = match args {
+            [last] => ("1", None, *last),
+            [first, last] => (*first, None, *last),
+            [first, increment, last] => (*first, Some(*increment), *last),
+            _ => return None,
+        };
+
+        Some(Self {
+            first,
+            increment,
+            last,
+            bounds,
+        })
+    }
+
+    /// GNU `seq` never counts against the sign of the increment, which
+    /// defaults to one
+    fn is_empty_descending(&self) -> bool {
+        let ints: Option<Vec<i64>> = self
+            .bounds
+            .iter()
+            .map(|bound| match bound {
+                Bound::Int(n) => Some(*n),
+                _ => None,
+            })
+            .collect();
+
+        match ints.as_deref() {
+            Some([last]) => *last < 1,
+            Some([first, last]) => first > last,
+            Some([first, increment, last]) => {
+                (*increment > 0 && first > last) || (*increment < 0 && first < last)
+            }
+            _ => false,
+        }
+    }
+
+    fn to_nushell(&self) -> String {
+        let has_float = self.bounds.iter().any(|b| matches!(b, Bound::Float));
+        let unit_step = self.increment.is_none_or(|inc| inc == "1");
+
+        if unit_step && !has_float {
+            format!("{}..{}", self.first, self.last)
+        } else {
+            let increment = self.increment.unwrap_or("1");
+            format!("seq {} {increment} {}", self.first, self.last)
+        }
+    }
+}
+
+struct UseBuiltinSeq;
+
+impl DetectFix for UseBuiltinSeq {
+    type FixInput<'a> = ExternalCmdFixData<'a>;
+
+    fn id(&self) -> &'static str {
+        "seq_to_range"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "External `seq` replaceable with a range or built-in `seq`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Integer sequences with a step of one become range literals like '1..10'. Other \
+             steps, negative steps and floats keep the 'seq FIRST INCREMENT LAST' form of the \
+             built-in 'seq'. Calls with formatting flags such as '-w' or '-s' are not reported.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/types_of_data.html#ranges")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.detect_external_with_validation("seq", |_, fix_data, ctx| {
+            let args: Vec<&str> = fix_data.arg_texts(ctx).collect();
+            let seq = SeqArgs::parse(&args)?;
+            Some(if seq.is_empty_descending() {
+                DESCENDING_NOTE
+            } else {
+                NOTE
+            })
+        })
+    }
+
+    fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let args: Vec<&str> = fix_data.arg_texts(context).collect();
+        let seq = SeqArgs::parse(&args).filter(|seq| !seq.is_empty_descending())?;
+        let replacement = seq.to_nushell();
+
+        Some(Fix {
+            explanation: format!("Use '{replacement}'").into(),
+            replacements: vec![Replacement::new(fix_data.expr_span, replacement)],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &UseBuiltinSeq;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
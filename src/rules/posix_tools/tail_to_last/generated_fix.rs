@@ -7,25 +7,25 @@ fn fix_tail_with_count_to_last() {
 }
 
 #[test]
-fn fix_tail_follow_to_watch() {
-    let source = "tail -f log.txt";
-    RULE.assert_fixed_contains(source, "watch log.txt");
+fn fix_preserves_filename() {
+    let source = "tail -5 my-log-file.log";
+    RULE.assert_fixed_contains(source, "my-log-file.log");
 }
 
 #[test]
-fn fix_tail_follow_long_to_watch() {
-    let source = "tail --follow log.txt";
-    RULE.assert_fixed_contains(source, "watch log.txt");
+fn fix_tail_with_line_option() {
+    let source = "^tail -n 3 file.txt";
+    RULE.assert_fixed_is(source, "open file.txt | lines | last 3");
 }
 
 #[test]
-fn fix_tail_follow_uppercase_to_watch() {
-    let source = "tail -F log.txt";
-    RULE.assert_fixed_contains(source, "watch log.txt");
+fn fix_tail_byte_count() {
+    let source = "^tail -c 8 data.bin";
+    RULE.assert_fixed_is(source, "open --raw data.bin | into binary | last 8");
 }
 
 #[test]
-fn fix_preserves_filename() {
-    let source = "tail -5 my-log-file.log";
-    RULE.assert_fixed_contains(source, "my-log-file.log");
+fn fix_tail_of_piped_input() {
+    let source = "^cat app.log | ^tail -n 5";
+    RULE.assert_fixed_contains(source, "lines | last 5");
 }
@@ -1,5 +1,6 @@
 use nu_protocol::ast::{Expr, ExternalArgument, Traverse};
 
+use super::HeadTailOptions;
 use crate::{
     LintLevel,
     context::LintContext,
@@ -7,17 +8,19 @@ use crate::{
     violation::{Detection, Fix, Replacement},
 };
 
-struct TailFixData<'a> {
-    count: Option<&'a str>,
-    filename: Option<&'a str>,
-    follow: bool,
+const FOLLOW_NOTE: &str = "'tail -f' has no built-in equivalent. Use 'watch' to run a closure \
+                           whenever the file changes, for example 'watch log.txt { open --raw \
+                           log.txt | lines | last 10 }'.";
+
+struct TailFixData {
+    replacement: Option<String>,
     expr_span: nu_protocol::Span,
 }
 
 struct UseBuiltinTail;
 
 impl DetectFix for UseBuiltinTail {
-    type FixInput<'a> = TailFixData<'a>;
+    type FixInput<'a> = TailFixData;
 
     fn id(&self) -> &'static str {
         "tail_to_last"
@@ -61,28 +64,16 @@ impl DetectFix for UseBuiltinTail {
                         })
                         .collect();
 
-                let follow = args_with_spans
-                    .iter()
-                    .any(|(text, _)| *text == "-f" || *text == "-F" || *text == "--follow");
-
-                let count = args_with_spans
-                    .iter()
-                    .find(|(text, _)| {
-                        text.starts_with('-')
-                            && text.len() > 1
-                            && *text != "-f"
-                            && *text != "-F"
-                            && *text != "--follow"
-                    })
-                    .map(|(text, _)| &text[1..]);
-
-                let filename = args_with_spans
-                    .iter()
-                    .find(|(text, _)| !text.starts_with('-'))
-                    .map(|(text, _)| *text);
+                let opts = HeadTailOptions::parse(args_with_spans.iter().map(|(text, _)| *text));
+                let follow = opts.as_ref().is_some_and(|opts| opts.follow);
+                let message = if follow {
+                    FOLLOW_NOTE
+                } else {
+                    "Use 'last N' to get the last N items"
+                };
 
                 let detection = args_with_spans.iter().fold(
-                    Detection::from_global_span("Use 'last N' to get the last N items", head.span)
+                    Detection::from_global_span(message, head.span)
                         .with_primary_label("external 'tail'"),
                     |det, (text, span)| {
                         if text.starts_with('-') && text.len() > 1 {
@@ -94,9 +85,9 @@ impl DetectFix for UseBuiltinTail {
                 );
 
                 let fix_data = TailFixData {
-                    count,
-                    filename,
-                    follow,
+                    replacement: opts
+                        .filter(|opts| !opts.follow)
+                        .map(|opts| opts.to_nushell("last")),
                     expr_span: expr.span,
                 };
 
@@ -109,39 +100,10 @@ impl DetectFix for UseBuiltinTail {
     }
 
     fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
-        if fix_data.follow {
-            let file = fix_data.filename.unwrap_or("file");
-            let count = fix_data.count.unwrap_or("20");
-            let replacement =
-                format!("watch {file} {{ open --raw {file} | lines | last {count} }}");
-            let description = "Use 'watch' to monitor file changes. Nu's watch executes a closure \
-                               when the file changes, similar to 'tail -f'. Note: this is \
-                               event-based, not continuous streaming.";
-
-            return Some(Fix {
-                explanation: description.into(),
-                replacements: vec![Replacement {
-                    span: fix_data.expr_span.into(),
-                    replacement_text: replacement.into(),
-                }],
-            });
-        }
-
-        let count = fix_data.count.unwrap_or("10");
-
-        let replacement = fix_data.filename.map_or_else(
-            || format!("last {count}"),
-            |file| format!("open {file} | lines | last {count}"),
-        );
-
-        let description = "Use 'last' with cleaner syntax: 'last N' instead of 'tail -N'";
-
+        let replacement = fix_data.replacement.clone()?;
         Some(Fix {
-            explanation: description.into(),
-            replacements: vec![Replacement {
-                span: fix_data.expr_span.into(),
-                replacement_text: replacement.into(),
-            }],
+            explanation: "Use 'last' with cleaner syntax: 'last N' instead of 'tail -N'".into(),
+            replacements: vec![Replacement::new(fix_data.expr_span, replacement)],
         })
     }
 }
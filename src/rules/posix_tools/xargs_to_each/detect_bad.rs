@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn detect_simple_xargs() {
+    RULE.assert_detects("open files.txt | ^xargs rm");
+}
+
+#[test]
+fn detect_xargs_with_placeholder() {
+    RULE.assert_detects("ls | get name | to text | ^xargs -I {} cp {} backup/");
+}
+
+#[test]
+fn detect_parallel_xargs() {
+    RULE.assert_detects("open urls.txt | ^xargs -P 4 -n 1 curl -O");
+}
+
+#[test]
+fn detect_null_separated_xargs() {
+    RULE.assert_detects("^find . -print0 | ^xargs -0 rm");
+}
@@ -0,0 +1,49 @@
+use super::RULE;
+
+#[test]
+fn fix_simple_xargs() {
+    RULE.assert_fixed_is(
+        "open files.txt | ^xargs rm",
+        "open files.txt | lines | each {|it| ^rm $it }",
+    );
+}
+
+#[test]
+fn fix_one_argument_per_call() {
+    RULE.assert_fixed_is(
+        "open files.txt | ^xargs -n1 gzip -k",
+        "open files.txt | lines | each {|it| ^gzip -k $it }",
+    );
+}
+
+#[test]
+fn fix_placeholder_becomes_closure_parameter() {
+    RULE.assert_fixed_is(
+        "open files.txt | ^xargs -I {} cp {} backup/",
+        "open files.txt | lines | each {|it| ^cp $it backup/ }",
+    );
+}
+
+#[test]
+fn fix_placeholder_inside_argument() {
+    RULE.assert_fixed_contains(
+        "open files.txt | ^xargs -I % mv % %.bak",
+        r#"each {|it| ^mv $it $"($it).bak" }"#,
+    );
+}
+
+#[test]
+fn fix_parallel_uses_par_each() {
+    RULE.assert_fixed_is(
+        "open urls.txt | ^xargs -P 4 -n 1 curl -O",
+        "open urls.txt | lines | par-each {|it| ^curl -O $it }",
+    );
+}
+
+#[test]
+fn fix_keeps_quoted_arguments() {
+    RULE.assert_fixed_contains(
+        r#"open files.txt | ^xargs -I {} cp {} "my backups/""#,
+        r#"^cp $it "my backups/""#,
+    );
+}
@@ -0,0 +1,16 @@
+use super::RULE;
+
+#[test]
+fn ignore_lines_each() {
+    RULE.assert_ignores("open files.txt | lines | each {|it| rm $it }");
+}
+
+#[test]
+fn ignore_par_each() {
+    RULE.assert_ignores("open urls.txt | lines | par-each {|url| http get $url }");
+}
+
+#[test]
+fn ignore_xargs_as_argument() {
+    RULE.assert_ignores("^which xargs");
+}
@@ -0,0 +1,145 @@
+use crate::{
+    LintLevel,
+    context::{ExternalCmdFixData, LintContext},
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+const NOTE: &str = "Use 'lines | each {|it| ... }' to run a command for every input line, or \
+                    'par-each' to run them in parallel. Nu passes each line as a value, so no \
+                    quoting or placeholder rules apply.";
+
+/// Options of an external `xargs` call that have a direct `each` equivalent
+#[derive(Default)]
+struct XargsOptions<'a> {
+    /// The `-I` placeholder, replaced by the closure parameter
+    placeholder: Option<&'a str>,
+    /// `-P`: run several commands at the same time
+    parallel: bool,
+    /// The command and its arguments, as unquoted content and source text
+    command: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> XargsOptions<'a> {
+    /// Parse the flags in front of the command. Returns `None` for flags
+    /// without an `each` equivalent, such as `-0`, `-d` or `-n` above one.
+    fn parse(args: &[(&'a str, &'a str)]) -> Option<Self> {
+        let mut opts = Self::default();
+        let mut iter = args.iter().copied();
+
+        while let Some((arg, source)) = iter.next() {
+            match arg {
+                "-I" => opts.placeholder = Some(iter.next()?.0),
+                "-n" | "-L" => {
+                    if iter.next()?.0 != "1" {
+                        return None;
+                    }
+                }
+                "-n1" | "-L1" | "--max-args=1" | "--max-lines=1" => {}
+                "-P" => opts.parallel = is_parallel(iter.next()?.0)?,
+                "-r" | "--no-run-if-empty" => {}
+                _ if arg.starts_with("-I") => opts.placeholder = arg.get(2..),
+                _ if arg.starts_with("--replace=") => {
+                    opts.placeholder = arg.split_once('=').map(|(_, p)| p);
+                }
+                _ if arg.starts_with("-P") => opts.parallel = is_parallel(&arg[2..])?,
+                _ if arg.starts_with("--max-procs=") => {
+                    opts.parallel = is_parallel(arg.split_once('=')?.1)?;
+                }
+                _ if arg.starts_with('-') => return None,
+                _ => {
+                    opts.command.push((arg, source));
+                    opts.command.extend(iter);
+                    break;
+                }
+            }
+        }
+
+        // Without a command, `xargs` just echoes its input
+        (!opts.command.is_empty()).then_some(opts)
+    }
+
+    fn argument(&self, (arg, source): (&str, &str)) -> String {
+        match self.placeholder {
+            Some(placeholder) if arg == placeholder => "$it".to_string(),
+            Some(placeholder) if arg.contains(placeholder) => {
+                format!("$\"{}\"", arg.replace(placeholder, "($it)"))
+            }
+            _ => source.to_string(),
+        }
+    }
+
+    fn to_nushell(&self) -> String {
+        let mut call: Vec<String> = self.command.iter().map(|&arg| self.argument(arg)).collect();
+        call[0] = format!("^{}", call[0]);
+        // Without a placeholder, xargs appends the input as last argument
+        if self.placeholder.is_none() {
+            call.push("$it".to_string());
+        }
+
+        let each = if self.parallel { "par-each" } else { "each" };
+        format!("lines | {each} {{|it| {} }}", call.join(" "))
+    }
+}
+
+/// `-P 1` runs one command at a time, `-P 0` as many as possible
+fn is_parallel(procs: &str) -> Option<bool> {
+    procs.parse::<usize>().ok().map(|n| n != 1)
+}
+
+struct UseEachOverXargs;
+
+impl DetectFix for UseEachOverXargs {
+    type FixInput<'a> = ExternalCmdFixData<'a>;
+
+    fn id(&self) -> &'static str {
+        "xargs_to_each"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`xargs` replaceable with `lines | each`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A fix is offered for 'xargs CMD', 'xargs -n1 CMD' and 'xargs -I {} CMD {}', where \
+             the placeholder becomes the closure parameter '$it'. '-P' turns 'each' into \
+             'par-each'. Plain 'xargs' passes many lines to a single call, while the fix runs the \
+             command once per line. Other flags, such as '-0' or '-d', are only reported.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/each.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.detect_external_with_validation("xargs", |_, _, _| Some(NOTE))
+    }
+
+    fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let args: Vec<(&str, &str)> = fix_data
+            .arg_texts(context)
+            .zip(fix_data.args.iter().map(|arg| context.expr_text(arg)))
+            .collect();
+        let replacement = XargsOptions::parse(&args)?.to_nushell();
+
+        Some(Fix {
+            explanation: "Run the command for every line with 'each'".into(),
+            replacements: vec![Replacement::new(fix_data.expr_span, replacement)],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &UseEachOverXargs;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
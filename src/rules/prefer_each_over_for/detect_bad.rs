@@ -0,0 +1,20 @@
+use super::RULE;
+
+#[test]
+fn detects_command_call_per_item() {
+    let bad_code = r"
+def process [file: string] { open $file | get version }
+for file in [a.toml b.toml] { process $file }
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_transformation_body() {
+    RULE.assert_detects(r"for x in [1 2 3] { $x * 2 }");
+}
+
+#[test]
+fn detects_range_iterator() {
+    RULE.assert_detects(r"for i in 1..5 { $i | into string }");
+}
@@ -0,0 +1,24 @@
+use super::RULE;
+
+#[test]
+fn fix_rewrites_to_each() {
+    RULE.assert_fixed_is(
+        r"let doubled = for x in [1 2 3] { $x * 2 }",
+        r"let doubled = [1 2 3] | each {|x| $x * 2 }",
+    );
+}
+
+#[test]
+fn fix_keeps_variable_iterator() {
+    let bad_code = r"
+def process [file: string] { open $file | get version }
+let files = [a.toml b.toml]
+for file in $files { process $file }
+";
+    let expected = r"
+def process [file: string] { open $file | get version }
+let files = [a.toml b.toml]
+$files | each {|file| process $file }
+";
+    RULE.assert_fixed_is(bad_code, expected);
+}
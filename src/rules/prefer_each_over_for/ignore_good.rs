@@ -0,0 +1,43 @@
+use super::RULE;
+
+#[test]
+fn ignores_print_body() {
+    RULE.assert_ignores(r"for x in [1 2 3] { print $x }");
+}
+
+#[test]
+fn ignores_accumulation() {
+    RULE.assert_ignores(r"mut total = 0; for x in [1 2 3] { $total += $x }");
+}
+
+#[test]
+fn ignores_append_accumulation() {
+    RULE.assert_ignores(
+        r"mut result = []; for x in [1 2 3] { $result = ($result | append ($x * 2)) }",
+    );
+}
+
+#[test]
+fn ignores_read_of_outer_mut() {
+    RULE.assert_ignores(r"mut total = 0; for x in [1 2 3] { $x + $total }");
+}
+
+#[test]
+fn ignores_break() {
+    RULE.assert_ignores(r"for x in [1 2 3] { if $x > 1 { break }; $x * 2 }");
+}
+
+#[test]
+fn ignores_continue() {
+    RULE.assert_ignores(r"for x in [1 2 3] { if $x == 2 { continue }; $x * 2 }");
+}
+
+#[test]
+fn ignores_return() {
+    RULE.assert_ignores(r"def find [] { for x in [1 2 3] { if $x == 2 { return $x }; $x } }");
+}
+
+#[test]
+fn ignores_each() {
+    RULE.assert_ignores(r"[1 2 3] | each {|x| $x * 2 }");
+}
@@ -0,0 +1,180 @@
+use nu_protocol::{
+    BlockId, Span,
+    ast::{Argument, Call, Expr, Expression, Operator, Traverse},
+};
+
+use crate::{
+    LintLevel,
+    ast::{call::CallExt, expression::ExpressionExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    rules::side_effects::block_has_only_side_effects,
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    loop_span: Span,
+    loop_var: String,
+    iterator: Span,
+    body: Span,
+}
+
+/// Assignments, `break`, `continue` and `return` tie the body to the loop
+/// and can't be moved into a closure
+fn has_loop_control(block_id: BlockId, context: &LintContext) -> bool {
+    let mut found = Vec::new();
+    context.working_set.get_block(block_id).flat_map(
+        context.working_set,
+        &|expr| match &expr.expr {
+            Expr::BinaryOp(_, op, _)
+                if matches!(op.expr, Expr::Operator(Operator::Assignment(_))) =>
+            {
+                vec![expr.span]
+            }
+            Expr::Call(call)
+                if ["break", "continue", "return"]
+                    .iter()
+                    .any(|name| call.is_call_to_command(name, context)) =>
+            {
+                vec![expr.span]
+            }
+            _ => vec![],
+        },
+        &mut found,
+    );
+    !found.is_empty()
+}
+
+/// Closures can't capture `mut` variables, so a body that reads one declared
+/// outside of it has to stay a loop
+fn reads_outer_mut(block_id: BlockId, body: Span, context: &LintContext) -> bool {
+    let mut found = Vec::new();
+    context.working_set.get_block(block_id).flat_map(
+        context.working_set,
+        &|expr| match &expr.expr {
+            Expr::Var(var_id) => {
+                let var = context.working_set.get_variable(*var_id);
+                if var.mutable && !body.contains_span(var.declaration_span) {
+                    vec![expr.span]
+                } else {
+                    vec![]
+                }
+            }
+            _ => vec![],
+        },
+        &mut found,
+    );
+    !found.is_empty()
+}
+
+fn check_for(
+    call: &Call,
+    expr: &Expression,
+    context: &LintContext,
+) -> Option<(Detection, FixData)> {
+    if !call.is_call_to_command("for", context) || call.arguments.len() != 3 {
+        return None;
+    }
+    let loop_var = call.loop_var_from_for(context)?;
+    let iterator_arg = call.get_for_loop_iterator()?;
+    let iterator = match &iterator_arg.expr {
+        Expr::Keyword(keyword) => &keyword.expr,
+        _ => iterator_arg,
+    };
+    let Some(Argument::Positional(body)) = call.arguments.last() else {
+        return None;
+    };
+    let body_id = body.extract_block_id()?;
+
+    let is_empty = context.working_set.get_block(body_id).pipelines.is_empty();
+    if is_empty
+        || block_has_only_side_effects(body_id, context)
+        || has_loop_control(body_id, context)
+        || reads_outer_mut(body_id, body.span, context)
+    {
+        return None;
+    }
+
+    let detection = Detection::from_global_span(
+        "The `for` loop discards what its body returns; use `each` to collect the results",
+        expr.span,
+    )
+    .with_primary_label("results are discarded")
+    .with_extra_label("returns a value for each item", body.span);
+
+    Some((
+        detection,
+        FixData {
+            loop_span: expr.span,
+            loop_var,
+            iterator: iterator.span,
+            body: body.span,
+        },
+    ))
+}
+
+struct PreferEachOverFor;
+
+impl DetectFix for PreferEachOverFor {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "prefer_each_over_for"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`for` loop whose body produces values instead of `each`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A `for` loop always returns nothing, so the value its body computes for each item is \
+             thrown away. When the body calls a command per item, `$xs | each {|x| ... }` keeps \
+             the results and can be piped further. Loops that assign to variables, read a `mut` \
+             variable declared outside of them (closures can't capture those) or use `break`, \
+             `continue` or `return` are not reported, and neither are bodies that only have side \
+             effects such as `print`, which `each_nothing_to_for_loop` prefers as a `for` loop.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/each.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.detect_with_fix_data(|expr, ctx| match &expr.expr {
+            Expr::Call(call) => check_for(call, expr, ctx).into_iter().collect(),
+            _ => vec![],
+        })
+    }
+
+    fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let iterator = context.span_text(fix_data.iterator).trim();
+        let body = context
+            .span_text(fix_data.body)
+            .trim()
+            .strip_prefix('{')?
+            .strip_suffix('}')?
+            .trim();
+        Some(Fix {
+            explanation: "Rewrite the loop as `each`".into(),
+            replacements: vec![Replacement::new(
+                fix_data.loop_span,
+                format!("{iterator} | each {{|{}| {body} }}", fix_data.loop_var),
+            )],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &PreferEachOverFor;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
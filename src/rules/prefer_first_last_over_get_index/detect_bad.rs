@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn detects_get_zero() {
+    RULE.assert_detects(r"[1 2 3] | get 0");
+}
+
+#[test]
+fn detects_length_minus_one() {
+    RULE.assert_detects(r"[1 2 3] | get (($in | length) - 1)");
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r"
+def newest [] {
+    ls | sort-by modified | get 0
+}
+";
+    RULE.assert_detects(bad_code);
+}
@@ -0,0 +1,14 @@
+use super::RULE;
+
+#[test]
+fn fix_get_zero_to_first() {
+    RULE.assert_fixed_is(
+        r"ls | sort-by modified | get 0 | get name",
+        r"ls | sort-by modified | first | get name",
+    );
+}
+
+#[test]
+fn fix_length_minus_one_to_last() {
+    RULE.assert_fixed_is(r"[1 2 3] | get (($in | length) - 1)", r"[1 2 3] | last");
+}
@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn ignore_other_index() {
+    RULE.assert_ignores(r"[1 2 3] | get 1");
+}
+
+#[test]
+fn ignore_variable_index() {
+    RULE.assert_ignores(r"let i = 0; [1 2 3] | get $i");
+}
+
+#[test]
+fn ignore_column_name() {
+    RULE.assert_ignores(r"ls | get name");
+}
+
+#[test]
+fn ignore_optional_get() {
+    RULE.assert_ignores(r"[] | get -o 0");
+}
+
+#[test]
+fn ignore_length_of_other_list() {
+    RULE.assert_ignores(r"let xs = [1 2]; [1 2 3] | get (($xs | length) - 1)");
+}
+
+#[test]
+fn ignore_length_minus_two() {
+    RULE.assert_ignores(r"[1 2 3] | get (($in | length) - 2)");
+}
@@ -0,0 +1,150 @@
+use nu_protocol::{
+    Span,
+    ast::{Call, Expr, Expression, Math, Operator},
+};
+
+use super::parsing::extract_index_from_call;
+use crate::{
+    LintLevel,
+    ast::{
+        call::CallExt,
+        expression::{ExpressionExt, is_pipeline_input_var},
+    },
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    get_span: Span,
+    replacement: &'static str,
+}
+
+/// `($in | length)`
+fn is_input_length(expr: &Expression, context: &LintContext) -> bool {
+    let Some(block_id) = expr.extract_block_id() else {
+        return false;
+    };
+    let block = context.working_set.get_block(block_id);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return false;
+    };
+    let [input, length] = pipeline.elements.as_slice() else {
+        return false;
+    };
+    let Expr::Call(length) = &length.expr.expr else {
+        return false;
+    };
+    input
+        .expr
+        .extract_direct_var()
+        .is_some_and(|var_id| is_pipeline_input_var(var_id, context))
+        && length.is_call_to_command("length", context)
+        && length.arguments.is_empty()
+}
+
+/// `(($in | length) - 1)`
+fn is_last_index(expr: &Expression, context: &LintContext) -> bool {
+    let Some(block_id) = expr.extract_block_id() else {
+        return false;
+    };
+    let block = context.working_set.get_block(block_id);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return false;
+    };
+    let [element] = pipeline.elements.as_slice() else {
+        return false;
+    };
+    let Expr::BinaryOp(lhs, op, rhs) = &element.expr.expr else {
+        return false;
+    };
+    matches!(op.expr, Expr::Operator(Operator::Math(Math::Subtract)))
+        && matches!(rhs.expr, Expr::Int(1))
+        && is_input_length(lhs, context)
+}
+
+fn replacement_for(call: &Call, context: &LintContext) -> Option<&'static str> {
+    if !call.is_call_to_command("get", context) || call.arguments.len() != 1 {
+        return None;
+    }
+    if extract_index_from_call(call, context) == Some(0) {
+        return Some("first");
+    }
+    call.get_first_positional_arg()
+        .is_some_and(|index| is_last_index(index, context))
+        .then_some("last")
+}
+
+fn check_get(expr: &Expression, context: &LintContext) -> Vec<(Detection, FixData)> {
+    let Expr::Call(call) = &expr.expr else {
+        return vec![];
+    };
+    let Some(replacement) = replacement_for(call, context) else {
+        return vec![];
+    };
+    let detection = Detection::from_global_span(
+        format!(
+            "Use `{replacement}` instead of `{}`",
+            context.span_text(expr.span)
+        ),
+        expr.span,
+    )
+    .with_primary_label(format!("same as `{replacement}`"));
+    vec![(
+        detection,
+        FixData {
+            get_span: expr.span,
+            replacement,
+        },
+    )]
+}
+
+struct PreferFirstLastOverGetIndex;
+
+impl DetectFix for PreferFirstLastOverGetIndex {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "prefer_first_last_over_get_index"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`get 0` or `get (($in | length) - 1)` instead of `first` or `last`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`first` and `last` say directly which element is wanted, while `get 0` and the \
+             length-minus-one index have to be decoded. Only literal indices are reported; an \
+             index held in a variable is left alone.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/first.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.detect_with_fix_data(check_get)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: format!("Replace with `{}`", fix_data.replacement).into(),
+            replacements: vec![Replacement::new(fix_data.get_span, fix_data.replacement)],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &PreferFirstLastOverGetIndex;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
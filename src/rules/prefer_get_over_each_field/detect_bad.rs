@@ -0,0 +1,22 @@
+use super::RULE;
+
+#[test]
+fn detects_cell_path_body() {
+    RULE.assert_detects(r"ls | each {|f| $f.name }");
+}
+
+#[test]
+fn detects_get_body() {
+    RULE.assert_detects(r"ls | each {|f| $f | get size }");
+}
+
+#[test]
+fn detects_in_function() {
+    RULE.assert_detects(
+        r"
+def names [] {
+    ls | where type == file | each {|row| $row.name }
+}
+",
+    );
+}
@@ -0,0 +1,19 @@
+use super::RULE;
+
+#[test]
+fn fix_cell_path_body() {
+    RULE.assert_fixed_is(r"ls | each {|f| $f.name }", r"ls | get name");
+}
+
+#[test]
+fn fix_get_body() {
+    RULE.assert_fixed_is(r"ls | each {|f| $f | get size }", r"ls | get size");
+}
+
+#[test]
+fn fix_quotes_field_with_space() {
+    RULE.assert_fixed_is(
+        r#"$rows | each {|r| $r."full name" }"#,
+        r"$rows | get 'full name'",
+    );
+}
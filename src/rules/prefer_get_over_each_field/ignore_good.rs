@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn ignores_nested_field() {
+    RULE.assert_ignores(r"$rows | each {|r| $r.meta.id }");
+}
+
+#[test]
+fn ignores_optional_field() {
+    RULE.assert_ignores(r"$rows | each {|r| $r.id? }");
+}
+
+#[test]
+fn ignores_transformed_field() {
+    RULE.assert_ignores(r"ls | each {|f| $f.name | str upcase }");
+}
+
+#[test]
+fn ignores_multiple_fields() {
+    RULE.assert_ignores(r"ls | each {|f| [$f.name $f.size] }");
+}
+
+#[test]
+fn ignores_field_of_other_variable() {
+    RULE.assert_ignores(r"let config = {id: 1}; ls | each {|f| $config.id }");
+}
+
+#[test]
+fn ignores_get_with_flags() {
+    RULE.assert_ignores(r"$rows | each {|r| $r | get --optional id }");
+}
@@ -0,0 +1,172 @@
+use nu_protocol::{
+    Span, VarId,
+    ast::{Call, Expr, Expression, PathMember, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::{
+        block::BlockExt, call::CallExt, expression::ExpressionExt,
+        string::cell_path_member_needs_quotes,
+    },
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    each_span: Span,
+    field: String,
+}
+
+/// Name of a single, non-optional string member
+fn single_field(members: &[PathMember]) -> Option<&str> {
+    match members {
+        [
+            PathMember::String {
+                val,
+                optional: false,
+                ..
+            },
+        ] => Some(val),
+        _ => None,
+    }
+}
+
+/// Field read by the closure body: `$param.field` or `$param | get field`
+fn projected_field<'a>(
+    pipeline: &'a Pipeline,
+    param: VarId,
+    context: &LintContext,
+) -> Option<&'a str> {
+    match pipeline.elements.as_slice() {
+        [element] => {
+            let Expr::FullCellPath(cell_path) = &element.expr.expr else {
+                return None;
+            };
+            if !matches!(cell_path.head.expr, Expr::Var(var_id) if var_id == param) {
+                return None;
+            }
+            single_field(&cell_path.tail)
+        }
+        [value, stage] => {
+            if value.expr.extract_direct_var() != Some(param) {
+                return None;
+            }
+            let Expr::Call(call) = &stage.expr.expr else {
+                return None;
+            };
+            if !call.is_call_to_command("get", context) || call.arguments.len() != 1 {
+                return None;
+            }
+            let Expr::CellPath(cell_path) = &call.get_first_positional_arg()?.expr else {
+                return None;
+            };
+            single_field(&cell_path.members)
+        }
+        _ => None,
+    }
+}
+
+fn closure_field<'a>(call: &Call, context: &'a LintContext) -> Option<&'a str> {
+    if !call.is_call_to_command("each", context) || call.arguments.len() != 1 {
+        return None;
+    }
+    let Expr::Closure(block_id) = call.get_first_positional_arg()?.expr else {
+        return None;
+    };
+    let block = context.working_set.get_block(block_id);
+    let [param] = block.signature.required_positional.as_slice() else {
+        return None;
+    };
+    let [pipeline] = block.pipelines.as_slice() else {
+        return None;
+    };
+    projected_field(pipeline, param.var_id?, context)
+}
+
+fn check_each(expr: &Expression, context: &LintContext) -> Option<(Detection, FixData)> {
+    let Expr::Call(call) = &expr.expr else {
+        return None;
+    };
+    let field = closure_field(call, context)?;
+    let field = if cell_path_member_needs_quotes(field) {
+        format!("'{field}'")
+    } else {
+        field.to_string()
+    };
+
+    let detection = Detection::from_global_span(
+        format!("`each` only reads one field of every row; use `get {field}`"),
+        expr.span,
+    )
+    .with_primary_label("single field projection");
+    Some((
+        detection,
+        FixData {
+            each_span: expr.span,
+            field,
+        },
+    ))
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, FixData)> {
+    pipeline
+        .elements
+        .iter()
+        .skip(1)
+        .filter_map(|element| check_each(&element.expr, context))
+        .collect()
+}
+
+struct PreferGetOverEachField;
+
+impl DetectFix for PreferGetOverEachField {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "prefer_get_over_each_field"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`each` extracting a single field instead of `get`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "An `each` closure whose body is only `$row.field` or `$row | get field` turns a \
+             table into the list of values in one column, which is exactly what `get field` does. \
+             Note that `each` drops `null` results while `get` keeps them, so rows missing a \
+             value stay in the output after the fix.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/get.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.ast.detect_in_pipelines(context, check_pipeline)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let replacement = format!("get {}", fix_data.field);
+        Some(Fix {
+            explanation: format!("Replace `each` with `{replacement}`").into(),
+            replacements: vec![Replacement::new(fix_data.each_span, replacement)],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &PreferGetOverEachField;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn detects_equal_null() {
+    RULE.assert_detects(r"def f [x?] { if $x == null { 'missing' } }");
+}
+
+#[test]
+fn detects_not_equal_null() {
+    RULE.assert_detects(r"def f [x?] { $x != null }");
+}
+
+#[test]
+fn detects_null_on_left() {
+    RULE.assert_detects(r"def f [x?] { null == $x }");
+}
+
+#[test]
+fn detects_cell_path() {
+    RULE.assert_detects(r"let config = {}; $config.port? == null");
+}
@@ -0,0 +1,17 @@
+use super::RULE;
+
+#[test]
+fn fix_equal_to_is_empty() {
+    RULE.assert_fixed_is(
+        r"def f [x?] { if $x == null { 'missing' } }",
+        r"def f [x?] { if ($x | is-empty) { 'missing' } }",
+    );
+}
+
+#[test]
+fn fix_not_equal_to_is_not_empty() {
+    RULE.assert_fixed_is(
+        r"let config = {}; $config.port? != null",
+        r"let config = {}; ($config.port? | is-not-empty)",
+    );
+}
@@ -0,0 +1,16 @@
+use super::RULE;
+
+#[test]
+fn ignores_is_empty() {
+    RULE.assert_ignores(r"def f [x?] { $x | is-empty }");
+}
+
+#[test]
+fn ignores_other_comparisons() {
+    RULE.assert_ignores(r#"def f [x?] { $x == "" }"#);
+}
+
+#[test]
+fn ignores_row_condition_shorthand() {
+    RULE.assert_ignores(r"ls | where size == null");
+}
@@ -0,0 +1,118 @@
+use nu_protocol::{
+    Span,
+    ast::{Comparison, Expr, Expression, Operator},
+};
+
+use crate::{
+    LintLevel,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    comparison_span: Span,
+    replacement: String,
+}
+
+/// Whether the operand is a variable or a cell path on a variable, written
+/// with `$` (column shorthands in row conditions can't be piped)
+fn is_variable_operand(operand: &Expression, context: &LintContext) -> bool {
+    let head = match &operand.expr {
+        Expr::FullCellPath(cell_path) => &cell_path.head,
+        _ => operand,
+    };
+    matches!(head.expr, Expr::Var(_)) && context.span_text(operand.span).starts_with('$')
+}
+
+fn check_comparison(expr: &Expression, context: &LintContext) -> Vec<(Detection, FixData)> {
+    let Expr::BinaryOp(lhs, op, rhs) = &expr.expr else {
+        return vec![];
+    };
+    let command = match op.expr {
+        Expr::Operator(Operator::Comparison(Comparison::Equal)) => "is-empty",
+        Expr::Operator(Operator::Comparison(Comparison::NotEqual)) => "is-not-empty",
+        _ => return vec![],
+    };
+    let operand = match (&lhs.expr, &rhs.expr) {
+        (Expr::Nothing, _) => rhs,
+        (_, Expr::Nothing) => lhs,
+        _ => return vec![],
+    };
+    if !is_variable_operand(operand, context) {
+        return vec![];
+    }
+
+    let replacement = format!("({} | {command})", context.span_text(operand.span));
+    let detection = Detection::from_global_span(
+        format!("Comparison with `null` can be written as `{replacement}`"),
+        expr.span,
+    )
+    .with_primary_label("null comparison");
+
+    vec![(
+        detection,
+        FixData {
+            comparison_span: expr.span,
+            replacement,
+        },
+    )]
+}
+
+struct PreferIsEmptyOverNullCompare;
+
+impl DetectFix for PreferIsEmptyOverNullCompare {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "prefer_is_empty_over_null_compare"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`== null` comparison instead of `is-empty`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`($x | is-empty)` and `($x | is-not-empty)` are the usual way to check optional \
+             values. They are not equivalent to comparing with `null`: `is-empty` is also true \
+             for an empty string, list or record, so the fix changes behaviour when such values \
+             are possible. For that reason this rule is off by default.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/is-empty.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Off
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.detect_with_fix_data(check_comparison)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: format!(
+                "Replace with `{}` (also matches empty values)",
+                fix_data.replacement
+            )
+            .into(),
+            replacements: vec![Replacement::new(
+                fix_data.comparison_span,
+                fix_data.replacement.clone(),
+            )],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &PreferIsEmptyOverNullCompare;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
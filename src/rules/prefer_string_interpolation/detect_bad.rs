@@ -0,0 +1,29 @@
+use super::RULE;
+
+#[test]
+fn detects_literal_and_variable() {
+    RULE.assert_detects(r#"let name = 'world'; print ("Hello " + $name + "!")"#);
+}
+
+#[test]
+fn detects_concatenate_operator() {
+    RULE.assert_detects(r#"let dir = 'src'; let path = $dir ++ "/main.nu""#);
+}
+
+#[test]
+fn detects_cell_path() {
+    RULE.assert_detects(r#"def greet [user: record] { "Hi " + $user.name }"#);
+}
+
+#[test]
+fn detects_subexpression() {
+    RULE.assert_detects(r#"let label = "Files: " + (ls | length | into string)"#);
+}
+
+#[test]
+fn reports_chain_once() {
+    RULE.assert_count(
+        r#"def main [first: string, last: string] { print ("Name: " + $first + " " + $last) }"#,
+        1,
+    );
+}
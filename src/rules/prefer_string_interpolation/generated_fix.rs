@@ -0,0 +1,33 @@
+use super::RULE;
+
+#[test]
+fn fix_builds_interpolation() {
+    RULE.assert_fixed_is(
+        r#"def main [name: string] { print ("Hello " + $name + "!") }"#,
+        r#"def main [name: string] { print ($"Hello ($name)!") }"#,
+    );
+}
+
+#[test]
+fn fix_keeps_subexpression() {
+    RULE.assert_fixed_is(
+        r#"let label = "Files: " + (ls | length | into string)"#,
+        r#"let label = $"Files: (ls | length | into string)""#,
+    );
+}
+
+#[test]
+fn fix_escapes_literal_segments() {
+    RULE.assert_fixed_is(
+        r#"def main [n: string] { 'count (' + $n + ') "done"' }"#,
+        r#"def main [n: string] { $"count \(($n)\) \"done\"" }"#,
+    );
+}
+
+#[test]
+fn fix_cell_path() {
+    RULE.assert_fixed_is(
+        r#"def greet [user: record] { "Hi " + $user.name }"#,
+        r#"def greet [user: record] { $"Hi ($user.name)" }"#,
+    );
+}
@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn ignores_number_addition() {
+    RULE.assert_ignores(r"let x = 1; let y = $x + 2");
+}
+
+#[test]
+fn ignores_list_concatenation() {
+    RULE.assert_ignores(r"let xs = [1 2]; let ys = $xs ++ [3]");
+}
+
+#[test]
+fn ignores_only_literals() {
+    RULE.assert_ignores(r#"let s = "a" + "b""#);
+}
+
+#[test]
+fn ignores_only_variables() {
+    RULE.assert_ignores(r"def join [a: string, b: string] { $a + $b }");
+}
+
+#[test]
+fn ignores_typed_int_variable() {
+    RULE.assert_ignores(r#"def show [n: int] { "count" + $n }"#);
+}
+
+#[test]
+fn ignores_existing_interpolation() {
+    RULE.assert_ignores(r#"let name = 'x'; print $"Hello ($name)""#);
+}
@@ -0,0 +1,206 @@
+use nu_protocol::{
+    Span, Type,
+    ast::{Expr, Expression, Math, Operator, Traverse},
+};
+
+use crate::{
+    LintLevel,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    chain_span: Span,
+    interpolation: String,
+}
+
+const fn is_concatenation(op: &Expression) -> bool {
+    matches!(
+        op.expr,
+        Expr::Operator(Operator::Math(Math::Add | Math::Concatenate))
+    )
+}
+
+/// Operands of a chain like `"a" + $b + "c"`, from left to right
+fn flatten_chain(expr: &Expression) -> Vec<&Expression> {
+    match &expr.expr {
+        Expr::BinaryOp(lhs, op, rhs) if is_concatenation(op) => {
+            let mut operands = flatten_chain(lhs);
+            operands.extend(flatten_chain(rhs));
+            operands
+        }
+        _ => vec![expr],
+    }
+}
+
+/// Spans of the operands of a concatenation that are concatenations
+/// themselves
+fn concatenation_operands(expr: &Expression) -> Vec<Span> {
+    let Expr::BinaryOp(lhs, op, rhs) = &expr.expr else {
+        return vec![];
+    };
+    if !is_concatenation(op) {
+        return vec![];
+    }
+    [lhs, rhs]
+        .into_iter()
+        .filter(|operand| matches!(&operand.expr, Expr::BinaryOp(_, op, _) if is_concatenation(op)))
+        .map(|operand| operand.span)
+        .collect()
+}
+
+/// Whether a non-literal operand can be a string: a variable that isn't
+/// declared with another type, a cell path or a subexpression
+fn is_string_like_value(expr: &Expression, context: &LintContext) -> bool {
+    match &expr.expr {
+        Expr::Var(var_id) => matches!(
+            context.working_set.get_variable(*var_id).ty,
+            Type::String | Type::Any
+        ),
+        Expr::FullCellPath(cell_path) if cell_path.tail.is_empty() => {
+            is_string_like_value(&cell_path.head, context)
+        }
+        Expr::FullCellPath(cell_path) => matches!(cell_path.head.expr, Expr::Var(_)),
+        Expr::Subexpression(_) => true,
+        _ => false,
+    }
+}
+
+/// Escapes text for a double-quoted interpolation, where `(` starts an
+/// expression
+fn escape_literal_segment(text: &str) -> String {
+    text.chars().fold(String::new(), |mut escaped, c| {
+        match c {
+            '\\' => escaped.push_str(r"\\"),
+            '"' => escaped.push_str(r#"\""#),
+            '(' => escaped.push_str(r"\("),
+            ')' => escaped.push_str(r"\)"),
+            '\n' => escaped.push_str(r"\n"),
+            '\t' => escaped.push_str(r"\t"),
+            '\r' => escaped.push_str(r"\r"),
+            _ => escaped.push(c),
+        }
+        escaped
+    })
+}
+
+fn build_interpolation(operands: &[&Expression], context: &LintContext) -> Option<String> {
+    let mut has_literal = false;
+    let mut has_value = false;
+    let mut content = String::new();
+
+    for operand in operands {
+        if let Expr::String(text) = &operand.expr {
+            has_literal = true;
+            content.push_str(&escape_literal_segment(text));
+        } else if is_string_like_value(operand, context) {
+            has_value = true;
+            let text = context.span_text(operand.span);
+            if matches!(operand.expr, Expr::Subexpression(_)) {
+                content.push_str(text);
+            } else {
+                content.push_str(&format!("({text})"));
+            }
+        } else {
+            return None;
+        }
+    }
+
+    (has_literal && has_value).then(|| format!("$\"{content}\""))
+}
+
+fn check_expression(expr: &Expression, context: &LintContext) -> Vec<(Detection, FixData)> {
+    let Expr::BinaryOp(_, op, _) = &expr.expr else {
+        return vec![];
+    };
+    if !is_concatenation(op) {
+        return vec![];
+    }
+    let operands = flatten_chain(expr);
+    let Some(interpolation) = build_interpolation(&operands, context) else {
+        return vec![];
+    };
+
+    let detection = Detection::from_global_span(
+        format!("String built by concatenation; use the interpolation `{interpolation}`"),
+        expr.span,
+    )
+    .with_primary_label("string concatenation");
+
+    vec![(
+        detection,
+        FixData {
+            chain_span: expr.span,
+            interpolation,
+        },
+    )]
+}
+
+struct PreferStringInterpolation;
+
+impl DetectFix for PreferStringInterpolation {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "prefer_string_interpolation"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "String concatenated with `+` instead of interpolation"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Gluing literals and variables together with `+` or `++`, as in `\"Hello \" + $name + \
+             \"!\"`, is harder to read than the interpolation `$\"Hello ($name)!\"`, which shows \
+             the final text in one piece. Only chains that mix string literals with variables, \
+             cell paths or subexpressions are reported; variables declared with a non-string type \
+             are left alone.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/working_with_strings.html#string-interpolation")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        // Operands that are themselves concatenations belong to the chain
+        // around them, which is reported as a whole
+        let mut inner_links = Vec::new();
+        context.ast.flat_map(
+            context.working_set,
+            &concatenation_operands,
+            &mut inner_links,
+        );
+
+        context
+            .detect_with_fix_data(check_expression)
+            .into_iter()
+            .filter(|(_, fix_data)| !inner_links.contains(&fix_data.chain_span))
+            .collect()
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: "Use string interpolation".into(),
+            replacements: vec![Replacement::new(
+                fix_data.chain_span,
+                fix_data.interpolation.clone(),
+            )],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &PreferStringInterpolation;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
@@ -1,13 +1,33 @@
-use nu_protocol::ast::{Call, Comparison, Expr, Expression, Operator};
+use nu_protocol::{
+    Span, VarId,
+    ast::{Call, Comparison, Expr, Expression, Operator, Traverse},
+};
 
 use crate::{
     LintLevel,
-    ast::{call::CallExt, expression::ExpressionExt},
+    ast::{block::BlockExt, call::CallExt, expression::ExpressionExt},
     context::LintContext,
     rule::{DetectFix, Rule},
-    violation::Detection,
+    violation::{Detection, Fix, Replacement},
 };
 
+/// A zero-initialized `mut` counter declared at the top level
+struct CounterDecl {
+    var_id: VarId,
+    name: String,
+    span: Span,
+    /// Whether the `while` loop is the next statement after the declaration
+    directly_followed: bool,
+}
+
+struct FixData {
+    /// From the `mut` keyword up to the opening brace of the loop body
+    head_span: Span,
+    head: String,
+    /// The trailing increment, including the whitespace before it
+    increment_span: Span,
+}
+
 fn is_counter_comparison(expr: &Expression, counter_name: &str, context: &LintContext) -> bool {
     matches!(
         &expr.expr,
@@ -42,49 +62,117 @@ fn has_increment_in_block(
 fn extract_counter_from_mut(
     expr: &Expression,
     context: &LintContext,
-) -> Option<(String, nu_protocol::Span)> {
+) -> Option<(VarId, String, Span)> {
     let Expr::Call(call) = &expr.expr else {
         return None;
     };
 
     (call.get_call_name(context) == "mut")
         .then(|| {
-            let var_name = call.get_positional_arg(0)?.extract_variable_name(context)?;
+            let (var_id, var_name, _) = call.extract_variable_declaration(context)?;
             let init_value = call.get_positional_arg(1)?;
 
-            (init_value.extract_int_value(context) == Some(0)).then_some((var_name, expr.span))
+            (init_value.extract_int_value(context) == Some(0))
+                .then_some((var_id, var_name, expr.span))
         })
         .flatten()
 }
 
+/// Builds the `for` rewrite when the loop is a plain counter loop: `<` or `<=`
+/// condition, a single `+= 1` as last statement, no `continue`, and no use of
+/// the counter outside the loop.
+fn build_fix_data(call: &Call, counter: &CounterDecl, context: &LintContext) -> Option<FixData> {
+    let Expr::BinaryOp(_, op, bound) = &call.get_positional_arg(0)?.expr else {
+        return None;
+    };
+    let range_op = match &op.expr {
+        Expr::Operator(Operator::Comparison(Comparison::LessThan)) => "..<",
+        Expr::Operator(Operator::Comparison(Comparison::LessThanOrEqual)) => "..",
+        _ => return None,
+    };
+
+    let body_expr = call.get_positional_arg(1)?;
+    let body = context.working_set.get_block(body_expr.extract_block_id()?);
+    let [.., before_increment, last] = body.pipelines.as_slice() else {
+        return None;
+    };
+    let [increment] = last.elements.as_slice() else {
+        return None;
+    };
+    if !increment.expr.is_counter_increment(&counter.name, context) {
+        return None;
+    }
+
+    let mut assigned = Vec::new();
+    body.flat_map(
+        context.working_set,
+        &|expr| expr.extract_assigned_variable().into_iter().collect(),
+        &mut assigned,
+    );
+    let counter_assignments = assigned.iter().filter(|id| **id == counter.var_id).count();
+    let bound_mutated = assigned.iter().any(|id| bound.contains_variable(*id));
+    let has_continue = !body
+        .find_expr_spans(context, |expr, ctx| {
+            matches!(&expr.expr, Expr::Call(c) if c.is_call_to_command("continue", ctx))
+        })
+        .is_empty();
+    let used_outside = context
+        .ast
+        .var_usages(counter.var_id, context)
+        .iter()
+        .any(|span| !call.span().contains_span(*span));
+    if counter_assignments != 1 || bound_mutated || has_continue || used_outside {
+        return None;
+    }
+
+    let bound_text = context.expr_text(bound);
+    let bound_text = match &bound.expr {
+        Expr::Int(_) | Expr::Var(_) | Expr::FullCellPath(_) => bound_text.to_string(),
+        _ => format!("({bound_text})"),
+    };
+    let previous_end = before_increment.elements.last()?.expr.span.end;
+
+    Some(FixData {
+        head_span: Span::new(counter.span.start, body_expr.span.start),
+        head: format!("for {} in 0{range_op}{bound_text} ", counter.name),
+        increment_span: Span::new(previous_end, increment.expr.span.end),
+    })
+}
+
 fn check_while_loop_for_counter(
     call: &Call,
-    counter_name: &str,
-    counter_span: nu_protocol::Span,
+    counter: &CounterDecl,
     context: &LintContext,
-) -> Option<Detection> {
+) -> Option<(Detection, Option<FixData>)> {
     let condition = call.get_positional_arg(0)?;
     let body_expr = call.get_positional_arg(1)?;
     let block_id = body_expr.extract_block_id()?;
 
-    (is_counter_comparison(condition, counter_name, context)
-        && has_increment_in_block(block_id, counter_name, context))
+    (is_counter_comparison(condition, &counter.name, context)
+        && has_increment_in_block(block_id, &counter.name, context))
     .then(|| {
-        Detection::from_global_span(
+        let detection = Detection::from_global_span(
             format!(
-                "While loop with counter '{counter_name}' can be replaced with range iteration"
+                "While loop with counter '{}' can be replaced with range iteration",
+                counter.name
             ),
-            counter_span,
+            counter.span,
         )
         .with_primary_label("counter initialization")
-        .with_extra_label("while loop using counter", call.span())
+        .with_extra_label("while loop using counter", call.span());
+
+        let fix_data = counter
+            .directly_followed
+            .then(|| build_fix_data(call, counter, context))
+            .flatten();
+        (detection, fix_data)
     })
 }
 
 struct WhileCounter;
 
 impl DetectFix for WhileCounter {
-    type FixInput<'a> = ();
+    type FixInput<'a> = Option<FixData>;
 
     fn id(&self) -> &'static str {
         "while_counter_to_range"
@@ -103,29 +191,54 @@ impl DetectFix for WhileCounter {
     }
 
     fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
-        let violations = context
-            .ast
-            .pipelines
+        let pipelines = &context.ast.pipelines;
+        pipelines
             .iter()
-            .flat_map(|pipeline| &pipeline.elements)
-            .filter_map(|element| extract_counter_from_mut(&element.expr, context))
-            .flat_map(|(counter_name, counter_span)| {
-                context.detect(|expr, ctx| {
+            .enumerate()
+            .flat_map(|(index, pipeline)| {
+                pipeline
+                    .elements
+                    .iter()
+                    .map(move |element| (index, element))
+            })
+            .filter_map(|(index, element)| {
+                let (var_id, name, span) = extract_counter_from_mut(&element.expr, context)?;
+                let next_while = pipelines
+                    .get(index + 1)
+                    .and_then(|next| next.elements.first());
+                Some((var_id, name, span, next_while.map(|e| e.expr.span)))
+            })
+            .flat_map(|(var_id, name, span, next_span)| {
+                context.detect_with_fix_data(|expr, ctx| {
                     let Expr::Call(call) = &expr.expr else {
                         return vec![];
                     };
+                    let counter = CounterDecl {
+                        var_id,
+                        name: name.clone(),
+                        span,
+                        directly_followed: next_span == Some(expr.span),
+                    };
 
                     (call.get_call_name(ctx) == "while")
-                        .then(|| {
-                            check_while_loop_for_counter(call, &counter_name, counter_span, ctx)
-                        })
+                        .then(|| check_while_loop_for_counter(call, &counter, ctx))
                         .flatten()
                         .into_iter()
                         .collect()
                 })
             })
-            .collect();
-        Self::no_fix(violations)
+            .collect()
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let fix_data = fix_data.as_ref()?;
+        Some(Fix {
+            explanation: "Rewrite as a `for` loop over a range".into(),
+            replacements: vec![
+                Replacement::new(fix_data.head_span, fix_data.head.clone()),
+                Replacement::new(fix_data.increment_span, String::new()),
+            ],
+        })
     }
 }
 
@@ -134,4 +247,6 @@ pub static RULE: &dyn Rule = &WhileCounter;
 #[cfg(test)]
 mod detect_bad;
 #[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
 mod ignore_good;
@@ -0,0 +1,80 @@
+use super::RULE;
+
+#[test]
+fn test_fix_less_than_to_exclusive_range() {
+    let bad_code = r"
+mut i = 0
+while $i < 10 {
+    print $i
+    $i += 1
+}
+";
+    let expected = r"
+for i in 0..<10 {
+    print $i
+}
+";
+
+    RULE.assert_fixed_is(bad_code, expected);
+}
+
+#[test]
+fn test_fix_less_or_equal_to_inclusive_range() {
+    let bad_code = r"
+mut i = 0
+while $i <= 5 {
+    print $i
+    $i = $i + 1
+}
+";
+    let expected = r"
+for i in 0..5 {
+    print $i
+}
+";
+
+    RULE.assert_fixed_is(bad_code, expected);
+}
+
+#[test]
+fn test_fix_wraps_computed_bound() {
+    let bad_code = r"
+let items = [a b c]
+mut i = 0
+while $i < ($items | length) {
+    print ($items | get $i)
+    $i += 1
+}
+";
+
+    RULE.assert_fixed_contains(bad_code, "for i in 0..<($items | length) {");
+}
+
+#[test]
+fn test_no_fix_when_counter_used_after_loop() {
+    // The counter's final value is read after the loop, so it must stay `mut`
+    let code = r"
+mut i = 0
+while $i < 10 {
+    print $i
+    $i += 1
+}
+print $i
+";
+    RULE.assert_count(code, 1);
+    RULE.assert_no_fix(code);
+}
+
+#[test]
+fn test_no_fix_with_continue() {
+    // `continue` skips the increment, a `for` loop would not
+    let code = r"
+mut i = 0
+while $i < 10 {
+    if $i == 3 { continue }
+    $i += 1
+}
+";
+    RULE.assert_detects(code);
+    RULE.assert_no_fix(code);
+}
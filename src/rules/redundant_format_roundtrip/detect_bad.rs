@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn detects_json_round_trip() {
+    RULE.assert_detects(r"let copy = $config | to json | from json");
+}
+
+#[test]
+fn detects_raw_json_round_trip() {
+    RULE.assert_detects(r"let copy = $config | to json -r | from json");
+}
+
+#[test]
+fn detects_round_trip_with_collect_in_between() {
+    RULE.assert_detects(r"let rows = ls | to csv | collect | from csv");
+}
+
+#[test]
+fn detects_round_trip_in_command() {
+    let bad_code = r"
+def normalize [] {
+    $in | to yaml | from yaml | sort-by name
+}
+";
+    RULE.assert_count(bad_code, 1);
+}
@@ -0,0 +1,17 @@
+use super::RULE;
+
+#[test]
+fn fix_removes_both_stages() {
+    RULE.assert_fixed_is(
+        r"let copy = $config | to json | from json",
+        r"let copy = $config",
+    );
+}
+
+#[test]
+fn fix_removes_no_op_stages_in_between() {
+    RULE.assert_fixed_is(
+        r"let names = ls | to nuon | into string | from nuon | get name",
+        r"let names = ls | get name",
+    );
+}
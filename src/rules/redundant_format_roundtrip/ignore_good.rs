@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn ignores_different_formats() {
+    RULE.assert_ignores(r"let data = $config | to json | from yaml");
+}
+
+#[test]
+fn ignores_serialization_only() {
+    RULE.assert_ignores(r"$config | to json | save config.json");
+}
+
+#[test]
+fn ignores_stage_in_between() {
+    RULE.assert_ignores(r"let data = $config | to json | str replace 'a' 'b' | from json");
+}
+
+#[test]
+fn ignores_noheaders() {
+    RULE.assert_ignores(r"let rows = ls | to csv --noheaders | from csv");
+}
+
+#[test]
+fn ignores_from_with_flags() {
+    RULE.assert_ignores(r"let rows = ls | to csv | from csv --noheaders");
+}
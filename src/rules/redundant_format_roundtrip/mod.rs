@@ -0,0 +1,146 @@
+use lsp_types::DiagnosticTag;
+use nu_protocol::{
+    Span,
+    ast::{Argument, Call, Expr, Pipeline, PipelineElement},
+};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+const FORMATS: &[&str] = &["csv", "json", "nuon", "toml", "tsv", "yaml"];
+
+/// Flags of `to <fmt>` that only change how the text is laid out
+const LAYOUT_FLAGS: &[&str] = &["raw", "r", "indent", "i", "tabs", "t"];
+
+struct FixData {
+    /// From the end of the element before `to` up to the end of `from`
+    removal_span: Span,
+}
+
+const fn element_call(element: &PipelineElement) -> Option<&Call> {
+    match &element.expr.expr {
+        Expr::Call(call) => Some(call),
+        _ => None,
+    }
+}
+
+/// Format written by a `to <fmt>` stage whose flags don't affect the parsed
+/// result
+fn serialized_format(call: &Call, context: &LintContext) -> Option<&'static str> {
+    let name = call.get_call_name(context);
+    let format = name.strip_prefix("to ")?;
+    let only_layout_flags = call.arguments.iter().all(|arg| {
+        matches!(arg, Argument::Named(named) if LAYOUT_FLAGS.contains(&named.0.item.as_str()))
+    });
+    only_layout_flags
+        .then(|| FORMATS.iter().copied().find(|fmt| *fmt == format))
+        .flatten()
+}
+
+fn is_plain_from(call: &Call, format: &str, context: &LintContext) -> bool {
+    call.arguments.is_empty() && call.get_call_name(context).strip_prefix("from ") == Some(format)
+}
+
+/// Stages that pass the serialized text through unchanged
+fn is_no_op(call: &Call, context: &LintContext) -> bool {
+    call.arguments.is_empty()
+        && (call.is_call_to_command("collect", context)
+            || call.is_call_to_command("into string", context))
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, FixData)> {
+    let elements = &pipeline.elements;
+    (1..elements.len())
+        .filter_map(|to_index| {
+            let format = serialized_format(element_call(&elements[to_index])?, context)?;
+            let from_offset = elements[to_index + 1..].iter().position(|element| {
+                element_call(element).is_none_or(|call| !is_no_op(call, context))
+            })?;
+            let from_element = &elements[to_index + 1 + from_offset];
+            if !is_plain_from(element_call(from_element)?, format, context) {
+                return None;
+            }
+
+            let to_span = elements[to_index].expr.span;
+            let detection = Detection::from_global_span(
+                format!("Data is converted to {format} and immediately parsed back"),
+                Span::new(to_span.start, from_element.expr.span.end),
+            )
+            .with_primary_label(format!("{format} round-trip"))
+            .with_extra_label("serialized here", to_span)
+            .with_extra_label("parsed again here", from_element.expr.span);
+
+            Some((
+                detection,
+                FixData {
+                    removal_span: Span::new(
+                        elements[to_index - 1].expr.span.end,
+                        from_element.expr.span.end,
+                    ),
+                },
+            ))
+        })
+        .collect()
+}
+
+struct RedundantFormatRoundtrip;
+
+impl DetectFix for RedundantFormatRoundtrip {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "redundant_format_roundtrip"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`to <format> | from <format>` that parses its own output"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Serializing structured data and parsing the text straight back costs time and can \
+             lose information: CSV flattens nested values and JSON turns dates into strings. \
+             Nushell pipelines carry structured data, so both stages can usually be dropped. Bare \
+             `collect` or `into string` stages in between are removed as well. Round-trips \
+             through two different formats, or with flags that change the parsed result, are not \
+             reported.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/loading_data.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn diagnostic_tags(&self) -> &'static [DiagnosticTag] {
+        &[DiagnosticTag::UNNECESSARY]
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.ast.detect_in_pipelines(context, check_pipeline)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: "Remove the serialization round-trip".into(),
+            replacements: vec![Replacement::new(fix_data.removal_span, String::new())],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &RedundantFormatRoundtrip;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
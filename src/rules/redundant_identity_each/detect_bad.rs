@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn detects_dollar_in_closure() {
+    RULE.assert_detects(r"[1 2 3] | each { $in }");
+}
+
+#[test]
+fn detects_parameter_closure() {
+    RULE.assert_detects(r"[1 2 3] | each {|x| $x }");
+}
+
+#[test]
+fn detects_in_middle_of_pipeline() {
+    RULE.assert_detects(r"ls | each {|f| $f } | get name");
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r"
+def names [] {
+    ls | get name | each {|n| $n }
+}
+";
+    RULE.assert_detects(bad_code);
+}
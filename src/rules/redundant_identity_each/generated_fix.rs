@@ -0,0 +1,11 @@
+use super::RULE;
+
+#[test]
+fn fix_removes_stage() {
+    RULE.assert_fixed_is(r"[1 2 3] | each {|x| $x }", r"[1 2 3]");
+}
+
+#[test]
+fn fix_keeps_later_stages() {
+    RULE.assert_fixed_is(r"ls | each { $in } | get name", r"ls | get name");
+}
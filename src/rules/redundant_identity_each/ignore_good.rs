@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn ignore_field_access() {
+    RULE.assert_ignores(r"ls | each { $in.name }");
+}
+
+#[test]
+fn ignore_parameter_field_access() {
+    RULE.assert_ignores(r"ls | each {|f| $f.name }");
+}
+
+#[test]
+fn ignore_method_applied() {
+    RULE.assert_ignores(r"['a ' ' b'] | each {|s| $s | str trim }");
+}
+
+#[test]
+fn ignore_other_variable() {
+    RULE.assert_ignores(r"let y = 0; [1 2] | each {|x| $y }");
+}
+
+#[test]
+fn ignore_flags() {
+    RULE.assert_ignores(r"[1 null 2] | each --keep-empty {|x| $x }");
+}
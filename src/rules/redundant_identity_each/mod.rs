@@ -0,0 +1,142 @@
+use lsp_types::DiagnosticTag;
+use nu_protocol::{
+    Span,
+    ast::{Call, Expr, Expression, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::{
+        block::BlockExt,
+        call::CallExt,
+        expression::{ExpressionExt, is_pipeline_input_var},
+    },
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    /// The `| each { ... }` stage, from the end of the previous element
+    stage_span: Span,
+}
+
+/// Whether the closure returns its element unchanged: `{ $in }` or
+/// `{|x| $x }`
+fn is_identity_closure(expr: &Expression, context: &LintContext) -> bool {
+    let Expr::Closure(block_id) = &expr.expr else {
+        return false;
+    };
+    let block = context.working_set.get_block(*block_id);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return false;
+    };
+    let [element] = pipeline.elements.as_slice() else {
+        return false;
+    };
+
+    let body = match &element.expr.expr {
+        Expr::Collect(_, inner) => inner,
+        _ => &element.expr,
+    };
+    // A bare variable only: `$in.name` or `$x | str trim` are not identities
+    let Some(var_id) = body.extract_direct_var() else {
+        return false;
+    };
+    let param = match block.signature.required_positional.as_slice() {
+        [] => None,
+        [param] => param.var_id,
+        _ => return false,
+    };
+    param == Some(var_id) || is_pipeline_input_var(var_id, context)
+}
+
+fn is_identity_each(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("each", context)
+        && call.arguments.len() == 1
+        && call
+            .get_first_positional_arg()
+            .is_some_and(|closure| is_identity_closure(closure, context))
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, FixData)> {
+    pipeline
+        .elements
+        .windows(2)
+        .filter_map(|pair| {
+            let Expr::Call(call) = &pair[1].expr.expr else {
+                return None;
+            };
+            if !is_identity_each(call, context) {
+                return None;
+            }
+            let each_span = pair[1].expr.span;
+            let detection = Detection::from_global_span(
+                "`each` returns every element unchanged; remove it",
+                each_span,
+            )
+            .with_primary_label("identity closure");
+            Some((
+                detection,
+                FixData {
+                    stage_span: Span::new(pair[0].expr.span.end, each_span.end),
+                },
+            ))
+        })
+        .collect()
+}
+
+struct RedundantIdentityEach;
+
+impl DetectFix for RedundantIdentityEach {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "redundant_identity_each"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`each` whose closure returns its input unchanged"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`each { $in }` and `each {|x| $x }` map every element to itself, so the stage only \
+             costs a closure call per element. The one difference is that `each` drops `null` \
+             results, so check that the input contains no nulls that should be filtered before \
+             removing it.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/each.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn diagnostic_tags(&self) -> &'static [DiagnosticTag] {
+        &[DiagnosticTag::UNNECESSARY]
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.ast.detect_in_pipelines(context, check_pipeline)
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: "Remove the identity `each`".into(),
+            replacements: vec![Replacement::new(fix_data.stage_span, String::new())],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &RedundantIdentityEach;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
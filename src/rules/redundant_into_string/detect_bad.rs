@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn detects_before_print_in_pipeline() {
+    RULE.assert_detects(r"let n = 5; $n | into string | print");
+}
+
+#[test]
+fn detects_print_argument() {
+    RULE.assert_detects(r"let n = 5; print ($n | into string)");
+}
+
+#[test]
+fn detects_interpolation() {
+    RULE.assert_detects(r#"let n = 5; print $"total: ($n | into string)""#);
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r#"
+def report [count: int] {
+    $"found ($count | into string) items"
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
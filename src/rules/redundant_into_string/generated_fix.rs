@@ -0,0 +1,25 @@
+use super::RULE;
+
+#[test]
+fn fix_removes_stage_before_print() {
+    RULE.assert_fixed_is(
+        r"let n = 5; $n | into string | print",
+        r"let n = 5; $n | print",
+    );
+}
+
+#[test]
+fn fix_print_argument() {
+    RULE.assert_fixed_is(
+        r"let n = 5; print ($n | into string)",
+        r"let n = 5; print ($n)",
+    );
+}
+
+#[test]
+fn fix_interpolation() {
+    RULE.assert_fixed_is(
+        r#"let n = 5; $"total: ($n | into string)""#,
+        r#"let n = 5; $"total: ($n)""#,
+    );
+}
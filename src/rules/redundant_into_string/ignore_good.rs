@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn ignore_group_digits() {
+    RULE.assert_ignores(r"let n = 5000; print ($n | into string --group-digits)");
+}
+
+#[test]
+fn ignore_decimals_in_interpolation() {
+    RULE.assert_ignores(r#"let x = 1.2345; $"value: ($x | into string --decimals 2)""#);
+}
+
+#[test]
+fn ignore_value_kept_as_string() {
+    RULE.assert_ignores(r"let n = 5; let s = $n | into string; $s | str length");
+}
+
+#[test]
+fn ignore_print_with_arguments_after_conversion() {
+    RULE.assert_ignores(r"let n = 5; $n | into string | print 'other'");
+}
+
+#[test]
+fn ignore_string_command_after_conversion() {
+    RULE.assert_ignores(r#"let n = 5; $"($n | into string | str length)""#);
+}
@@ -0,0 +1,168 @@
+use lsp_types::DiagnosticTag;
+use nu_protocol::{
+    Span,
+    ast::{Argument, Call, Expr, Expression, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, expression::ExpressionExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    /// The `| into string` stage, from the end of the previous element
+    stage_span: Span,
+}
+
+/// An `into string` without flags or cell paths. Flags like `--group-digits`
+/// change the text and are kept.
+fn is_bare_into_string(expr: &Expression, context: &LintContext) -> bool {
+    let Expr::Call(call) = &expr.expr else {
+        return false;
+    };
+    call.is_call_to_command("into string", context) && call.arguments.is_empty()
+}
+
+fn is_print_of_input(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("print", context) && call.get_first_positional_arg().is_none()
+}
+
+fn redundant_stage_at(
+    pipeline: &Pipeline,
+    index: usize,
+    reason: &str,
+) -> Option<(Detection, FixData)> {
+    let previous = pipeline.elements.get(index.checked_sub(1)?)?;
+    let stage_span = pipeline.elements[index].expr.span;
+
+    let detection =
+        Detection::from_global_span(format!("`into string` is redundant: {reason}"), stage_span)
+            .with_primary_label("redundant conversion");
+
+    Some((
+        detection,
+        FixData {
+            stage_span: Span::new(previous.expr.span.end, stage_span.end),
+        },
+    ))
+}
+
+/// `... | into string | print`
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, FixData)> {
+    pipeline
+        .elements
+        .windows(2)
+        .enumerate()
+        .filter_map(|(index, pair)| {
+            let Expr::Call(print) = &pair[1].expr.expr else {
+                return None;
+            };
+            (is_bare_into_string(&pair[0].expr, context) && is_print_of_input(print, context))
+                .then(|| redundant_stage_at(pipeline, index, "`print` converts its input to text"))
+                .flatten()
+        })
+        .collect()
+}
+
+/// `(... | into string)` whose value is only used as text
+fn check_subexpression(
+    expr: &Expression,
+    reason: &str,
+    context: &LintContext,
+) -> Option<(Detection, FixData)> {
+    let block = context.working_set.get_block(expr.extract_block_id()?);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return None;
+    };
+    let last_index = pipeline.elements.len().checked_sub(1)?;
+    is_bare_into_string(&pipeline.elements[last_index].expr, context)
+        .then(|| redundant_stage_at(pipeline, last_index, reason))
+        .flatten()
+}
+
+/// `print ($x | into string)` and `$"($x | into string)"`
+fn check_expression(expr: &Expression, context: &LintContext) -> Vec<(Detection, FixData)> {
+    match &expr.expr {
+        Expr::Call(call) if call.is_call_to_command("print", context) => call
+            .arguments
+            .iter()
+            .filter_map(|arg| match arg {
+                Argument::Positional(arg) => {
+                    check_subexpression(arg, "`print` converts its arguments to text", context)
+                }
+                _ => None,
+            })
+            .collect(),
+        Expr::StringInterpolation(items) => items
+            .iter()
+            .filter_map(|item| {
+                check_subexpression(
+                    item,
+                    "string interpolation converts values to text",
+                    context,
+                )
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+struct RedundantIntoString;
+
+impl DetectFix for RedundantIntoString {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "redundant_into_string"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`into string` before `print` or inside an interpolation"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`print` and string interpolation already turn numbers, dates, durations and other \
+             values into text, so `print ($n | into string)` and `$\"total: ($n | into string)\"` \
+             convert twice. Flags like `--group-digits` or `--decimals` produce different text \
+             and are not reported.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/into_string.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn diagnostic_tags(&self) -> &'static [DiagnosticTag] {
+        &[DiagnosticTag::UNNECESSARY]
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let mut detections = context.ast.detect_in_pipelines(context, check_pipeline);
+        detections.extend(context.detect_with_fix_data(check_expression));
+        detections
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: "Remove `into string`".into(),
+            replacements: vec![Replacement::new(fix_data.stage_span, String::new())],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &RedundantIntoString;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
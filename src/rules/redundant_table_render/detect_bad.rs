@@ -0,0 +1,23 @@
+use super::RULE;
+
+#[test]
+fn detects_table_before_print() {
+    let bad_code = r"
+def show [] {
+    ls | table | print
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_table_at_end_of_script() {
+    let bad_code = r"ls | sort-by size | table";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_table_before_print_in_closure() {
+    let bad_code = r"[[a]; [1]] | each {|row| $row | table | print }";
+    RULE.assert_count(bad_code, 1);
+}
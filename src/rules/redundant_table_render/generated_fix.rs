@@ -0,0 +1,22 @@
+use super::RULE;
+
+#[test]
+fn fix_removes_table_before_print() {
+    let bad_code = r"
+def show [] {
+    ls | table | print
+}
+";
+    let expected = r"
+def show [] {
+    ls | print
+}
+";
+    RULE.assert_fixed_is(bad_code, expected);
+}
+
+#[test]
+fn fix_removes_trailing_table() {
+    let bad_code = r"ls | sort-by size | table";
+    RULE.assert_fixed_is(bad_code, r"ls | sort-by size");
+}
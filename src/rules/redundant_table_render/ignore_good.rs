@@ -0,0 +1,41 @@
+use super::RULE;
+
+#[test]
+fn ignores_expanded_table() {
+    let good_code = r"ls | table --expand | print";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_short_expand_flag() {
+    let good_code = r"ls | table -e";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_width_flag() {
+    let good_code = r"ls | table --width 80 | print";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_rendered_string_saved_to_file() {
+    let good_code = r"ls | table | save listing.txt";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_table_at_end_of_command_body() {
+    let good_code = r"
+def listing [] {
+    ls | table
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_print_with_argument() {
+    let good_code = r#"ls | table | print "done""#;
+    RULE.assert_ignores(good_code);
+}
@@ -0,0 +1,148 @@
+use lsp_types::DiagnosticTag;
+use nu_protocol::{
+    Span,
+    ast::{Call, Expr, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    /// The `| table` stage, from the end of the previous element
+    stage_span: Span,
+}
+
+/// A `table` call without flags, which renders exactly like the default output
+fn is_bare_table(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("table", context) && call.arguments.is_empty()
+}
+
+fn is_print_of_input(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("print", context) && call.get_first_positional_arg().is_none()
+}
+
+fn redundant_table_at(
+    pipeline: &Pipeline,
+    index: usize,
+    reason: &str,
+) -> Option<(Detection, FixData)> {
+    let previous = pipeline.elements.get(index.checked_sub(1)?)?;
+    let table_span = pipeline.elements[index].expr.span;
+
+    let detection = Detection::from_global_span(
+        format!("`table` without flags is redundant: {reason}"),
+        table_span,
+    )
+    .with_primary_label("redundant `table`");
+
+    Some((
+        detection,
+        FixData {
+            stage_span: Span::new(previous.expr.span.end, table_span.end),
+        },
+    ))
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, FixData)> {
+    pipeline
+        .elements
+        .windows(2)
+        .enumerate()
+        .filter_map(|(index, pair)| {
+            let (Expr::Call(table), Expr::Call(print)) = (&pair[0].expr.expr, &pair[1].expr.expr)
+            else {
+                return None;
+            };
+            (is_bare_table(table, context) && is_print_of_input(print, context))
+                .then(|| {
+                    redundant_table_at(pipeline, index, "`print` already renders values as a table")
+                })
+                .flatten()
+        })
+        .collect()
+}
+
+/// A bare `table` ending a top-level pipeline, whose value is displayed anyway
+fn check_top_level(pipeline: &Pipeline, context: &LintContext) -> Option<(Detection, FixData)> {
+    let last_index = pipeline.elements.len().checked_sub(1)?;
+    let Expr::Call(call) = &pipeline.elements[last_index].expr.expr else {
+        return None;
+    };
+    is_bare_table(call, context)
+        .then(|| {
+            redundant_table_at(
+                pipeline,
+                last_index,
+                "the output of a top-level pipeline is rendered as a table",
+            )
+        })
+        .flatten()
+}
+
+struct RedundantTableRender;
+
+impl DetectFix for RedundantTableRender {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "redundant_table_render"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Bare `table` before `print` or at the end of the output"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Structured values are rendered as a table whenever they are displayed, both by \
+             `print` and when they are the result of a top-level pipeline. An explicit `table` \
+             without flags in those positions only converts the value to a string earlier. Keep \
+             `table` when flags such as `--expand` or `--width` change the rendering.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/table.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn diagnostic_tags(&self) -> &'static [DiagnosticTag] {
+        &[DiagnosticTag::UNNECESSARY]
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let mut detections = context.ast.detect_in_pipelines(context, check_pipeline);
+        detections.extend(
+            context
+                .ast
+                .pipelines
+                .iter()
+                .filter_map(|pipeline| check_top_level(pipeline, context)),
+        );
+        detections
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: "Remove `table`".into(),
+            replacements: vec![Replacement::new(fix_data.stage_span, String::new())],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &RedundantTableRender;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
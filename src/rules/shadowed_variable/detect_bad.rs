@@ -0,0 +1,28 @@
+use super::RULE;
+
+#[test]
+fn detects_redeclared_let() {
+    RULE.assert_detects("let name = 'a'\nlet name = 'b'\nprint $name");
+}
+
+#[test]
+fn detects_let_after_mut() {
+    RULE.assert_detects("mut count = 0\nlet count = 10\nprint $count");
+}
+
+#[test]
+fn detects_in_command_body() {
+    let bad_code = r"
+def main [] {
+    let files = ls
+    let files = ls src
+    $files | length
+}
+";
+    RULE.assert_count(bad_code, 1);
+}
+
+#[test]
+fn labels_previous_declaration() {
+    RULE.assert_labels_contain("let x = 1\nlet x = 2\nprint $x", "previously declared here");
+}
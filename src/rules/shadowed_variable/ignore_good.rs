@@ -0,0 +1,26 @@
+use super::RULE;
+
+#[test]
+fn ignores_rebinding_from_previous_value() {
+    RULE.assert_ignores("let x = 1\nlet x = $x + 1\nprint $x");
+}
+
+#[test]
+fn ignores_rebinding_through_pipeline() {
+    RULE.assert_ignores("let items = [3 1 2]\nlet items = $items | sort\nprint $items");
+}
+
+#[test]
+fn ignores_different_names() {
+    RULE.assert_ignores("let a = 1\nlet b = 2\nprint ($a + $b)");
+}
+
+#[test]
+fn ignores_nested_block() {
+    RULE.assert_ignores("let x = 1\nif true { let x = 2; print $x }\nprint $x");
+}
+
+#[test]
+fn ignores_underscore_names() {
+    RULE.assert_ignores("let _ = 1\nlet _ = 2");
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use nu_protocol::{
+    Span, VarId,
+    ast::{Block, Call, Expr, Expression, Traverse},
+};
+
+use crate::{
+    LintLevel,
+    ast::call::CallExt,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Whether the initializer of the declaration reads the variable, as in
+/// `let x = $x + 1`
+fn initializer_uses(call: &Call, var_id: VarId, context: &LintContext) -> bool {
+    let Some(value) = call.get_positional_arg(1) else {
+        return false;
+    };
+    let mut usages = Vec::new();
+    value.flat_map(
+        context.working_set,
+        &|expr| match &expr.expr {
+            Expr::Var(id) if *id == var_id => vec![expr.span],
+            _ => vec![],
+        },
+        &mut usages,
+    );
+    !usages.is_empty()
+}
+
+fn check_block(block: &Block, context: &LintContext) -> Vec<Detection> {
+    let mut declared: HashMap<String, (VarId, Span)> = HashMap::new();
+    let mut detections = Vec::new();
+
+    for pipeline in &block.pipelines {
+        let [element] = pipeline.elements.as_slice() else {
+            continue;
+        };
+        let Expr::Call(call) = &element.expr.expr else {
+            continue;
+        };
+        let Some((var_id, var_name, var_span)) = call.extract_variable_declaration(context) else {
+            continue;
+        };
+
+        if let Some(&(previous_id, previous_span)) = declared.get(&var_name)
+            && !var_name.starts_with('_')
+            && !initializer_uses(call, previous_id, context)
+        {
+            detections.push(
+                Detection::from_global_span(
+                    format!("'{var_name}' is declared again and hides the previous binding"),
+                    var_span,
+                )
+                .with_primary_label("shadows an earlier variable")
+                .with_extra_label("previously declared here", previous_span),
+            );
+        }
+        declared.insert(var_name, (var_id, var_span));
+    }
+
+    detections
+}
+
+fn check_expression(expr: &Expression, context: &LintContext) -> Vec<Detection> {
+    match &expr.expr {
+        Expr::Block(block_id) | Expr::Closure(block_id) | Expr::Subexpression(block_id) => {
+            check_block(context.working_set.get_block(*block_id), context)
+        }
+        _ => vec![],
+    }
+}
+
+struct ShadowedVariable;
+
+impl DetectFix for ShadowedVariable {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "shadowed_variable"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Variable declared again in the same block"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A second `let` or `mut` with the same name makes the earlier variable unreachable \
+             for the rest of the block. This is allowed in Nushell, but it is easy to miss when \
+             reading the code, and often one of the two was meant to have another name. Rebinding \
+             that builds on the old value, like `let x = $x + 1`, is not reported. Variables \
+             whose name starts with `_` are ignored.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/variables.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let mut detections = check_block(context.ast, context);
+        detections.extend(context.detect(check_expression));
+        Self::no_fix(detections)
+    }
+}
+
+pub static RULE: &dyn Rule = &ShadowedVariable;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
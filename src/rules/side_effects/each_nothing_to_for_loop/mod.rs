@@ -3,9 +3,10 @@ use nu_protocol::{
     ast::{Block, Expr, Expression},
 };
 
+use super::block_has_only_side_effects;
 use crate::{
     Fix, LintLevel, Replacement,
-    ast::{block::BlockExt, call::CallExt},
+    ast::call::CallExt,
     context::LintContext,
     rule::{DetectFix, Rule},
     violation::Detection,
@@ -19,49 +20,6 @@ pub struct FixData {
     pipeline_elements_before_each: usize,
 }
 
-/// Checks if an expression returns Nothing (only side effects, no data)
-fn returns_nothing(expr: &Expression, ctx: &LintContext) -> bool {
-    match &expr.expr {
-        Expr::Call(call) => {
-            let decl = ctx.working_set.get_decl(call.decl_id);
-            let sig = decl.signature();
-
-            // If all output types are Nothing, then this returns nothing
-            sig.input_output_types
-                .iter()
-                .all(|(_in, out)| matches!(out, nu_protocol::Type::Nothing))
-        }
-        _ => false,
-    }
-}
-
-/// Checks if a block contains only side effects (no return values used)
-fn block_has_only_side_effects(block_id: nu_protocol::BlockId, ctx: &LintContext) -> bool {
-    let block = ctx.working_set.get_block(block_id);
-
-    // Use the block's inferred output type
-    let output_type = block.infer_output_type(ctx);
-
-    // If the block returns Nothing, it's side-effect-only
-    if matches!(output_type, nu_protocol::Type::Nothing) {
-        return true;
-    }
-
-    // For Type::Any, check the last pipeline element
-    if matches!(output_type, nu_protocol::Type::Any) {
-        let Some(last_pipeline) = block.pipelines.last() else {
-            return false;
-        };
-        let Some(last_element) = last_pipeline.elements.last() else {
-            return false;
-        };
-
-        return returns_nothing(&last_element.expr, ctx);
-    }
-
-    false
-}
-
 fn extract_pipeline_info(expr: &Expression, ctx: &LintContext) -> Option<(Span, usize)> {
     for pipeline in &ctx.ast.pipelines {
         for (i, elem) in pipeline.elements.iter().enumerate() {
@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn detects_git_commit() {
+    RULE.assert_detects(r#"^git commit -m "update""#);
+}
+
+#[test]
+fn detects_http_post() {
+    RULE.assert_detects(r"http post https://example.com/api {name: test}");
+}
+
+#[test]
+fn detects_each_statement() {
+    let bad_code = r#"
+^git add .
+^git commit -m "update"
+http put https://example.com/api/1 {done: true}
+"#;
+    RULE.assert_count(bad_code, 2);
+}
@@ -0,0 +1,35 @@
+use super::RULE;
+
+#[test]
+fn ignores_piped_into_ignore() {
+    RULE.assert_ignores(r#"^git commit -m "update" | ignore"#);
+}
+
+#[test]
+fn ignores_read_only_commands() {
+    let good_code = r"
+^git status
+http get https://example.com/api
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_streaming_output() {
+    RULE.assert_ignores(r"^git pull");
+}
+
+#[test]
+fn ignores_assigned_result() {
+    RULE.assert_ignores(r"let response = http post https://example.com/api {name: test}");
+}
+
+#[test]
+fn ignores_command_bodies() {
+    let good_code = r#"
+def save-work [] {
+    ^git commit -m "update"
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
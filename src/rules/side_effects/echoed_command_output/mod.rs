@@ -0,0 +1,105 @@
+use nu_protocol::ast::{Expr, Expression, Pipeline};
+
+use crate::{
+    LintLevel,
+    ast::call::CallExt,
+    context::LintContext,
+    effect::external::{ExternEffect, has_external_side_effect},
+    rule::{DetectFix, Rule},
+    rules::redundant_ignore,
+    violation::Detection,
+};
+
+/// Built-in commands that change remote state and also return the response
+const MUTATING_BUILTINS: &[&str] = &["http post", "http put", "http patch", "http delete"];
+
+/// Whether the command is run for its effect but also produces output that
+/// would be shown
+fn echoes_output(expr: &Expression, context: &LintContext) -> bool {
+    match &expr.expr {
+        Expr::ExternalCall(head, args) => {
+            let name = context.expr_text(head).trim_start_matches('^');
+            let has = |effect| has_external_side_effect(name, effect, context, args);
+            (has(ExternEffect::ModifiesFileSystem) || has(ExternEffect::ModifiesNetworkState))
+                && !has(ExternEffect::NoDataInStdout)
+                && !has(ExternEffect::SlowStreamingOutput)
+        }
+        Expr::Call(call) => {
+            let name = call.get_call_name(context);
+            MUTATING_BUILTINS.contains(&name.as_str())
+                && context
+                    .working_set
+                    .get_decl(call.decl_id)
+                    .signature()
+                    .get_output_type()
+                    != nu_protocol::Type::Nothing
+        }
+        _ => false,
+    }
+}
+
+fn check_top_level(pipeline: &Pipeline, context: &LintContext) -> Option<Detection> {
+    let last = pipeline.elements.last()?;
+    echoes_output(&last.expr, context).then(|| {
+        Detection::from_global_span(
+            "Output of this command is printed although it only runs for its side effect; append \
+             `| ignore` to silence it",
+            last.expr.span,
+        )
+        .with_primary_label("output is echoed")
+    })
+}
+
+struct EchoedCommandOutput;
+
+impl DetectFix for EchoedCommandOutput {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "echoed_command_output"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Top-level side-effect command whose output gets printed"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "The result of a top-level pipeline is displayed. Commands that are run for their \
+             side effect, such as `^git commit` or `http post`, still return text or a response \
+             body, which then clutters the script output. Pipe them into `ignore` when the output \
+             is not wanted. Opt-in, and conflicts with `redundant_ignore`.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/ignore.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Off
+    }
+
+    fn conflicts_with(&self) -> &'static [&'static dyn Rule] {
+        static CONFLICTS: &[&dyn Rule] = &[redundant_ignore::RULE];
+        CONFLICTS
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(
+            context
+                .ast
+                .pipelines
+                .iter()
+                .filter_map(|pipeline| check_top_level(pipeline, context))
+                .collect(),
+        )
+    }
+}
+
+pub static RULE: &dyn Rule = &EchoedCommandOutput;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
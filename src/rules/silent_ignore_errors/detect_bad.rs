@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn detects_bare_do_ignore_errors() {
+    RULE.assert_detects(r"do -i { rm old.log }");
+}
+
+#[test]
+fn detects_long_flag() {
+    RULE.assert_detects(r"do --ignore-errors { ^git fetch }");
+}
+
+#[test]
+fn detects_unchecked_binding() {
+    let bad_code = r"
+let config = do -i { open config.toml }
+print $config
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_inside_command() {
+    let bad_code = r"
+def cleanup [] {
+    do -i { rm -r build }
+    print done
+}
+";
+    RULE.assert_count(bad_code, 1);
+}
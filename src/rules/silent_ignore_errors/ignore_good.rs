@@ -0,0 +1,34 @@
+use super::RULE;
+
+#[test]
+fn ignores_plain_do() {
+    RULE.assert_ignores(r"do { print hello }");
+}
+
+#[test]
+fn ignores_try_catch() {
+    RULE.assert_ignores(r"try { rm old.log } catch {|err| print $err.msg }");
+}
+
+#[test]
+fn ignores_result_compared_to_null() {
+    let good_code = r#"
+let config = do -i { open config.toml }
+if $config == null { print "no config" }
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_result_piped_into_default() {
+    RULE.assert_ignores(r"let config = do -i { open config.toml } | default {}");
+}
+
+#[test]
+fn ignores_variable_checked_with_is_empty() {
+    let good_code = r#"
+let output = do -i { ^git describe --tags }
+if ($output | is-empty) { print "untagged" }
+"#;
+    RULE.assert_ignores(good_code);
+}
@@ -0,0 +1,172 @@
+use std::collections::{HashMap, HashSet};
+
+use nu_protocol::{
+    Span, VarId,
+    ast::{Call, Comparison, Expr, Expression, Operator, Pipeline, Traverse},
+};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, expression::ExpressionExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Commands that react to a missing (`null`) result
+const NULL_CHECKS: &[&str] = &["default", "is-empty", "is-not-empty"];
+
+fn is_do_ignore_errors(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("do", context)
+        && (call.has_named_flag("ignore-errors") || call.has_named_flag("i"))
+}
+
+fn is_null_check(expr: &Expression, context: &LintContext) -> bool {
+    matches!(&expr.expr, Expr::Call(call) if NULL_CHECKS.iter().any(|name| call.is_call_to_command(name, context)))
+}
+
+fn var_of(expr: &Expression) -> Option<VarId> {
+    match &expr.expr {
+        Expr::Var(var_id) => Some(*var_id),
+        Expr::FullCellPath(cell_path) => var_of(&cell_path.head),
+        _ => None,
+    }
+}
+
+/// `do -i` calls whose result is piped straight into a null check, and
+/// variables that are piped into one
+fn null_checked_in_pipeline(
+    pipeline: &Pipeline,
+    context: &LintContext,
+) -> Vec<(Span, Option<VarId>)> {
+    pipeline
+        .elements
+        .windows(2)
+        .filter(|pair| is_null_check(&pair[1].expr, context))
+        .map(|pair| (pair[0].expr.span, var_of(&pair[0].expr)))
+        .collect()
+}
+
+/// Variables compared against `null`, as in `$result == null`
+fn null_compared_var(expr: &Expression) -> Option<VarId> {
+    let Expr::BinaryOp(lhs, op, rhs) = &expr.expr else {
+        return None;
+    };
+    if !matches!(
+        op.expr,
+        Expr::Operator(Operator::Comparison(
+            Comparison::Equal | Comparison::NotEqual
+        ))
+    ) {
+        return None;
+    }
+    match (&lhs.expr, &rhs.expr) {
+        (Expr::Nothing, _) => var_of(rhs),
+        (_, Expr::Nothing) => var_of(lhs),
+        _ => None,
+    }
+}
+
+/// Maps the span of a `do` call bound with `let`/`mut` to its variable
+fn bound_results(context: &LintContext) -> HashMap<Span, VarId> {
+    let mut bindings = Vec::new();
+    context.ast.flat_map(
+        context.working_set,
+        &|expr| {
+            let Expr::Call(call) = &expr.expr else {
+                return vec![];
+            };
+            let Some((var_id, _, _)) = call.extract_variable_declaration(context) else {
+                return vec![];
+            };
+            call.get_positional_arg(1)
+                .map(|value| (value.unwrap_block_expr(context).span, var_id))
+                .into_iter()
+                .collect()
+        },
+        &mut bindings,
+    );
+    bindings.into_iter().collect()
+}
+
+struct SilentIgnoreErrors;
+
+impl DetectFix for SilentIgnoreErrors {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "silent_ignore_errors"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`do --ignore-errors` whose result is never checked"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`do -i { ... }` turns every error inside the block into `null`, like a `try` with an \
+             empty `catch`. When the result is not checked afterwards, failures go unnoticed. Use \
+             `try { ... } catch {|err| ... }` to log or handle the error, or check the result \
+             with `== null`, `is-empty` or `default`.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/do.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let piped_checks = context
+            .ast
+            .detect_in_pipelines(context, null_checked_in_pipeline);
+        let checked_spans: HashSet<Span> = piped_checks.iter().map(|(span, _)| *span).collect();
+
+        let mut compared = Vec::new();
+        context.ast.flat_map(
+            context.working_set,
+            &|expr| null_compared_var(expr).into_iter().collect(),
+            &mut compared,
+        );
+        let checked_vars: HashSet<VarId> = piped_checks
+            .iter()
+            .filter_map(|(_, var_id)| *var_id)
+            .chain(compared)
+            .collect();
+
+        let bound = bound_results(context);
+
+        Self::no_fix(context.detect_single(|expr, ctx| {
+            let Expr::Call(call) = &expr.expr else {
+                return None;
+            };
+            if !is_do_ignore_errors(call, ctx)
+                || checked_spans.contains(&expr.span)
+                || bound
+                    .get(&expr.span)
+                    .is_some_and(|var_id| checked_vars.contains(var_id))
+            {
+                return None;
+            }
+            Some(
+                Detection::from_global_span(
+                    "`do --ignore-errors` discards errors silently; handle them with `try` and \
+                     `catch`, or check the result for `null`",
+                    call.head,
+                )
+                .with_primary_label("errors are swallowed")
+                .with_extra_span(expr.span),
+            )
+        }))
+    }
+}
+
+pub static RULE: &dyn Rule = &SilentIgnoreErrors;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
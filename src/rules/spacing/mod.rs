@@ -1,6 +1,7 @@
 pub mod block_brace_spacing;
 pub mod closure_brace_pipe_spacing;
 pub mod closure_pipe_body_spacing;
+pub mod multiline_trailing_comma;
 pub mod no_trailing_spaces;
 pub mod omit_list_commas;
 pub mod pipe_spacing;
@@ -36,6 +37,20 @@ pub fn has_explicit_pipe_delimiters(context: &LintContext, span: Span) -> bool {
     chars.find(|c| !c.is_whitespace()) == Some('|')
 }
 
+/// Finds the byte offset of the first comma in the whitespace between two
+/// items, skipping commas that appear inside `#` comments.
+pub fn find_comma_outside_comment(between_text: &str) -> Option<usize> {
+    let mut offset = 0;
+    for line in between_text.split('\n') {
+        let comment_start = line.find('#').unwrap_or(line.len());
+        if let Some(comma_pos) = line[..comment_start].find(',') {
+            return Some(offset + comma_pos);
+        }
+        offset += line.len() + 1;
+    }
+    None
+}
+
 /// Determines if a type is a record type.
 pub const fn is_record_type(ty: &nu_protocol::Type) -> bool {
     matches!(ty, nu_protocol::Type::Record(_))
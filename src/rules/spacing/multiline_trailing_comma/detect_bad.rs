@@ -0,0 +1,67 @@
+use super::RULE;
+use crate::{Config, config::TrailingComma};
+
+fn always() -> Config {
+    Config {
+        trailing_comma: TrailingComma::Always,
+        ..Config::default()
+    }
+}
+
+#[test]
+fn detects_trailing_comma_in_multiline_list() {
+    let bad_code = "let items = [
+    1,
+    2,
+    3,
+]";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_trailing_comma_in_multiline_record() {
+    let bad_code = "let config = {
+    name: 'nu',
+    version: 1,
+}";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_trailing_comma_before_comment() {
+    let bad_code = "let items = [
+    1
+    2, # last item
+]";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_each_nested_literal() {
+    let bad_code = "let config = {
+    tags: [
+        a,
+        b,
+    ],
+}";
+    RULE.assert_count(bad_code, 2);
+}
+
+#[test]
+fn detects_missing_trailing_comma_in_list_when_always() {
+    let bad_code = "let items = [
+    1,
+    2,
+    3
+]";
+    RULE.assert_detects_with_config(bad_code, &always());
+}
+
+#[test]
+fn detects_missing_trailing_comma_in_record_when_always() {
+    let bad_code = "let config = {
+    name: 'nu',
+    version: 1
+}";
+    RULE.assert_detects_with_config(bad_code, &always());
+}
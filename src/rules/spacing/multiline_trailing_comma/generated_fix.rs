@@ -0,0 +1,43 @@
+use super::RULE;
+use crate::{Config, config::TrailingComma};
+
+#[test]
+fn removes_trailing_comma_from_list() {
+    let bad_code = "let items = [
+    1,
+    2,
+]";
+    let expected = "let items = [
+    1,
+    2
+]";
+    RULE.assert_fixed_is(bad_code, expected);
+}
+
+#[test]
+fn removes_trailing_comma_from_record() {
+    let bad_code = "let config = {
+    name: 'nu',
+}";
+    let expected = "let config = {
+    name: 'nu'
+}";
+    RULE.assert_fixed_is(bad_code, expected);
+}
+
+#[test]
+fn adds_trailing_comma_when_always() {
+    let config = Config {
+        trailing_comma: TrailingComma::Always,
+        ..Config::default()
+    };
+    let bad_code = "let items = [
+    1,
+    2
+]";
+    let expected = "let items = [
+    1,
+    2,
+]";
+    RULE.assert_fixed_is_with_config(bad_code, expected, &config);
+}
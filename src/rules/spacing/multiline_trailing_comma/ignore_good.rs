@@ -0,0 +1,75 @@
+use super::RULE;
+use crate::{Config, config::TrailingComma};
+
+fn always() -> Config {
+    Config {
+        trailing_comma: TrailingComma::Always,
+        ..Config::default()
+    }
+}
+
+#[test]
+fn ignores_multiline_list_without_trailing_comma() {
+    let good_code = "let items = [
+    1
+    2
+    3
+]";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_single_line_trailing_comma() {
+    let good_code = "let items = [1, 2, 3,]";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_closing_bracket_on_last_item_line() {
+    let good_code = "let items = [1,
+    2,
+    3]";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_comma_inside_trailing_comment() {
+    let good_code = "let items = [
+    1
+    2 # one, two
+]";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_empty_multiline_list() {
+    let good_code = "let items = [
+]";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_existing_trailing_comma_when_always() {
+    let good_code = "let items = [
+    1,
+    2,
+]";
+    RULE.assert_ignores_with_config(good_code, &always());
+}
+
+#[test]
+fn ignores_comma_free_list_when_always() {
+    let good_code = "let items = [
+    1
+    2
+]";
+    RULE.assert_ignores_with_config(good_code, &always());
+}
+
+#[test]
+fn ignores_single_item_when_always() {
+    let good_code = "let config = {
+    name: 'nu'
+}";
+    RULE.assert_ignores_with_config(good_code, &always());
+}
@@ -0,0 +1,153 @@
+use nu_protocol::{
+    Span,
+    ast::{Expr, Expression, ListItem, RecordItem},
+};
+
+use super::find_comma_outside_comment;
+use crate::{
+    LintLevel,
+    config::TrailingComma,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+enum FixData {
+    Remove { comma_span: Span },
+    Insert { after_last_item: Span },
+}
+
+const fn list_item_span(item: &ListItem) -> Span {
+    match item {
+        ListItem::Item(expr) => expr.span,
+        ListItem::Spread(spread, expr) => Span::new(spread.start, expr.span.end),
+    }
+}
+
+const fn record_item_span(item: &RecordItem) -> Span {
+    match item {
+        RecordItem::Pair(key, value) => Span::new(key.span.start, value.span.end),
+        RecordItem::Spread(spread, expr) => Span::new(spread.start, expr.span.end),
+    }
+}
+
+fn literal_item_spans(expr: &Expression, context: &LintContext) -> Option<Vec<Span>> {
+    let text = context.span_text(expr.span);
+    match &expr.expr {
+        Expr::List(items) if text.starts_with('[') && text.ends_with(']') => {
+            Some(items.iter().map(list_item_span).collect())
+        }
+        Expr::Record(items) if text.starts_with('{') && text.ends_with('}') => {
+            Some(items.iter().map(record_item_span).collect())
+        }
+        _ => None,
+    }
+}
+
+fn check_literal(
+    expr: &Expression,
+    context: &LintContext,
+    style: TrailingComma,
+) -> Option<(Detection, FixData)> {
+    let item_spans = literal_item_spans(expr, context)?;
+    let last = *item_spans.last()?;
+
+    // Only literals whose closing bracket sits on its own line are multi-line
+    let tail_span = Span::new(last.end, expr.span.end - 1);
+    let tail_text = context.span_text(tail_span);
+    if !tail_text.contains('\n') {
+        return None;
+    }
+
+    let trailing_comma = find_comma_outside_comment(tail_text);
+
+    match (style, trailing_comma) {
+        (TrailingComma::Never, Some(offset)) => {
+            let comma_span = Span::new(tail_span.start + offset, tail_span.start + offset + 1);
+            let detection =
+                Detection::from_global_span("Remove trailing comma after last item", comma_span)
+                    .with_primary_label("trailing comma")
+                    .with_extra_label("multi-line literal", expr.span);
+            Some((detection, FixData::Remove { comma_span }))
+        }
+        (TrailingComma::Always, None) => {
+            let [first, second, ..] = item_spans.as_slice() else {
+                return None;
+            };
+            let separator = context.span_text(Span::new(first.end, second.start));
+            find_comma_outside_comment(separator)?;
+
+            let detection = Detection::from_global_span(
+                "Add trailing comma after last item of comma-separated literal",
+                last,
+            )
+            .with_primary_label("missing trailing comma")
+            .with_extra_label("multi-line literal", expr.span);
+            Some((
+                detection,
+                FixData::Insert {
+                    after_last_item: Span::new(last.end, last.end),
+                },
+            ))
+        }
+        _ => None,
+    }
+}
+
+struct MultilineTrailingComma;
+
+impl DetectFix for MultilineTrailingComma {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "multiline_trailing_comma"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Inconsistent trailing comma in multi-line list or record"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Multi-line list and record literals should consistently end with or without a \
+             trailing comma. The direction is set with `trailing_comma` in the configuration \
+             file: `never` (default) removes trailing commas, `always` adds one to literals whose \
+             items are already separated by commas.",
+        )
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let style = context.config.trailing_comma;
+        context
+            .detect_with_fix_data(|expr, ctx| check_literal(expr, ctx, style).into_iter().collect())
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let (explanation, replacement) = match fix_data {
+            FixData::Remove { comma_span } => {
+                ("Remove trailing comma", Replacement::new(*comma_span, ""))
+            }
+            FixData::Insert { after_last_item } => (
+                "Add trailing comma",
+                Replacement::new(*after_last_item, ","),
+            ),
+        };
+        Some(Fix {
+            explanation: explanation.into(),
+            replacements: vec![replacement],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &MultilineTrailingComma;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
@@ -3,6 +3,7 @@ use nu_protocol::{
     ast::{Expr, ListItem},
 };
 
+use super::find_comma_outside_comment;
 use crate::{
     LintLevel,
     context::LintContext,
@@ -14,18 +15,6 @@ struct CommaFixData {
     comma_span: Span,
 }
 
-fn find_comma_outside_comment(between_text: &str) -> Option<usize> {
-    let mut offset = 0;
-    for line in between_text.split('\n') {
-        let comment_start = line.find('#').unwrap_or(line.len());
-        if let Some(comma_pos) = line[..comment_start].find(',') {
-            return Some(offset + comma_pos);
-        }
-        offset += line.len() + 1;
-    }
-    None
-}
-
 fn check_list_commas(
     context: &LintContext,
     span: Span,
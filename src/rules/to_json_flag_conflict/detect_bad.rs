@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn detects_raw_with_indent() {
+    let bad_code = r#"let out = {a: 1} | to json --raw --indent 2"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_short_raw_with_tabs() {
+    let bad_code = r#"let out = {a: 1} | to json -r --tabs 1"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_indent_with_tabs() {
+    let bad_code = r#"let out = {a: 1} | to json --indent 4 --tabs 1"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_str_trim_after_to_json() {
+    let bad_code = r#"let out = {a: 1} | to json | str trim"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_str_trim_after_raw_to_json() {
+    let bad_code = r#"let out = {a: 1} | to json --raw | str trim"#;
+    RULE.assert_labels_contain(bad_code, "trims nothing");
+}
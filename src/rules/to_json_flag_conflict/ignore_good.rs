@@ -0,0 +1,31 @@
+use super::RULE;
+
+#[test]
+fn ignores_plain_to_json() {
+    let good_code = r#"let out = {a: 1} | to json"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_raw_only() {
+    let good_code = r#"let out = {a: 1} | to json --raw"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_indent_only() {
+    let good_code = r#"let out = {a: 1} | to json --indent 4"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_str_trim_with_char() {
+    let good_code = r#"let out = {a: 1} | to json | str trim --char '}'"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_other_formats() {
+    let good_code = r#"let out = {a: 1} | to nuon --raw"#;
+    RULE.assert_ignores(good_code);
+}
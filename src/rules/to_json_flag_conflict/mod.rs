@@ -0,0 +1,129 @@
+use nu_protocol::ast::{Call, Expr, Expression, Pipeline};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, pipeline::PipelineExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Flag pairs of `to json` that contradict each other, given as
+/// `(long, short)` names, followed by the reason.
+const CONFLICTS: &[((&str, &str), (&str, &str), &str)] = &[
+    (
+        ("raw", "r"),
+        ("indent", "i"),
+        "`--raw` removes all whitespace, so the indentation is ignored",
+    ),
+    (
+        ("raw", "r"),
+        ("tabs", "t"),
+        "`--raw` removes all whitespace, so the tab indentation is ignored",
+    ),
+    (
+        ("indent", "i"),
+        ("tabs", "t"),
+        "`--indent` and `--tabs` both set the indentation, only one takes effect",
+    ),
+];
+
+fn has_flag(call: &Call, (long, short): (&str, &str)) -> bool {
+    call.has_named_flag(long) || call.has_named_flag(short)
+}
+
+fn is_to_json(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("to json", context)
+}
+
+fn is_plain_str_trim(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("str trim", context) && call.arguments.is_empty()
+}
+
+fn check_flags(expr: &Expression, context: &LintContext) -> Option<Detection> {
+    let Expr::Call(call) = &expr.expr else {
+        return None;
+    };
+    if !is_to_json(call, context) {
+        return None;
+    }
+
+    let (first, second, reason) = CONFLICTS
+        .iter()
+        .find(|(first, second, _)| has_flag(call, *first) && has_flag(call, *second))?;
+
+    Some(
+        Detection::from_global_span(
+            format!(
+                "`to json --{}` contradicts `--{}`: {reason}",
+                first.0, second.0
+            ),
+            expr.span,
+        )
+        .with_primary_label("contradictory flags"),
+    )
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    pipeline
+        .find_command_pairs(context, is_to_json, is_plain_str_trim)
+        .into_iter()
+        .map(|pair| {
+            let trim_span = pipeline.elements[pair.second_index].expr.span;
+            let message = if has_flag(pair.first, ("raw", "r")) {
+                "`to json --raw` output has no surrounding whitespace, so `str trim` does nothing"
+            } else {
+                "`str trim` only strips the ends of `to json` output, the indentation inside \
+                 stays; use `to json --raw` for compact output"
+            };
+            Detection::from_global_span(message, pair.span)
+                .with_primary_label("whitespace is controlled by `to json` flags")
+                .with_extra_label("trims nothing", trim_span)
+        })
+        .collect()
+}
+
+struct ToJsonFlagConflict;
+
+impl DetectFix for ToJsonFlagConflict {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "to_json_flag_conflict"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`to json` flags or trimming that contradict each other"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "The whitespace in `to json` output is controlled by its flags: `--raw` produces \
+             compact output, `--indent` and `--tabs` choose the indentation. Combining `--raw` \
+             with an indentation flag, or both indentation flags, leaves one of them without \
+             effect. Piping into `str trim` to compact the output does not work either, since it \
+             only strips leading and trailing whitespace, which `to json` never emits.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/to_json.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let mut detections = context.detect_single(check_flags);
+        detections.extend(context.ast.detect_in_pipelines(context, check_pipeline));
+        Self::no_fix(detections)
+    }
+}
+
+pub static RULE: &dyn Rule = &ToJsonFlagConflict;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
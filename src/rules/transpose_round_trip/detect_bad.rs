@@ -0,0 +1,18 @@
+use super::RULE;
+
+#[test]
+fn detects_double_transpose() {
+    let bad_code = r"let data = [[a b]; [1 2]] | transpose | transpose";
+    RULE.assert_detects(bad_code);
+    RULE.assert_no_fix(bad_code);
+}
+
+#[test]
+fn detects_double_transpose_mid_pipeline() {
+    let bad_code = r"
+def summary [] {
+    ls | select name size | transpose | transpose | first 3
+}
+";
+    RULE.assert_count(bad_code, 1);
+}
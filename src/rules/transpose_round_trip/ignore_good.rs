@@ -0,0 +1,21 @@
+use super::RULE;
+
+#[test]
+fn ignores_single_transpose() {
+    RULE.assert_ignores(r"let data = {a: 1, b: 2} | transpose key value");
+}
+
+#[test]
+fn ignores_header_row() {
+    RULE.assert_ignores(r"let data = [[a b]; [1 2]] | transpose | transpose --header-row");
+}
+
+#[test]
+fn ignores_named_columns() {
+    RULE.assert_ignores(r"let data = {a: 1} | transpose key value | transpose -r");
+}
+
+#[test]
+fn ignores_stage_in_between() {
+    RULE.assert_ignores(r"let data = [[a b]; [1 2]] | transpose | skip 1 | transpose");
+}
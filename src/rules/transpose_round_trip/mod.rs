@@ -0,0 +1,73 @@
+use nu_protocol::ast::{Call, Pipeline};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, pipeline::PipelineExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// A `transpose` without column names or flags, which names the new columns
+/// `column0`, `column1`, ...
+fn is_bare_transpose(call: &Call, context: &LintContext) -> bool {
+    call.is_call_to_command("transpose", context) && call.arguments.is_empty()
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<Detection> {
+    pipeline
+        .find_command_pairs(context, is_bare_transpose, is_bare_transpose)
+        .into_iter()
+        .map(|pair| {
+            Detection::from_global_span(
+                "Two bare `transpose` in a row move the column names into the first row",
+                pair.span,
+            )
+            .with_primary_label("use `transpose --header-row` to restore the column names")
+        })
+        .collect()
+}
+
+struct TransposeRoundTrip;
+
+impl DetectFix for TransposeRoundTrip {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "transpose_round_trip"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`transpose | transpose` that does not restore the columns"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A bare `transpose` names the columns of its result `column0`, `column1`, ... and \
+             keeps the original column names as data in `column0`. A second bare `transpose` \
+             therefore does not restore the input: `[[a b]; [1 2]] | transpose | transpose` gives \
+             `[[column0 column1 column2]; [column0 a b] [column1 1 2]]`. To swap rows and columns \
+             back, the second stage needs `--header-row`. Stages with column names or flags are \
+             not reported.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/transpose.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.ast.detect_in_pipelines(context, check_pipeline))
+    }
+}
+
+pub static RULE: &dyn Rule = &TransposeRoundTrip;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
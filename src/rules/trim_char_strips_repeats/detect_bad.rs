@@ -0,0 +1,39 @@
+use super::RULE;
+
+#[test]
+fn detects_left_trim_with_char() {
+    let bad_code = r#"let path = "/usr/local" | str trim --left --char '/'"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_right_trim_with_char() {
+    let bad_code = r#"let name = "file.txt." | str trim --right --char '.'"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_short_flags() {
+    let bad_code = r#"let path = "/usr/local" | str trim -l -c '/'"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_multi_character_char_argument() {
+    let bad_code = r#"let id = "prefix_value" | str trim --char 'prefix_'"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_inside_closure() {
+    let bad_code = r#"
+ls | get name | each {|name| $name | str trim --left --char '.' }
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn labels_trimmed_character() {
+    let bad_code = r#"let path = "/usr/local" | str trim --left --char '/'"#;
+    RULE.assert_labels_contain(bad_code, "trimmed character");
+}
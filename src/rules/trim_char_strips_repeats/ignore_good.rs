@@ -0,0 +1,41 @@
+use super::RULE;
+
+#[test]
+fn ignores_plain_str_trim() {
+    let good_code = r#"let name = "  padded  " | str trim"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_one_sided_whitespace_trim() {
+    let good_code = r#"let name = "  padded" | str trim --left"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_two_sided_char_trim() {
+    let good_code = r#"let quoted = '"value"' | str trim --char '"'"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_anchored_regex_replace() {
+    let good_code = r#"let path = "/usr/local" | str replace --regex '^/' ''"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_char_from_variable() {
+    let good_code = r#"
+def strip [sep: string] {
+    "a/b/" | str trim --right --char $sep
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_other_str_subcommands() {
+    let good_code = r#"let name = "file.txt" | str downcase"#;
+    RULE.assert_ignores(good_code);
+}
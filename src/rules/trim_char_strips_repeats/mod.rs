@@ -0,0 +1,109 @@
+use nu_protocol::ast::{Call, Expr, Expression};
+
+use crate::{
+    LintLevel,
+    ast::{call::CallExt, regex::escape_regex, string::StringFormat},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+fn has_flag(call: &Call, long: &str, short: &str) -> bool {
+    call.has_named_flag(long) || call.has_named_flag(short)
+}
+
+fn char_argument(call: &Call) -> Option<&Expression> {
+    call.get_named_arg_expr("char")
+        .or_else(|| call.get_named_arg_expr("c"))
+}
+
+fn check_str_trim(expr: &Expression, context: &LintContext) -> Option<Detection> {
+    let Expr::Call(call) = &expr.expr else {
+        return None;
+    };
+
+    if !call.is_call_to_command("str trim", context) {
+        return None;
+    }
+
+    let char_expr = char_argument(call)?;
+    let char_text = StringFormat::from_expression(char_expr, context)?
+        .content()
+        .to_string();
+
+    if char_text.chars().count() > 1 {
+        return Some(
+            Detection::from_global_span(
+                format!(
+                    "`str trim --char` only accepts a single character, got '{char_text}'; use \
+                     `str replace --regex` to strip a prefix or suffix"
+                ),
+                char_expr.span,
+            )
+            .with_primary_label("multi-character trim")
+            .with_extra_span(call.head),
+        );
+    }
+
+    let (side, anchored) = if has_flag(call, "left", "l") {
+        ("leading", format!("^{}", escape_regex(&char_text)))
+    } else if has_flag(call, "right", "r") {
+        ("trailing", format!("{}$", escape_regex(&char_text)))
+    } else {
+        return None;
+    };
+
+    Some(
+        Detection::from_global_span(
+            format!(
+                "`str trim` removes every {side} '{char_text}', not just one; use `str replace \
+                 --regex '{anchored}' ''` to strip a single occurrence"
+            ),
+            expr.span,
+        )
+        .with_primary_label(format!("strips all {side} '{char_text}'"))
+        .with_extra_label("trimmed character", char_expr.span),
+    )
+}
+
+struct TrimCharStripsRepeats;
+
+impl DetectFix for TrimCharStripsRepeats {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "trim_char_strips_repeats"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`str trim --char` removes all repeats, not a single prefix"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "`str trim --char` removes every consecutive occurrence of the character from the \
+             trimmed side(s), so `'//path' | str trim --left --char '/'` yields `path`. When only \
+             one prefix or suffix should be removed, anchor a regex instead: `str replace --regex \
+             '^/' ''`. The flag also rejects strings longer than one character at runtime.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/commands/docs/str_trim.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.detect_single(check_str_trim))
+    }
+}
+
+pub static RULE: &dyn Rule = &TrimCharStripsRepeats;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
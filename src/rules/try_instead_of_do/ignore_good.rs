@@ -68,3 +68,9 @@ fn test_do_block_with_safe_external() {
     // echo doesn't have FailsInNormalCircumstances effect
     RULE.assert_ignores("do { ^echo hello }");
 }
+
+#[test]
+fn test_do_ignore_errors_left_to_other_rule() {
+    let good_code = r"do -i { ^curl https://api.example.com }";
+    RULE.assert_ignores(good_code);
+}
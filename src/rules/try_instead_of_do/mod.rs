@@ -78,6 +78,11 @@ impl DetectFix for TryInsteadOfDo {
                 return vec![];
             }
 
+            // Errors ignored with `do -i` are reported by `silent_ignore_errors`
+            if call.has_named_flag("ignore-errors") || call.has_named_flag("i") {
+                return vec![];
+            }
+
             let Some(block_arg) = call.get_positional_arg(0) else {
                 return vec![];
             };
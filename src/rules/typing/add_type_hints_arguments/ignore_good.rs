@@ -72,3 +72,15 @@ def process [
 
     RULE.assert_ignores(good_code);
 }
+
+#[test]
+fn ignore_fully_untyped_main() {
+    // Reported by `untyped_main_params` instead
+    let good_code = r"
+def main [name, count] {
+    print $name $count
+}
+";
+
+    RULE.assert_ignores(good_code);
+}
@@ -3,6 +3,7 @@ use nu_protocol::{
     ast::{Call, Expr},
 };
 
+use super::all_positionals_untyped;
 use crate::{
     LintLevel,
     ast::{
@@ -135,8 +136,9 @@ fn detect_signature(
 }
 
 fn detect_def_call(call: &Call, ctx: &LintContext) -> Vec<(Detection, FixData)> {
+    // A fully untyped `main` is reported by `untyped_main_params`
     call.custom_command_def(ctx)
-        .is_some()
+        .is_some_and(|def| !(def.is_main() && all_positionals_untyped(&def.signature)))
         .then(|| {
             call.get_positional_arg(1)
                 .zip(call.get_positional_arg(2))
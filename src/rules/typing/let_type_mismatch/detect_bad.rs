@@ -0,0 +1,36 @@
+use super::RULE;
+
+#[test]
+fn detects_string_for_int() {
+    RULE.assert_detects(r#"let x: int = "hello""#);
+}
+
+#[test]
+fn detects_int_for_string() {
+    RULE.assert_detects(r"let name: string = 42");
+}
+
+#[test]
+fn detects_mut_binding() {
+    RULE.assert_detects(r"mut enabled: bool = 'yes'");
+}
+
+#[test]
+fn detects_list_for_scalar() {
+    RULE.assert_detects(r"let size: filesize = [1 2]");
+}
+
+#[test]
+fn detects_list_element() {
+    RULE.assert_detects(r#"let ports: list<int> = [80 "443"]"#);
+}
+
+#[test]
+fn detects_record_field() {
+    RULE.assert_detects(r"let config: record<port: int> = {port: 'http'}");
+}
+
+#[test]
+fn labels_annotation() {
+    RULE.assert_labels_contain(r#"let x: int = "hello""#, "declared as int");
+}
@@ -0,0 +1,36 @@
+use super::RULE;
+
+#[test]
+fn ignore_matching_types() {
+    RULE.assert_ignores(r#"let x: int = 5; let s: string = "a"; let d: duration = 1sec"#);
+}
+
+#[test]
+fn ignore_int_for_number_or_float() {
+    RULE.assert_ignores(r"let n: number = 1; let f: float = 2");
+}
+
+#[test]
+fn ignore_without_annotation() {
+    RULE.assert_ignores(r#"let x = "hello""#);
+}
+
+#[test]
+fn ignore_any_annotation() {
+    RULE.assert_ignores(r"let x: any = 'text'");
+}
+
+#[test]
+fn ignore_computed_initializer() {
+    RULE.assert_ignores(r#"let x: int = ("5" | into int)"#);
+}
+
+#[test]
+fn ignore_pipeline_starting_with_literal() {
+    RULE.assert_ignores(r#"let x: int = "hello" | str length"#);
+}
+
+#[test]
+fn ignore_matching_list_elements() {
+    RULE.assert_ignores(r"let xs: list<string> = ['a' 'b']");
+}
@@ -0,0 +1,160 @@
+use nu_protocol::{
+    Span, Type,
+    ast::{Call, Expr, Expression, ListItem, RecordItem},
+};
+
+use super::is_scalar_type;
+use crate::{
+    LintLevel,
+    ast::{call::CallExt, string::StringFormat},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Type of a literal value, `None` for anything computed at runtime
+fn literal_type(expr: &Expression) -> Option<Type> {
+    match &expr.expr {
+        Expr::Int(_)
+        | Expr::Float(_)
+        | Expr::Bool(_)
+        | Expr::String(_)
+        | Expr::RawString(_)
+        | Expr::ValueWithUnit(_)
+        | Expr::DateTime(_)
+        | Expr::List(_)
+        | Expr::Record(_)
+        | Expr::Table(_) => Some(expr.ty.clone()),
+        _ => None,
+    }
+}
+
+/// Whether a literal of type `found` is accepted where `annotated` is declared
+fn accepts(annotated: &Type, found: &Type) -> bool {
+    annotated == found
+        || matches!(
+            (annotated, found),
+            (Type::Number, Type::Int | Type::Float)
+                | (Type::Float, Type::Int)
+                | (Type::Glob, Type::String)
+        )
+}
+
+/// First literal in `value`, or inside its list elements and record fields,
+/// that can never have the annotated type
+fn find_mismatch<'a>(
+    annotated: &Type,
+    value: &'a Expression,
+    context: &LintContext,
+) -> Option<(&'a Expression, Type, Type)> {
+    match (annotated, &value.expr) {
+        (Type::List(element), Expr::List(items)) => items.iter().find_map(|item| match item {
+            ListItem::Item(expr) => find_mismatch(element, expr, context),
+            ListItem::Spread(..) => None,
+        }),
+        (Type::Record(fields), Expr::Record(items)) => items.iter().find_map(|item| {
+            let RecordItem::Pair(key, field_value) = item else {
+                return None;
+            };
+            let key = StringFormat::from_expression(key, context)?;
+            let (_, field_type) = fields.iter().find(|(name, _)| name == key.content())?;
+            find_mismatch(field_type, field_value, context)
+        }),
+        _ => {
+            let found = literal_type(value)?;
+            // Only compare when at least one side is a plain scalar, so
+            // differences in inferred element types don't count
+            let comparable = is_scalar_type(annotated)
+                || (matches!(annotated, Type::List(_) | Type::Record(_) | Type::Table(_))
+                    && is_scalar_type(&found));
+            (comparable && !accepts(annotated, &found)).then(|| (value, annotated.clone(), found))
+        }
+    }
+}
+
+/// The initializer when it is a single literal, like `5` in `let x: int = 5`
+fn literal_initializer<'a>(call: &'a Call, context: &'a LintContext) -> Option<&'a Expression> {
+    let value = call.get_positional_arg(1)?;
+    let Expr::Block(block_id) = &value.expr else {
+        return Some(value);
+    };
+    let block = context.working_set.get_block(*block_id);
+    let [pipeline] = block.pipelines.as_slice() else {
+        return None;
+    };
+    let [element] = pipeline.elements.as_slice() else {
+        return None;
+    };
+    Some(&element.expr)
+}
+
+fn check_let(expr: &Expression, context: &LintContext) -> Option<Detection> {
+    let Expr::Call(call) = &expr.expr else {
+        return None;
+    };
+    let (var_id, var_name, var_span) = call.extract_variable_declaration(context)?;
+    let value = literal_initializer(call, context)?;
+
+    // The variable only carries the annotated type when there is a `: type`
+    // between the name and the `=`
+    let declaration = context.span_text(Span::new(var_span.start, value.span.start));
+    if !declaration.contains(':') {
+        return None;
+    }
+
+    let annotated = &context.working_set.get_variable(var_id).ty;
+    let (literal, expected, found) = find_mismatch(annotated, value, context)?;
+    let name = var_name.split(':').next().unwrap_or(&var_name).trim();
+
+    Some(
+        Detection::from_global_span(
+            format!("'{name}' is declared as {expected} but initialized with a {found} literal"),
+            literal.span,
+        )
+        .with_primary_label(format!("{found} literal"))
+        .with_extra_label(format!("declared as {annotated}"), var_span),
+    )
+}
+
+struct LetTypeMismatch;
+
+impl DetectFix for LetTypeMismatch {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "let_type_mismatch"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "`let` type annotation contradicts its literal value"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A binding like `let port: int = \"8080\"` declares one type and assigns a literal of \
+             another. Either the annotation or the value is wrong. The parser rejects some of \
+             these, but element types of list and record literals are not always checked, for \
+             example `let ports: list<int> = [80 \"443\"]`. Only literal values are compared; \
+             initializers computed at runtime are left to Nushell.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/variables.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(context.detect_single(check_let))
+    }
+}
+
+pub static RULE: &dyn Rule = &LetTypeMismatch;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
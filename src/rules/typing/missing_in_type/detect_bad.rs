@@ -3,8 +3,8 @@ use super::RULE;
 #[test]
 fn detect_missing_input_type_annotation() {
     let bad_code = r"
-def double [] {
-    $in * 2
+def increment-all [] {
+    $in | each {|x| $x + 1}
 }
 ";
     RULE.assert_detects(bad_code);
@@ -13,8 +13,8 @@ def double [] {
 #[test]
 fn detect_input_type_is_any() {
     let bad_code = r"
-def double []: any -> int {
-    $in * 2
+def increment-all []: any -> list<int> {
+    $in | each {|x| $x + 1}
 }
 ";
     RULE.assert_detects(bad_code);
@@ -23,8 +23,8 @@ def double []: any -> int {
 #[test]
 fn detect_missing_input_with_params() {
     let bad_code = r"
-def multiply [factor: int] {
-    $in * $factor
+def add-all [n: int] {
+    $in | each {|x| $x + $n}
 }
 ";
     RULE.assert_detects(bad_code);
@@ -33,8 +33,8 @@ def multiply [factor: int] {
 #[test]
 fn detect_exported_function_missing_input_type() {
     let bad_code = r"
-export def process [] {
-    $in | str trim
+export def name-of [] {
+    $in.name
 }
 ";
     RULE.assert_detects(bad_code);
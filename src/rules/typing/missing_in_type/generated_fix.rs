@@ -5,32 +5,32 @@ use crate::log::init_test_log;
 fn test_fix_untyped_input_adds_any_for_output() {
     init_test_log();
     let bad_code = r"
-def double [] {
-    $in * 2
+def double-all [] {
+    $in | each {|x| $x * 2}
 }
 ";
-    RULE.assert_fixed_contains(bad_code, "[]: int -> any");
+    RULE.assert_fixed_contains(bad_code, "[]: list<any> -> any");
 }
 
 #[test]
 fn test_fix_refines_any_input_type() {
     init_test_log();
     let bad_code = r"
-def double []: any -> int {
-    $in * 2
+def get_name []: any -> string {
+    $in.name
 }
 ";
-    RULE.assert_fixed_contains(bad_code, "[]: int -> int");
+    RULE.assert_fixed_contains(bad_code, "[]: record -> string");
 }
 
 #[test]
 fn test_fix_with_parameters() {
     let bad_code = r"
-def multiply [factor: int] {
-    $in * $factor
+def scale [factor: int] {
+    $in | each {|x| $x * $factor}
 }
 ";
-    RULE.assert_fixed_contains(bad_code, "[factor: int]: int -> any");
+    RULE.assert_fixed_contains(bad_code, "[factor: int]: list<any> -> any");
 }
 
 #[test]
@@ -67,9 +67,9 @@ def get_name [] {
 #[test]
 fn test_fix_exported_function() {
     let bad_code = r"
-export def process [] {
-    $in | str trim
+export def get_name [] {
+    $in.name
 }
 ";
-    RULE.assert_fixed_contains(bad_code, "[]: string -> any");
+    RULE.assert_fixed_contains(bad_code, "[]: record -> any");
 }
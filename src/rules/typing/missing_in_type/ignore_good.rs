@@ -62,3 +62,13 @@ def make-formatter [] {
 ";
     RULE.assert_ignores(good_code);
 }
+
+#[test]
+fn ignore_scalar_input_reported_by_scalar_in_type() {
+    let good_code = r"
+def double [] {
+    $in * 2
+}
+";
+    RULE.assert_ignores(good_code);
+}
@@ -3,15 +3,13 @@ use nu_protocol::{
     ast::{Call, Expr},
 };
 
-use super::{
-    FixData, extract_parameters_text, find_signature_span, get_input_type, get_output_type,
-};
+use super::{FixData, find_signature_span, fix_input_type, get_input_type, is_scalar_type};
 use crate::{
     LintLevel,
     ast::{block::BlockExt, call::CallExt},
     context::LintContext,
     rule::{DetectFix, Rule},
-    violation::{Detection, Fix, Replacement},
+    violation::{Detection, Fix},
 };
 
 fn detect_def_call(call: &Call, ctx: &LintContext) -> Vec<(Detection, FixData)> {
@@ -43,6 +41,11 @@ fn detect_def_call(call: &Call, ctx: &LintContext) -> Vec<(Detection, FixData)>
         return vec![];
     }
 
+    // `scalar_in_type` reports inputs used as a single value more precisely
+    if is_scalar_type(&block.infer_input_type(ctx)) {
+        return vec![];
+    }
+
     let Some(sig_span) = sig_span else {
         return vec![];
     };
@@ -95,20 +98,7 @@ impl DetectFix for TypeNuPipelineInput {
     }
 
     fn fix(&self, ctx: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
-        let block = ctx.working_set.get_block(fix_data.body_block_id);
-        let signature = &block.signature;
-
-        // Use AST data: extract parameters and types from signature
-        let params = extract_parameters_text(signature);
-        let input_type = block.infer_input_type(ctx);
-        let output_type = get_output_type(signature).unwrap_or(Type::Any);
-
-        let new_signature = format!("[{params}]: {input_type} -> {output_type}");
-
-        Some(Fix {
-            explanation: format!("Add input type annotation: {new_signature}").into(),
-            replacements: vec![Replacement::new(fix_data.sig_span, new_signature)],
-        })
+        Some(fix_input_type(ctx, fix_data))
     }
 }
 
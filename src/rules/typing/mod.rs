@@ -3,11 +3,29 @@ use nu_protocol::{
     ast::{Block, Call},
 };
 
-use crate::{ast::call::CallExt, context::LintContext};
+use crate::{
+    ast::{block::BlockExt, call::CallExt},
+    context::LintContext,
+    violation::{Fix, Replacement},
+};
 
 pub mod add_type_hints_arguments;
+pub mod let_type_mismatch;
 pub mod missing_in_type;
 pub mod missing_output_type;
+pub mod scalar_in_type;
+pub mod untyped_main_params;
+
+/// Whether a signature has positional parameters and none of them is typed
+pub fn all_positionals_untyped(signature: &nu_protocol::Signature) -> bool {
+    let mut positionals = signature
+        .required_positional
+        .iter()
+        .chain(&signature.optional_positional)
+        .chain(signature.rest_positional.iter())
+        .peekable();
+    positionals.peek().is_some() && positionals.all(|p| p.shape == SyntaxShape::Any)
+}
 
 pub fn find_return_span(block: &Block) -> Option<Span> {
     block
@@ -17,6 +35,21 @@ pub fn find_return_span(block: &Block) -> Option<Span> {
         .map(|e| e.expr.span)
 }
 
+/// Whether the type is a single value rather than a collection
+pub const fn is_scalar_type(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Int
+            | Type::Float
+            | Type::Number
+            | Type::String
+            | Type::Bool
+            | Type::Filesize
+            | Type::Duration
+            | Type::Date
+    )
+}
+
 pub fn get_input_type(signature: &nu_protocol::Signature) -> Option<Type> {
     signature
         .input_output_types
@@ -104,3 +137,22 @@ pub struct FixData {
     pub sig_span: Span,
     pub body_block_id: BlockId,
 }
+
+/// Rewrite the signature with the input type inferred from `$in`, keeping the
+/// parameters and the declared output type.
+pub fn fix_input_type(ctx: &LintContext, fix_data: &FixData) -> Fix {
+    let block = ctx.working_set.get_block(fix_data.body_block_id);
+    let signature = &block.signature;
+
+    // Use AST data: extract parameters and types from signature
+    let params = extract_parameters_text(signature);
+    let input_type = block.infer_input_type(ctx);
+    let output_type = get_output_type(signature).unwrap_or(Type::Any);
+
+    let new_signature = format!("[{params}]: {input_type} -> {output_type}");
+
+    Fix {
+        explanation: format!("Add input type annotation: {new_signature}").into(),
+        replacements: vec![Replacement::new(fix_data.sig_span, new_signature)],
+    }
+}
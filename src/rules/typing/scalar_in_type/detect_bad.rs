@@ -0,0 +1,51 @@
+use super::RULE;
+
+#[test]
+fn detect_arithmetic_on_in() {
+    let bad_code = r"
+def double [] {
+    $in * 2
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detect_input_type_is_any() {
+    let bad_code = r"
+def double []: any -> int {
+    $in * 2
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detect_with_params() {
+    let bad_code = r"
+def multiply [factor: int] {
+    $in * $factor
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detect_string_command_on_in() {
+    let bad_code = r"
+export def process [] {
+    $in | str trim
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn labels_in_usage() {
+    let bad_code = r"
+def increment [] {
+    $in + 1
+}
+";
+    RULE.assert_labels_contain(bad_code, "used as int here");
+}
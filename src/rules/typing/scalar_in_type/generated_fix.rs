@@ -0,0 +1,41 @@
+use super::RULE;
+
+#[test]
+fn fix_untyped_input_adds_any_for_output() {
+    let bad_code = r"
+def double [] {
+    $in * 2
+}
+";
+    RULE.assert_fixed_contains(bad_code, "[]: int -> any");
+}
+
+#[test]
+fn fix_refines_any_input_type() {
+    let bad_code = r"
+def double []: any -> int {
+    $in * 2
+}
+";
+    RULE.assert_fixed_contains(bad_code, "[]: int -> int");
+}
+
+#[test]
+fn fix_with_parameters() {
+    let bad_code = r"
+def multiply [factor: int] {
+    $in * $factor
+}
+";
+    RULE.assert_fixed_contains(bad_code, "[factor: int]: int -> any");
+}
+
+#[test]
+fn fix_string_input() {
+    let bad_code = r"
+export def process [] {
+    $in | str trim
+}
+";
+    RULE.assert_fixed_contains(bad_code, "[]: string -> any");
+}
@@ -0,0 +1,41 @@
+use super::RULE;
+
+#[test]
+fn ignore_declared_scalar_input() {
+    let good_code = r"
+def double []: int -> int {
+    $in * 2
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignore_list_input() {
+    let good_code = r"
+def increment-all [] {
+    $in | each {|x| $x + 1}
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignore_record_input() {
+    let good_code = r"
+def get_name [] {
+    $in.name
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignore_command_without_in() {
+    let good_code = r"
+def add [a: int, b: int] {
+    $a + $b
+}
+";
+    RULE.assert_ignores(good_code);
+}
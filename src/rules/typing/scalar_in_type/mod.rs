@@ -0,0 +1,102 @@
+use nu_protocol::{
+    Type,
+    ast::{Call, Expr},
+};
+
+use super::{FixData, find_signature_span, fix_input_type, get_input_type, is_scalar_type};
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix},
+};
+
+fn detect_def_call(call: &Call, ctx: &LintContext) -> Option<(Detection, FixData)> {
+    let def = call.custom_command_def(ctx)?;
+    let block = ctx.working_set.get_block(def.body);
+
+    if !block.uses_pipeline_input(ctx) {
+        return None;
+    }
+    if !matches!(get_input_type(&block.signature), None | Some(Type::Any)) {
+        return None;
+    }
+
+    let inferred = block.infer_input_type(ctx);
+    if !is_scalar_type(&inferred) {
+        return None;
+    }
+
+    let fix_data = FixData {
+        sig_span: find_signature_span(call, ctx)?,
+        body_block_id: def.body,
+    };
+
+    let mut detection = Detection::from_global_span(
+        format!(
+            "'{}' uses `$in` as {inferred}; declare `{inferred}` as its input type",
+            def.name
+        ),
+        def.name_span,
+    )
+    .with_primary_label(format!("input is {inferred}"));
+
+    if let Some(span) = block.find_dollar_in_usage() {
+        detection = detection.with_extra_label(format!("used as {inferred} here"), span);
+    }
+
+    Some((detection, fix_data))
+}
+
+struct ScalarInType;
+
+impl DetectFix for ScalarInType {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "scalar_in_type"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Command using `$in` as a single value without declaring its type"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "When a command without an input type uses `$in` in arithmetic, a comparison or a \
+             string command, the usage already tells which scalar type it expects, for example \
+             `$in + 1` needs an `int`. Declaring that type lets callers see the mismatch when \
+             they pipe in a list or a record. Inputs used as collections are reported by \
+             `missing_in_type` instead.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/custom_commands.html#input-output-types")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.detect_with_fix_data(|expr, ctx| match &expr.expr {
+            Expr::Call(call) => detect_def_call(call, ctx).into_iter().collect(),
+            _ => vec![],
+        })
+    }
+
+    fn fix(&self, ctx: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(fix_input_type(ctx, fix_data))
+    }
+}
+
+pub static RULE: &dyn Rule = &ScalarInType;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
@@ -0,0 +1,33 @@
+use super::RULE;
+
+#[test]
+fn detects_untyped_main() {
+    let bad_code = r#"
+def main [name, count] {
+    print $"($name): ($count)"
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_untyped_optional_and_rest() {
+    let bad_code = r"
+def main [target?, ...files] {
+    print $target $files
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_untyped_main_subcommand() {
+    let bad_code = r"
+def main [] {}
+
+def 'main build' [target] {
+    print $target
+}
+";
+    RULE.assert_count(bad_code, 1);
+}
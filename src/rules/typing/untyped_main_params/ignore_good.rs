@@ -0,0 +1,39 @@
+use super::RULE;
+
+#[test]
+fn ignores_typed_main() {
+    let good_code = r"
+def main [name: string, count: int = 1] {
+    print $name $count
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_partially_typed_main() {
+    let good_code = r"
+def main [name: string, extra] {
+    print $name $extra
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_main_without_parameters() {
+    let good_code = r"
+def main [] {
+    print hello
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_untyped_helper() {
+    let good_code = r"
+def helper [x] { $x }
+";
+    RULE.assert_ignores(good_code);
+}
@@ -0,0 +1,86 @@
+use super::all_positionals_untyped;
+use crate::{
+    LintLevel,
+    ast::declaration::CustomCommandDef,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+fn check_main(def: &CustomCommandDef) -> Option<Detection> {
+    if !def.is_main() || !all_positionals_untyped(&def.signature) {
+        return None;
+    }
+
+    let names: Vec<String> = def
+        .signature
+        .required_positional
+        .iter()
+        .chain(&def.signature.optional_positional)
+        .chain(def.signature.rest_positional.iter())
+        .map(|param| format!("`{}`", param.name))
+        .collect();
+
+    Some(
+        Detection::from_global_span(
+            format!(
+                "Command-line arguments {} of `{}` have no type; they are parsed from the command \
+                 line and shown in `--help`",
+                names.join(", "),
+                def.name
+            ),
+            def.signature_span,
+        )
+        .with_primary_label("untyped script arguments")
+        .with_extra_label("script entry point", def.name_span),
+    )
+}
+
+struct UntypedMainParams;
+
+impl DetectFix for UntypedMainParams {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "untyped_main_params"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Script `main` whose parameters are all untyped"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "The parameters of `def main` are the arguments of the script itself. Nushell \
+             converts command-line strings according to the declared type, rejects invalid input \
+             with a clear error and lists the types in `--help`. Without any types, every \
+             argument arrives as `any`, so mistakes surface deep inside the script instead of at \
+             the call site.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/scripts.html#parameterizing-scripts")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(
+            context
+                .custom_commands()
+                .iter()
+                .filter_map(check_main)
+                .collect(),
+        )
+    }
+}
+
+pub static RULE: &dyn Rule = &UntypedMainParams;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
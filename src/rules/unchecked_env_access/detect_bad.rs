@@ -0,0 +1,40 @@
+use super::RULE;
+
+#[test]
+fn detects_plain_env_read() {
+    let bad_code = r#"let editor = $env.EDITOR"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_env_read_with_default() {
+    let bad_code = r#"let editor = $env.EDITOR | default "vi""#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_read_before_assignment() {
+    let bad_code = r#"print $env.FOO; $env.FOO = 1"#;
+    RULE.assert_count(bad_code, 1);
+}
+
+#[test]
+fn detects_nested_env_read() {
+    let bad_code = r#"let host = $env.MY_SETTINGS.host"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_env_read_in_interpolation() {
+    let bad_code = r#"print $"Token: ($env.API_TOKEN)""#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_each_access() {
+    let bad_code = r#"
+let user = $env.USER
+let shell = $env.SHELL
+"#;
+    RULE.assert_count(bad_code, 2);
+}
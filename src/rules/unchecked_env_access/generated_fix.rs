@@ -0,0 +1,13 @@
+use super::RULE;
+
+#[test]
+fn fix_appends_question_mark() {
+    let bad_code = r#"let editor = $env.EDITOR | default "vi""#;
+    RULE.assert_fixed_is(bad_code, r#"let editor = $env.EDITOR? | default "vi""#);
+}
+
+#[test]
+fn fix_marks_first_member_optional() {
+    let bad_code = r#"let host = $env.MY_SETTINGS.host"#;
+    RULE.assert_fixed_is(bad_code, r#"let host = $env.MY_SETTINGS?.host"#);
+}
@@ -0,0 +1,54 @@
+use super::RULE;
+
+#[test]
+fn ignores_optional_access() {
+    let good_code = r#"let editor = $env.EDITOR? | default "vi""#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_assignment() {
+    let good_code = r#"$env.MY_VAR = "value""#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_always_set_variables() {
+    let good_code = r#"
+let path = $env.PATH
+let cwd = $env.PWD
+let home = $env.HOME
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_read_after_assignment() {
+    let good_code = r#"$env.FOO = 1; print $env.FOO"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_variables_set_for_scripts() {
+    let good_code = r#"
+let dir = $env.FILE_PWD
+let file = $env.CURRENT_FILE
+let version = $env.NU_VERSION
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_whole_env() {
+    let good_code = r#"let vars = $env | columns"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_other_records() {
+    let good_code = r#"
+let settings = {host: "localhost"}
+let host = $settings.host
+"#;
+    RULE.assert_ignores(good_code);
+}
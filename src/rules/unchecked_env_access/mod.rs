@@ -0,0 +1,174 @@
+use nu_protocol::{
+    ENV_VARIABLE_ID, Span,
+    ast::{Expr, Expression, PathMember, Traverse},
+};
+
+use crate::{
+    LintLevel,
+    ast::expression::ExpressionExt,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+/// Environment variables that Nushell always sets, so reading them cannot fail.
+/// `FILE_PWD` and `CURRENT_FILE` are set while a script runs.
+const ALWAYS_SET: &[&str] = &[
+    "PATH",
+    "Path",
+    "PWD",
+    "HOME",
+    "LAST_EXIT_CODE",
+    "config",
+    "NU_VERSION",
+    "FILE_PWD",
+    "CURRENT_FILE",
+];
+
+struct FixData {
+    name: String,
+    member_span: Span,
+}
+
+/// Assignment target, such as `$env.FOO` in `$env.FOO = 1`
+struct AssignmentTarget {
+    span: Span,
+    /// Name of the environment variable the target sets, if any
+    env_name: Option<String>,
+}
+
+/// First member of `$env.NAME...`
+fn env_member(expr: &Expression) -> Option<&PathMember> {
+    let Expr::FullCellPath(cell_path) = &expr.expr else {
+        return None;
+    };
+    if !matches!(&cell_path.head.expr, Expr::Var(var_id) if *var_id == ENV_VARIABLE_ID) {
+        return None;
+    }
+    cell_path.tail.first()
+}
+
+fn collect_assignment_targets(context: &LintContext) -> Vec<AssignmentTarget> {
+    let mut targets = Vec::new();
+    context.ast.flat_map(
+        context.working_set,
+        &|expr| match &expr.expr {
+            Expr::BinaryOp(lhs, _, _) if expr.is_assignment() => {
+                let env_name = match env_member(lhs) {
+                    Some(PathMember::String { val, .. }) => Some(val.clone()),
+                    _ => None,
+                };
+                vec![AssignmentTarget {
+                    span: lhs.span,
+                    env_name,
+                }]
+            }
+            _ => vec![],
+        },
+        &mut targets,
+    );
+    targets
+}
+
+/// Whether the access is an assignment target, or reads a variable that an
+/// earlier assignment in the file sets
+fn is_assigned(expr: &Expression, name: &str, targets: &[AssignmentTarget]) -> bool {
+    targets.iter().any(|target| {
+        target.span.contains_span(expr.span)
+            || (target.env_name.as_deref() == Some(name) && target.span.end <= expr.span.start)
+    })
+}
+
+fn check_env_access(
+    expr: &Expression,
+    assignment_targets: &[AssignmentTarget],
+) -> Option<(Detection, FixData)> {
+    let PathMember::String {
+        val,
+        span,
+        optional: false,
+        ..
+    } = env_member(expr)?
+    else {
+        return None;
+    };
+    if ALWAYS_SET.contains(&val.as_str()) || is_assigned(expr, val, assignment_targets) {
+        return None;
+    }
+
+    let detection = Detection::from_global_span(
+        format!(
+            "`$env.{val}` throws when the variable is unset; use `$env.{val}?` with `default` for \
+             a fallback"
+        ),
+        *span,
+    )
+    .with_primary_label("may be unset");
+
+    Some((
+        detection,
+        FixData {
+            name: val.clone(),
+            member_span: *span,
+        },
+    ))
+}
+
+struct UncheckedEnvAccess;
+
+impl DetectFix for UncheckedEnvAccess {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "unchecked_env_access"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Environment variable read without optional access `?`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Reading `$env.MY_VAR` raises an error when `MY_VAR` is not set in the environment of \
+             the caller. Mark the access optional with `$env.MY_VAR?` so it evaluates to `null`, \
+             and pipe into `default` to provide a fallback value: `$env.MY_VAR? | default \
+             'value'`. Variables that Nushell always sets, such as `PATH`, `PWD`, `HOME` and \
+             `FILE_PWD`, and variables the file assigns to `$env` before reading them are not \
+             reported.",
+        )
+    }
+
+    fn source_link(&self) -> Option<&'static str> {
+        Some("https://www.nushell.sh/book/environment.html")
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let assignment_targets = collect_assignment_targets(context);
+        context.detect_with_fix_data(|expr, _ctx| {
+            check_env_access(expr, &assignment_targets)
+                .into_iter()
+                .collect()
+        })
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let end = fix_data.member_span.end;
+        Some(Fix {
+            explanation: format!("Make `$env.{}` optional", fix_data.name).into(),
+            replacements: vec![Replacement::new(Span::new(end, end), "?")],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &UncheckedEnvAccess;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
@@ -0,0 +1,44 @@
+use super::RULE;
+
+#[test]
+fn detects_bare_self_call() {
+    let bad_code = r#"
+def forever [] {
+    print "tick"
+    forever
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_self_call_with_arguments() {
+    let bad_code = r#"
+def countdown [n: int] {
+    print $n
+    countdown ($n - 1)
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_self_call_in_pipeline() {
+    let bad_code = r#"
+def walk [dir: path] {
+    ls $dir | length
+    walk $dir | append 1
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_in_exported_command() {
+    let bad_code = r#"
+export def loop-me [] {
+    loop-me
+}
+"#;
+    RULE.assert_labels_contain(bad_code, "recursive command");
+}
@@ -0,0 +1,60 @@
+use super::RULE;
+
+#[test]
+fn ignores_recursion_behind_if() {
+    let good_code = r#"
+def countdown [n: int] {
+    if $n > 0 {
+        print $n
+        countdown ($n - 1)
+    }
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_recursion_after_early_return() {
+    let good_code = r#"
+def countdown [n: int] {
+    if $n <= 0 { return }
+    print $n
+    countdown ($n - 1)
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_recursion_inside_closure() {
+    let good_code = r#"
+def tree [dir: path] {
+    ls $dir | where type == dir | each {|d| tree $d.name }
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_recursion_inside_match() {
+    let good_code = r#"
+def fact [n: int] {
+    match $n {
+        0 => 1
+        _ => ($n * (fact ($n - 1)))
+    }
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_call_to_other_command() {
+    let good_code = r#"
+def helper [] { 1 }
+def main [] {
+    helper
+}
+"#;
+    RULE.assert_ignores(good_code);
+}
@@ -0,0 +1,119 @@
+use nu_protocol::{
+    BlockId, Span,
+    ast::{Block, Expr, FindMapResult, Traverse},
+};
+
+use crate::{
+    LintLevel,
+    ast::{call::CallExt, declaration::CustomCommandDef},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::Detection,
+};
+
+/// Commands that leave a custom command early, so a later self-call may be
+/// guarded by them.
+const EARLY_EXITS: &[&str] = &["return", "exit", "error make"];
+
+fn has_early_exit(block: &Block, context: &LintContext) -> bool {
+    block
+        .find_map(context.working_set, &|expr| match &expr.expr {
+            Expr::Call(call)
+                if EARLY_EXITS
+                    .iter()
+                    .any(|name| call.is_call_to_command(name, context)) =>
+            {
+                FindMapResult::Found(())
+            }
+            _ => FindMapResult::Continue,
+        })
+        .is_some()
+}
+
+/// Finds a call to the command itself that sits directly in one of the body's
+/// pipelines, outside of any branch, loop or closure.
+fn find_unconditional_self_call(
+    block: &Block,
+    body: BlockId,
+    context: &LintContext,
+) -> Option<Span> {
+    block
+        .pipelines
+        .iter()
+        .flat_map(|pipeline| &pipeline.elements)
+        .find_map(|element| match &element.expr.expr {
+            Expr::Call(call)
+                if context.working_set.get_decl(call.decl_id).block_id() == Some(body) =>
+            {
+                Some(element.expr.span)
+            }
+            _ => None,
+        })
+}
+
+fn check_command(def: &CustomCommandDef, context: &LintContext) -> Option<Detection> {
+    let block = context.working_set.get_block(def.body);
+    if has_early_exit(block, context) {
+        return None;
+    }
+
+    let self_call = find_unconditional_self_call(block, def.body, context)?;
+
+    Some(
+        Detection::from_global_span(
+            format!(
+                "`{}` calls itself on every path and never returns; guard the recursion with a \
+                 base case",
+                def.name
+            ),
+            self_call,
+        )
+        .with_primary_label("unconditional recursive call")
+        .with_extra_label("recursive command", def.name_span),
+    )
+}
+
+struct UnconditionalRecursion;
+
+impl DetectFix for UnconditionalRecursion {
+    type FixInput<'a> = ();
+
+    fn id(&self) -> &'static str {
+        "unconditional_recursion"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Custom command calls itself without a base case"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A custom command whose body calls itself outside of any `if`, `match`, loop or \
+             closure recurses on every invocation and only stops when the stack overflows. Wrap \
+             the recursive call in a condition that eventually becomes false, or `return` before \
+             it. Commands that contain `return`, `exit` or `error make` anywhere are assumed to \
+             terminate.",
+        )
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        Self::no_fix(
+            context
+                .custom_commands()
+                .iter()
+                .filter_map(|def| check_command(def, context))
+                .collect(),
+        )
+    }
+}
+
+pub static RULE: &dyn Rule = &UnconditionalRecursion;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod ignore_good;
@@ -70,3 +70,15 @@ def process [] {
     RULE.assert_detects(bad_code);
     RULE.assert_count(bad_code, 1);
 }
+
+#[test]
+fn test_mut_only_read_inside_closure() {
+    let bad_code = r"
+def scale [xs: list<int>] {
+    mut factor = 2
+    $xs | each {|x| $x * $factor }
+}
+";
+
+    RULE.assert_count(bad_code, 1);
+}
@@ -10,7 +10,7 @@ def process [] {
 ";
     let expected = r"
 def process [] {
-    x = 5
+    let x = 5
     echo $x
 }
 ";
@@ -30,10 +30,18 @@ def process [] {
     let expected = r"
 def process [] {
     # 这里有中文注释，确保 mut 前面存在多字节字符
-    value = 5
+    let value = 5
     echo $value
 }
 ";
 
     RULE.assert_fixed_is(bad_code, expected);
 }
+
+#[test]
+fn test_unnecessary_mut_fix_keeps_type_annotation() {
+    RULE.assert_fixed_is(
+        r"mut total: int = 0; print $total",
+        r"let total: int = 0; print $total",
+    );
+}
@@ -66,3 +66,15 @@ def process [] {
 
     RULE.assert_ignores(good_code);
 }
+
+#[test]
+fn test_env_assignment_not_flagged() {
+    let good_code = r"
+def --env setup [] {
+    $env.COUNT = 1
+    $env.COUNT = $env.COUNT + 1
+}
+";
+
+    RULE.assert_ignores(good_code);
+}
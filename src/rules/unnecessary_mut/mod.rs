@@ -19,7 +19,6 @@ struct UnnecessaryMutFixData {
     var_name: String,
     var_span: Span,
     keyword_span: Span,
-    mut_to_remove: Span,
 }
 
 fn extract_mut_declaration(
@@ -45,7 +44,6 @@ fn extract_mut_declaration(
         var_name,
         var_span,
         keyword_span: call.head,
-        mut_to_remove: Span::new(call.head.start, var_span.start),
     })
 }
 
@@ -62,6 +60,14 @@ impl DetectFix for UnnecessaryMut {
         "Variable marked `mut` but never reassigned"
     }
 
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A `mut` variable that is only read can be declared with `let`. Immutable bindings \
+             can be captured by closures, so `each` and `par-each` can use them, and readers \
+             don't have to look for a reassignment that never happens.",
+        )
+    }
+
     fn source_link(&self) -> Option<&'static str> {
         Some("https://www.nushell.sh/book/variables.html#mutable-variables")
     }
@@ -119,9 +125,12 @@ impl DetectFix for UnnecessaryMut {
 
     fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
         Some(Fix {
-            explanation: format!("Remove 'mut' keyword from variable '{}'", fix_data.var_name)
-                .into(),
-            replacements: vec![Replacement::new(fix_data.mut_to_remove, "")],
+            explanation: format!(
+                "Declare '{}' with 'let' instead of 'mut'",
+                fix_data.var_name
+            )
+            .into(),
+            replacements: vec![Replacement::new(fix_data.keyword_span, "let")],
         })
     }
 }
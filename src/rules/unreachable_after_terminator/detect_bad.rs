@@ -0,0 +1,62 @@
+use super::RULE;
+
+#[test]
+fn detects_code_after_return() {
+    let bad_code = r"
+def f [] {
+    return 1
+    print 'never'
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_code_after_error_make() {
+    let bad_code = r"
+def check [x: int] {
+    if $x < 0 {
+        error make {msg: 'negative'}
+        print 'unreachable'
+    }
+    $x
+}
+";
+    RULE.assert_count(bad_code, 1);
+}
+
+#[test]
+fn detects_code_after_break_in_loop() {
+    let bad_code = r"
+for x in [1 2 3] {
+    break
+    print $x
+}
+";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_code_after_exit_at_top_level() {
+    RULE.assert_detects("exit 1\nprint 'done'");
+}
+
+#[test]
+fn reports_dead_statements_together() {
+    let bad_code = r"
+def f [] {
+    return
+    let x = 1
+    print $x
+}
+";
+    RULE.assert_count(bad_code, 1);
+}
+
+#[test]
+fn labels_terminator() {
+    RULE.assert_labels_contain(
+        "def f [] { return 1; print 'x' }",
+        "`return` leaves the block",
+    );
+}
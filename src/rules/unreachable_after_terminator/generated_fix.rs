@@ -0,0 +1,34 @@
+use super::RULE;
+
+#[test]
+fn fix_removes_dead_statement() {
+    let bad_code = r"
+def f [] {
+    return 1
+    print 'never'
+}
+";
+    let expected = r"
+def f [] {
+    return 1
+}
+";
+    RULE.assert_fixed_is(bad_code, expected);
+}
+
+#[test]
+fn fix_removes_all_dead_statements() {
+    let bad_code = r"
+def f [] {
+    exit 0
+    let x = 1
+    print $x
+}
+";
+    let expected = r"
+def f [] {
+    exit 0
+}
+";
+    RULE.assert_fixed_is(bad_code, expected);
+}
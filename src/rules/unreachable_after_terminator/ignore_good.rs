@@ -0,0 +1,37 @@
+use super::RULE;
+
+#[test]
+fn ignore_return_at_end() {
+    RULE.assert_ignores(r"def f [] { let x = 1; return $x }");
+}
+
+#[test]
+fn ignore_code_after_conditional_return() {
+    let good_code = r"
+def f [x: int] {
+    if $x > 0 {
+        return 'positive'
+    }
+    'non-positive'
+}
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignore_code_after_try_with_error_make() {
+    let good_code = r"
+try {
+    error make {msg: 'boom'}
+} catch {
+    print 'caught'
+}
+print 'continues'
+";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignore_continue_as_last_statement() {
+    RULE.assert_ignores(r"for x in [1 2] { if $x == 1 { continue }; print $x }");
+}
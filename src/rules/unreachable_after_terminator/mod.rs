@@ -0,0 +1,143 @@
+use lsp_types::DiagnosticTag;
+use nu_protocol::{
+    Span,
+    ast::{Block, Expr, Expression, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::call::CallExt,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+const TERMINATORS: &[&str] = &["return", "exit", "break", "continue", "error make"];
+
+struct FixData {
+    /// The dead statements, from the end of the terminator
+    removal_span: Span,
+}
+
+fn pipeline_span(pipeline: &Pipeline) -> Option<Span> {
+    let first = pipeline.elements.first()?;
+    let last = pipeline.elements.last()?;
+    Some(Span::new(first.expr.span.start, last.expr.span.end))
+}
+
+/// Name of the command ending the statement, when control never continues
+/// past it
+fn terminator(pipeline: &Pipeline, context: &LintContext) -> Option<&'static str> {
+    let Expr::Call(call) = &pipeline.elements.last()?.expr.expr else {
+        return None;
+    };
+    TERMINATORS
+        .iter()
+        .find(|name| call.is_call_to_command(name, context))
+        .copied()
+}
+
+fn check_block(block: &Block, context: &LintContext) -> Vec<(Detection, FixData)> {
+    // Only statements of this block count: a `return` inside an `if` branch
+    // doesn't end the code after the `if`
+    let Some((index, name)) = block
+        .pipelines
+        .iter()
+        .enumerate()
+        .find_map(|(index, pipeline)| Some((index, terminator(pipeline, context)?)))
+    else {
+        return vec![];
+    };
+    let Some(terminator_span) = pipeline_span(&block.pipelines[index]) else {
+        return vec![];
+    };
+    let dead: Vec<Span> = block.pipelines[index + 1..]
+        .iter()
+        .filter_map(pipeline_span)
+        .collect();
+    let (Some(first), Some(last)) = (dead.first(), dead.last()) else {
+        return vec![];
+    };
+
+    let statements = if dead.len() == 1 {
+        String::from("1 statement")
+    } else {
+        format!("{} statements", dead.len())
+    };
+    let detection = Detection::from_global_span(
+        format!("{statements} after `{name}` can never run"),
+        Span::new(first.start, last.end),
+    )
+    .with_primary_label("unreachable code")
+    .with_extra_label(format!("`{name}` leaves the block here"), terminator_span);
+
+    vec![(
+        detection,
+        FixData {
+            removal_span: Span::new(terminator_span.end, last.end),
+        },
+    )]
+}
+
+fn check_expression(expr: &Expression, context: &LintContext) -> Vec<(Detection, FixData)> {
+    match &expr.expr {
+        Expr::Block(block_id) | Expr::Closure(block_id) | Expr::Subexpression(block_id) => {
+            check_block(context.working_set.get_block(*block_id), context)
+        }
+        _ => vec![],
+    }
+}
+
+struct UnreachableAfterTerminator;
+
+impl DetectFix for UnreachableAfterTerminator {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "unreachable_after_terminator"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Statements after `return`, `exit`, `break`, `continue` or `error make`"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "Once a block reaches `return`, `exit`, `break`, `continue` or `error make`, the \
+             statements that follow in the same block never run. They are usually left over from \
+             debugging or a sign that the terminator was meant to be inside a condition. \
+             Terminators nested in an `if` or `match` branch do not affect the code after that \
+             branch.",
+        )
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn diagnostic_tags(&self) -> &'static [DiagnosticTag] {
+        &[DiagnosticTag::UNNECESSARY]
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let mut detections = check_block(context.ast, context);
+        detections.extend(context.detect_with_fix_data(check_expression));
+        detections
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: "Remove unreachable code".into(),
+            replacements: vec![Replacement::new(fix_data.removal_span, String::new())],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &UnreachableAfterTerminator;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
@@ -0,0 +1,40 @@
+use super::RULE;
+
+#[test]
+fn detects_unused_each_parameter() {
+    let bad_code = "[1 2 3] | each {|x| 42 }";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_parameter_shadowed_by_typo() {
+    let bad_code = r#"
+let item = 5
+[1 2 3] | each {|itme| $item * 2 }
+"#;
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_unused_accumulator_parameter() {
+    let bad_code = "[1 2 3] | reduce {|it, acc| $it * 2 }";
+    RULE.assert_count(bad_code, 1);
+}
+
+#[test]
+fn detects_both_unused_parameters() {
+    let bad_code = "{a: 1} | items {|key, value| 'constant' }";
+    RULE.assert_count(bad_code, 2);
+}
+
+#[test]
+fn detects_stored_closure() {
+    let bad_code = "let handler = {|event| print 'fired' }";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_unused_catch_error() {
+    let bad_code = "try { error make {msg: 'boom'} } catch {|err| print 'failed' }";
+    RULE.assert_detects(bad_code);
+}
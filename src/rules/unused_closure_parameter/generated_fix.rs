@@ -0,0 +1,22 @@
+use super::RULE;
+
+#[test]
+fn prefixes_parameter_with_underscore() {
+    RULE.assert_fixed_is("[1 2 3] | each {|x| 42 }", "[1 2 3] | each {|_x| 42 }");
+}
+
+#[test]
+fn prefixes_only_unused_parameter() {
+    RULE.assert_fixed_is(
+        "[1 2 3] | reduce {|it, acc| $it * 2 }",
+        "[1 2 3] | reduce {|it, _acc| $it * 2 }",
+    );
+}
+
+#[test]
+fn prefixes_typed_parameter() {
+    RULE.assert_fixed_is(
+        "[1 2 3] | each {|x: int| 42 }",
+        "[1 2 3] | each {|_x: int| 42 }",
+    );
+}
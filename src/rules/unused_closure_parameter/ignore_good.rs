@@ -0,0 +1,55 @@
+use super::RULE;
+
+#[test]
+fn ignores_used_parameter() {
+    let good_code = "[1 2 3] | each {|x| $x * 2 }";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_parameter_used_in_nested_closure() {
+    let good_code = "[1 2 3] | each {|x| [4 5] | each {|y| $x + $y } }";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_underscore_parameter() {
+    let good_code = "[1 2 3] | each {|_x| 42 }";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_closure_without_parameters() {
+    let good_code = "[1 2 3] | each { 42 }";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_empty_pipes() {
+    let good_code = "do {|| print 'hi' }";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_parameter_used_in_cell_path() {
+    let good_code = "ls | each {|file| $file.name }";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_row_condition() {
+    let good_code = "ls | where size > 1kb";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_parameter_used_in_interpolation() {
+    let good_code = r#"[1 2 3] | each {|n| $"value: ($n)" }"#;
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_custom_command_parameters() {
+    let good_code = "def greet [name: string] { print 'hello' }";
+    RULE.assert_ignores(good_code);
+}
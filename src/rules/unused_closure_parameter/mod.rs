@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+
+use lsp_types::DiagnosticTag;
+use nu_protocol::{
+    BlockId, Span,
+    ast::{Expr, Expression},
+};
+
+use crate::{
+    LintLevel,
+    ast::block::BlockExt,
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    param_name: String,
+    name_span: Span,
+}
+
+fn check_closure(
+    expr: &Expression,
+    context: &LintContext,
+    command_bodies: &HashSet<BlockId>,
+) -> Vec<(Detection, FixData)> {
+    let Expr::Closure(block_id) = &expr.expr else {
+        return vec![];
+    };
+
+    // Bodies of custom commands are parsed as closures, their parameters are
+    // covered by `unused_parameter`
+    if command_bodies.contains(block_id) {
+        return vec![];
+    }
+
+    let block = context.working_set.get_block(*block_id);
+    let signature = &block.signature;
+
+    signature
+        .required_positional
+        .iter()
+        .chain(&signature.optional_positional)
+        .chain(&signature.rest_positional)
+        .filter_map(|param| {
+            let var_id = param.var_id?;
+            let name_span = context.working_set.get_variable(var_id).declaration_span;
+
+            // Skip underscore-prefixed parameters (intentionally unused) and
+            // implicit parameters without a source location
+            if param.name.starts_with('_') || !expr.span.contains_span(name_span) {
+                return None;
+            }
+
+            // Usages are only searched within the closure body, so a variable
+            // with the same name in the enclosing scope does not count
+            if !block.var_usages(var_id, context).is_empty() {
+                return None;
+            }
+
+            let detection = Detection::from_global_span(
+                format!("Closure parameter '{}' is never used", param.name),
+                name_span,
+            )
+            .with_primary_label("unused parameter")
+            .with_extra_span(expr.span);
+
+            Some((
+                detection,
+                FixData {
+                    param_name: param.name.clone(),
+                    name_span,
+                },
+            ))
+        })
+        .collect()
+}
+
+struct UnusedClosureParameter;
+
+impl DetectFix for UnusedClosureParameter {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "unused_closure_parameter"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Closure parameter declared but never used"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A closure like `{|x| fixed-thing }` that never references its parameter often \
+             signals a typo in the variable name or a leftover from refactoring. Prefix the \
+             parameter with an underscore to mark it as intentionally unused.",
+        )
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn diagnostic_tags(&self) -> &'static [DiagnosticTag] {
+        &[DiagnosticTag::UNNECESSARY]
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        let command_bodies: HashSet<BlockId> = context
+            .custom_commands()
+            .into_iter()
+            .map(|def| def.body)
+            .collect();
+        context.detect_with_fix_data(|expr, ctx| check_closure(expr, ctx, &command_bodies))
+    }
+
+    fn fix(&self, _context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        Some(Fix {
+            explanation: format!("Prefix `{}` with underscore", fix_data.param_name).into(),
+            replacements: vec![Replacement::new(
+                Span::new(fix_data.name_span.start, fix_data.name_span.start),
+                "_",
+            )],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &UnusedClosureParameter;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;
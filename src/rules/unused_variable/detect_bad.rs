@@ -10,6 +10,13 @@ fn test_unused_mut() {
     RULE.assert_detects("mut x = 5");
 }
 
+#[test]
+fn test_reported_on_name_not_initializer() {
+    // Editors grey out the reported span, but the effectful initializer stays
+    RULE.assert_reported_on("let output = ^git status", "output");
+    RULE.assert_reported_on("mut count = 5", "count");
+}
+
 #[test]
 fn test_unused_in_function() {
     let code = r#"
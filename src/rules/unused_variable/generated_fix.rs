@@ -107,3 +107,39 @@ fn test_fix_semicolon_preserves_following_command() {
     let expected = r#"head -n 10 README.md"#;
     RULE.assert_fixed_is(code, expected);
 }
+
+#[test]
+fn test_fix_removes_pure_pipeline() {
+    let code = r#"let total = [1 2 3] | math sum | into string
+print "done""#;
+    RULE.assert_fixed_is(code, r#"print "done""#);
+}
+
+#[test]
+fn test_fix_prefixes_external_initializer() {
+    let code = r#"let status = ^git status --short
+print "done""#;
+    let expected = r#"let _status = ^git status --short
+print "done""#;
+    RULE.assert_fixed_is(code, expected);
+}
+
+#[test]
+fn test_fix_prefixes_file_system_initializer() {
+    let code = r#"let listing = ls | where size > 1kb
+print "done""#;
+    let expected = r#"let _listing = ls | where size > 1kb
+print "done""#;
+    RULE.assert_fixed_is(code, expected);
+}
+
+#[test]
+fn test_fix_prefixes_custom_command_initializer() {
+    let code = r#"def setup [] { mkdir build; 1 }
+let result = setup
+print "done""#;
+    let expected = r#"def setup [] { mkdir build; 1 }
+let _result = setup
+print "done""#;
+    RULE.assert_fixed_is(code, expected);
+}
@@ -3,20 +3,43 @@ use std::collections::{HashMap, HashSet};
 use lsp_types::DiagnosticTag;
 use nu_protocol::{
     Span, VarId,
-    ast::{Expr, Traverse},
+    ast::{Call, Expr, Traverse},
 };
 
 use crate::{
     LintLevel,
     ast::{call::CallExt, expression::ExpressionExt},
     context::LintContext,
+    effect::builtin::is_side_effect_free,
     rule::{DetectFix, Rule},
     violation::{Detection, Fix, Replacement},
 };
 
 struct FixData {
     var_name: String,
+    name_span: Span,
     declaration_span: Span,
+    /// The initializer only computes a value, so the whole statement can go
+    removable: bool,
+}
+
+/// Whether evaluating the initializer of `let`/`mut` does nothing besides
+/// producing its value
+fn has_pure_initializer(call: &Call, context: &LintContext) -> bool {
+    let Some(value) = call.get_positional_arg(1) else {
+        return true;
+    };
+    let mut effects: Vec<Span> = Vec::new();
+    value.flat_map(
+        context.working_set,
+        &|expr| match &expr.expr {
+            Expr::ExternalCall(..) => vec![expr.span],
+            Expr::Call(call) if !is_side_effect_free(call, context) => vec![expr.span],
+            _ => vec![],
+        },
+        &mut effects,
+    );
+    effects.is_empty()
 }
 
 struct UnusedVariable;
@@ -32,6 +55,16 @@ impl DetectFix for UnusedVariable {
         "Variable declared but never used"
     }
 
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "A `let` or `mut` binding that is never read is dead code. When the value is only \
+             computed, for example a literal or a chain of data commands, the fix removes the \
+             statement. When computing it runs external commands, custom commands or commands \
+             that touch the file system or network, the fix keeps the statement and prefixes the \
+             name with `_` to mark it as intentionally unused.",
+        )
+    }
+
     fn level(&self) -> LintLevel {
         LintLevel::Warning
     }
@@ -42,29 +75,36 @@ impl DetectFix for UnusedVariable {
 
     fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
         // 1. Collect all let/mut declarations
-        let mut declarations: Vec<(VarId, String, Span)> = Vec::new();
+        let mut declarations: Vec<(VarId, FixData)> = Vec::new();
         context.ast.flat_map(
             context.working_set,
             &|expr| {
                 let Expr::Call(call) = &expr.expr else {
                     return vec![];
                 };
-                let Some((var_id, var_name, _)) = call.extract_variable_declaration(context) else {
+                let Some((var_id, var_name, name_span)) =
+                    call.extract_variable_declaration(context)
+                else {
                     return vec![];
                 };
                 // Skip underscore-prefixed variables (intentionally unused)
                 if var_name.starts_with('_') {
                     return vec![];
                 }
-                vec![(var_id, var_name, expr.span)]
+                vec![(
+                    var_id,
+                    FixData {
+                        var_name,
+                        name_span,
+                        declaration_span: expr.span,
+                        removable: has_pure_initializer(call, context),
+                    },
+                )]
             },
             &mut declarations,
         );
 
-        let decl_map: HashMap<VarId, (String, Span)> = declarations
-            .into_iter()
-            .map(|(id, name, span)| (id, (name, span)))
-            .collect();
+        let decl_map: HashMap<VarId, FixData> = declarations.into_iter().collect();
 
         // 2. Collect all variable usages
         let mut usages: Vec<VarId> = Vec::new();
@@ -79,25 +119,33 @@ impl DetectFix for UnusedVariable {
         decl_map
             .into_iter()
             .filter(|(var_id, _)| !used_vars.contains(var_id))
-            .map(|(_, (var_name, span))| {
+            .map(|(_, fix_data)| {
                 let detection = Detection::from_global_span(
-                    format!("Variable '{var_name}' is declared but never used"),
-                    span,
+                    format!(
+                        "Variable '{}' is declared but never used",
+                        fix_data.var_name
+                    ),
+                    fix_data.name_span,
                 )
                 .with_primary_label("unused variable");
 
-                (
-                    detection,
-                    FixData {
-                        var_name,
-                        declaration_span: span,
-                    },
-                )
+                (detection, fix_data)
             })
             .collect()
     }
 
     fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        // Removing the statement would also drop the effects of its initializer
+        if !fix_data.removable {
+            return Some(Fix {
+                explanation: format!("Prefix `{}` with underscore", fix_data.var_name).into(),
+                replacements: vec![Replacement::new(
+                    fix_data.name_span,
+                    format!("_{}", fix_data.var_name),
+                )],
+            });
+        }
+
         let removal_span = context.expand_span_to_statement(fix_data.declaration_span);
 
         Some(Fix {
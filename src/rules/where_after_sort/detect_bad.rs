@@ -0,0 +1,35 @@
+use super::RULE;
+
+#[test]
+fn detects_sort_where_first() {
+    let bad_code = "[5 3 8 1] | sort | where $it > 2 | first";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_sort_by_where_last() {
+    let bad_code = "ls | sort-by size | where type == file | last";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_filter_with_closure() {
+    let bad_code = "[5 3 8 1] | sort | filter {|x| $x mod 2 == 0 } | first";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_first_with_count() {
+    let bad_code = "ls | sort-by modified | where size > 1kb | first 3";
+    RULE.assert_detects(bad_code);
+}
+
+#[test]
+fn detects_in_nested_block() {
+    let bad_code = r#"
+def newest-large [] {
+    ls | sort-by modified | where size > 1mb | last
+}
+"#;
+    RULE.assert_detects(bad_code);
+}
@@ -0,0 +1,17 @@
+use super::RULE;
+
+#[test]
+fn swaps_where_and_sort() {
+    RULE.assert_fixed_is(
+        "[5 3 8 1] | sort | where $it > 2 | first",
+        "[5 3 8 1] | where $it > 2 | sort | first",
+    );
+}
+
+#[test]
+fn swaps_where_and_sort_by_with_flags() {
+    RULE.assert_fixed_is(
+        "ls | sort-by size --reverse | where type == file | last",
+        "ls | where type == file | sort-by size --reverse | last",
+    );
+}
@@ -0,0 +1,25 @@
+use super::RULE;
+
+#[test]
+fn ignores_where_before_sort() {
+    let good_code = "[5 3 8 1] | where $it > 2 | sort | first";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_sort_where_without_first() {
+    let good_code = "ls | sort-by size | where type == file";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_position_column_added_after_sort() {
+    let good_code = "ls | sort-by size | enumerate | where index < 3 | first";
+    RULE.assert_ignores(good_code);
+}
+
+#[test]
+fn ignores_sort_first() {
+    let good_code = "[5 3 8 1] | sort | first";
+    RULE.assert_ignores(good_code);
+}
@@ -0,0 +1,121 @@
+use nu_protocol::{
+    Span,
+    ast::{Call, Expr, Pipeline},
+};
+
+use crate::{
+    LintLevel,
+    ast::{block::BlockExt, call::CallExt, pipeline::PipelineExt},
+    context::LintContext,
+    rule::{DetectFix, Rule},
+    violation::{Detection, Fix, Replacement},
+};
+
+struct FixData {
+    /// Span covering both the sort and the filter
+    span: Span,
+    sort_span: Span,
+    filter_span: Span,
+}
+
+fn is_sort(call: &Call, context: &LintContext) -> bool {
+    matches!(call.get_call_name(context).as_str(), "sort" | "sort-by")
+}
+
+fn is_filter(call: &Call, context: &LintContext) -> bool {
+    matches!(call.get_call_name(context).as_str(), "where" | "filter")
+}
+
+fn is_first_or_last(pipeline: &Pipeline, index: usize, context: &LintContext) -> bool {
+    pipeline.elements.get(index).is_some_and(|element| {
+        matches!(
+            &element.expr.expr,
+            Expr::Call(call) if matches!(call.get_call_name(context).as_str(), "first" | "last")
+        )
+    })
+}
+
+fn check_pipeline(pipeline: &Pipeline, context: &LintContext) -> Vec<(Detection, FixData)> {
+    pipeline
+        .find_command_pairs(context, is_sort, is_filter)
+        .into_iter()
+        .filter(|pair| is_first_or_last(pipeline, pair.second_index + 1, context))
+        .map(|pair| {
+            let sort_span = pipeline.elements[pair.first_index].expr.span;
+            let filter_span = pipeline.elements[pair.second_index].expr.span;
+
+            let detection = Detection::from_global_span(
+                format!(
+                    "Run `{}` before `{}` so fewer rows are sorted",
+                    pair.second.get_call_name(context),
+                    pair.first.get_call_name(context)
+                ),
+                pair.span,
+            )
+            .with_primary_label("sorts rows that are filtered out afterwards")
+            .with_extra_label("can run first", filter_span);
+
+            (
+                detection,
+                FixData {
+                    span: pair.span,
+                    sort_span,
+                    filter_span,
+                },
+            )
+        })
+        .collect()
+}
+
+struct WhereAfterSort;
+
+impl DetectFix for WhereAfterSort {
+    type FixInput<'a> = FixData;
+
+    fn id(&self) -> &'static str {
+        "where_after_sort"
+    }
+
+    fn short_description(&self) -> &'static str {
+        "Filter with `where` before sorting, not after"
+    }
+
+    fn long_description(&self) -> Option<&'static str> {
+        Some(
+            "In `sort | where <cond> | first` every row is sorted, including the ones the filter \
+             throws away. A row predicate does not depend on the order of rows, so filtering \
+             first and sorting the remaining rows gives the same result for less work. Only a \
+             filter directly following the sort is moved: a predicate on a position column added \
+             after sorting (for example with `enumerate`) keeps its place.",
+        )
+    }
+
+    fn level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn detect<'a>(&self, context: &'a LintContext) -> Vec<(Detection, Self::FixInput<'a>)> {
+        context.ast.detect_in_pipelines(context, check_pipeline)
+    }
+
+    fn fix(&self, context: &LintContext, fix_data: &Self::FixInput<'_>) -> Option<Fix> {
+        let sort_text = context.span_text(fix_data.sort_span);
+        let filter_text = context.span_text(fix_data.filter_span);
+        Some(Fix {
+            explanation: "Swap filter and sort".into(),
+            replacements: vec![Replacement::new(
+                fix_data.span,
+                format!("{filter_text} | {sort_text}"),
+            )],
+        })
+    }
+}
+
+pub static RULE: &dyn Rule = &WhereAfterSort;
+
+#[cfg(test)]
+mod detect_bad;
+#[cfg(test)]
+mod generated_fix;
+#[cfg(test)]
+mod ignore_good;